@@ -0,0 +1,185 @@
+use thiserror::Error;
+
+use crate::{
+	timezone::Utc, ComponentRange, Date, DateTime, Month, NaiveDateTime, Time, TimeZone, Year,
+};
+
+/// Builds a [`DateTime`] one component at a time. See [`DateTime::builder`].
+///
+/// The year, month, and day must be given. The time defaults to midnight, and
+/// the time zone defaults to UTC. Nothing is validated until
+/// [`DateTimeBuilder::build`] is called.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct DateTimeBuilder<Tz: TimeZone = Utc> {
+	year: Option<i16>,
+	month: Option<Month>,
+	day: Option<u8>,
+	hour: u8,
+	minute: u8,
+	second: u8,
+	nanosecond: u32,
+	timezone: Tz,
+}
+
+/// The [`DateTime`] described by a [`DateTimeBuilder`] isn't valid
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Error)]
+pub enum BuildDateTimeError<E> {
+	/// A required component wasn't given
+	#[error("The {0} was not given")]
+	Missing(&'static str),
+	/// A component was out of range
+	#[error(transparent)]
+	ComponentRange(#[from] ComponentRange),
+	/// The local date and time doesn't exist in the time zone
+	#[error("The local date and time is invalid in the time zone: {0}")]
+	TimeZone(E),
+}
+
+impl DateTime<Utc> {
+	/// Starts building a date and time from its components.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use botic::timezone::UtcOffset;
+	/// use botic::{DateTime, Month};
+	///
+	/// let date_time = DateTime::builder()
+	///     .year(2024)
+	///     .month(Month::March)
+	///     .day(10)
+	///     .hour(2)
+	///     .minute(30)
+	///     .timezone(UtcOffset::from_hours(-5).unwrap())
+	///     .build()
+	///     .unwrap();
+	///
+	/// assert_eq!(date_time.naive_utc().hour(), 7);
+	///
+	/// let error = DateTime::builder().year(2024).month(Month::February).day(30).build();
+	/// assert!(error.is_err());
+	/// ```
+	#[must_use]
+	pub const fn builder() -> DateTimeBuilder {
+		DateTimeBuilder {
+			year: None,
+			month: None,
+			day: None,
+			hour: 0,
+			minute: 0,
+			second: 0,
+			nanosecond: 0,
+			timezone: Utc,
+		}
+	}
+}
+
+impl<Tz: TimeZone> DateTimeBuilder<Tz> {
+	#[must_use]
+	pub const fn year(mut self, year: i16) -> Self {
+		self.year = Some(year);
+		self
+	}
+
+	#[must_use]
+	pub const fn month(mut self, month: Month) -> Self {
+		self.month = Some(month);
+		self
+	}
+
+	#[must_use]
+	pub const fn day(mut self, day: u8) -> Self {
+		self.day = Some(day);
+		self
+	}
+
+	#[must_use]
+	pub const fn hour(mut self, hour: u8) -> Self {
+		self.hour = hour;
+		self
+	}
+
+	#[must_use]
+	pub const fn minute(mut self, minute: u8) -> Self {
+		self.minute = minute;
+		self
+	}
+
+	#[must_use]
+	pub const fn second(mut self, second: u8) -> Self {
+		self.second = second;
+		self
+	}
+
+	#[must_use]
+	pub const fn nanosecond(mut self, nanosecond: u32) -> Self {
+		self.nanosecond = nanosecond;
+		self
+	}
+
+	/// Sets the time zone which the other components are local to
+	#[must_use]
+	pub fn timezone<NewZone: TimeZone>(self, timezone: NewZone) -> DateTimeBuilder<NewZone> {
+		DateTimeBuilder {
+			year: self.year,
+			month: self.month,
+			day: self.day,
+			hour: self.hour,
+			minute: self.minute,
+			second: self.second,
+			nanosecond: self.nanosecond,
+			timezone,
+		}
+	}
+
+	/// Validates the components, and creates the date and time
+	///
+	/// # Errors
+	///
+	/// Returns an error if the year, month, or day wasn't given, if a
+	/// component is out of range, or if the local date and time doesn't exist
+	/// in the time zone
+	pub fn build(self) -> Result<DateTime<Tz>, BuildDateTimeError<Tz::Err>> {
+		let year = self.year.ok_or(BuildDateTimeError::Missing("year"))?;
+		let month = self.month.ok_or(BuildDateTimeError::Missing("month"))?;
+		let day = self.day.ok_or(BuildDateTimeError::Missing("day"))?;
+
+		let date = Date::from_ymd(Year::from_i16(year), month, day)?;
+		let time = Time::from_hms_nano(self.hour, self.minute, self.second, self.nanosecond)?;
+		DateTime::from_local(NaiveDateTime::new(date, time), self.timezone)
+			.map_err(BuildDateTimeError::TimeZone)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn precise_errors() {
+		let builder = DateTime::builder().year(2024).month(Month::March);
+		assert_eq!(builder.build(), Err(BuildDateTimeError::Missing("day")));
+
+		let Err(BuildDateTimeError::ComponentRange(error)) = builder.day(10).minute(60).build()
+		else {
+			panic!("the minute should be out of range");
+		};
+		assert_eq!(error.name(), "minute");
+	}
+
+	#[test]
+	fn defaults_to_midnight_utc() {
+		let date_time = DateTime::builder()
+			.year(2000)
+			.month(Month::January)
+			.day(1)
+			.build()
+			.unwrap();
+
+		let date = Date::from_ymd(2000.into(), Month::January, 1).unwrap();
+		assert_eq!(
+			date_time,
+			DateTime::from_utc(NaiveDateTime::new(date, Time::MIDNIGHT), Utc)
+		);
+	}
+}
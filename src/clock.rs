@@ -0,0 +1,220 @@
+//! Sources of the current time.
+//!
+//! Code which needs the current time can take a [`Clock`] instead of calling
+//! [`DateTime::now`] directly, so that tests can control the time.
+
+#[cfg(feature = "test-util")]
+use std::sync::Arc;
+use std::time::Duration;
+
+#[cfg(feature = "test-util")]
+use parking_lot::Mutex;
+
+use crate::{sys, timezone::Utc, DateTime, NaiveDateTime, Timestamp};
+
+/// A source of the current time
+pub trait Clock {
+	/// Gets the current date and time in UTC
+	fn now(&self) -> DateTime<Utc>;
+
+	/// Gets the smallest amount of time by which this clock can change. Parts
+	/// of a time which are smaller than this aren't meaningful.
+	fn resolution(&self) -> Duration {
+		Duration::from_nanos(1)
+	}
+
+	/// Checks if this clock never goes backwards
+	fn is_monotonic(&self) -> bool {
+		false
+	}
+
+	/// Checks if this clock can be changed while the program is running, such
+	/// as by an administrator or an NTP daemon
+	fn is_adjustable(&self) -> bool {
+		true
+	}
+}
+
+impl<C: Clock + ?Sized> Clock for &C {
+	fn now(&self) -> DateTime<Utc> {
+		(**self).now()
+	}
+
+	fn resolution(&self) -> Duration {
+		(**self).resolution()
+	}
+
+	fn is_monotonic(&self) -> bool {
+		(**self).is_monotonic()
+	}
+
+	fn is_adjustable(&self) -> bool {
+		(**self).is_adjustable()
+	}
+}
+
+/// The system's wall clock
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+	fn now(&self) -> DateTime<Utc> {
+		DateTime::<Utc>::now()
+	}
+
+	fn resolution(&self) -> Duration {
+		sys::realtime_resolution().unwrap_or(Duration::from_micros(1))
+	}
+}
+
+/// The system's wall clock, read in a way which is faster but less precise
+/// than [`SystemClock`].
+///
+/// This is meant for code which reads the time very often, such as loggers,
+/// and doesn't need more than millisecond precision. On Linux, this uses
+/// `CLOCK_REALTIME_COARSE`, and on Windows, it uses `GetSystemTimeAsFileTime`.
+/// On other platforms, it's the same as [`SystemClock`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct CoarseClock;
+
+impl Clock for CoarseClock {
+	fn now(&self) -> DateTime<Utc> {
+		let Some((seconds, nanoseconds)) = sys::coarse_now() else {
+			return DateTime::<Utc>::now();
+		};
+
+		let timestamp = Timestamp::new(seconds, nanoseconds);
+		DateTime::from_utc(NaiveDateTime::from_timestamp(timestamp), Utc)
+	}
+
+	fn resolution(&self) -> Duration {
+		sys::coarse_resolution().unwrap_or_else(|| SystemClock.resolution())
+	}
+}
+
+/// A clock which only changes when it's told to.
+///
+/// Clones of a `MockClock` share the same time, so one can be given to the
+/// code being tested while the test advances another.
+///
+/// # Example
+///
+/// ```
+/// use botic::clock::{Clock, MockClock};
+/// use botic::{Date, DateTime, Month, NaiveDateTime, Time};
+/// use botic::timezone::Utc;
+///
+/// let midnight = |day| {
+///     let date = Date::from_ymd(2000.into(), Month::January, day).unwrap();
+///     DateTime::from_utc(NaiveDateTime::new(date, Time::MIDNIGHT), Utc)
+/// };
+///
+/// let clock = MockClock::new(midnight(1));
+/// let handle = clock.clone();
+///
+/// handle.set(midnight(2));
+/// assert_eq!(clock.now(), midnight(2));
+/// ```
+#[cfg(feature = "test-util")]
+#[derive(Clone, Debug)]
+pub struct MockClock {
+	time: Arc<Mutex<DateTime<Utc>>>,
+}
+
+#[cfg(feature = "test-util")]
+impl MockClock {
+	/// Creates a clock which starts at the given time
+	#[must_use]
+	pub fn new(start: DateTime<Utc>) -> Self {
+		Self {
+			time: Arc::new(Mutex::new(start)),
+		}
+	}
+
+	/// Sets the current time of the clock
+	pub fn set(&self, time: DateTime<Utc>) {
+		*self.time.lock() = time;
+	}
+
+	/// Moves the clock forward by the given amount of time. Leap seconds are
+	/// counted, so advancing over a leap second takes an extra second.
+	///
+	/// # Panics
+	///
+	/// Panics if the clock would go past [`NaiveDateTime::MAX`]
+	pub fn advance(&self, duration: Duration) {
+		let mut time = self.time.lock();
+		let seconds = i64::try_from(duration.as_secs()).unwrap_or(i64::MAX);
+		let (advanced, seconds_overflow) = time.add_seconds_overflowing(seconds);
+		let (advanced, nanoseconds_overflow) =
+			advanced.add_nanoseconds_overflowing(i64::from(duration.subsec_nanos()));
+		assert!(
+			!seconds_overflow && !nanoseconds_overflow,
+			"the mock clock was advanced out of range"
+		);
+		*time = advanced;
+	}
+}
+
+#[cfg(feature = "test-util")]
+impl Clock for MockClock {
+	fn now(&self) -> DateTime<Utc> {
+		*self.time.lock()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn system_clock_resolution() {
+		let resolution = SystemClock.resolution();
+		assert!(!resolution.is_zero());
+		assert!(CoarseClock.resolution() >= resolution);
+	}
+
+	#[cfg(feature = "test-util")]
+	#[test]
+	fn clones_share_time() {
+		use crate::{Date, Month, Time};
+
+		let day = |day| Date::from_ymd(2000.into(), Month::January, day).unwrap();
+		let midnight = |day| DateTime::from_utc(NaiveDateTime::new(day, Time::MIDNIGHT), Utc);
+
+		let clock = MockClock::new(midnight(day(1)));
+		let handle = clock.clone();
+		assert_eq!(clock.now(), midnight(day(1)));
+
+		handle.set(midnight(day(2)));
+		assert_eq!(clock.now(), midnight(day(2)));
+	}
+
+	#[cfg(feature = "test-util")]
+	#[test]
+	fn advance() {
+		use crate::datetime;
+
+		let clock = MockClock::new(datetime!(2000-01-01 12:00:00.75 UTC));
+		clock.advance(Duration::from_millis(500));
+		assert_eq!(clock.now(), datetime!(2000-01-01 12:00:01.25 UTC));
+
+		clock.set(datetime!(2000-01-01 23:59:59 UTC));
+		clock.advance(Duration::from_secs(2));
+		assert_eq!(clock.now(), datetime!(2000-01-02 00:00:01 UTC));
+
+		clock.set(datetime!(2016-12-31 23:59:59.5 UTC));
+		clock.advance(Duration::from_secs(1));
+		assert_eq!(clock.now(), datetime!(2016-12-31 23:59:60.5 UTC));
+		clock.advance(Duration::from_secs(1));
+		assert_eq!(clock.now(), datetime!(2017-01-01 00:00:00.5 UTC));
+	}
+
+	#[cfg(feature = "test-util")]
+	#[test]
+	#[should_panic(expected = "advanced out of range")]
+	fn advance_out_of_range() {
+		let clock = MockClock::new(DateTime::from_utc(NaiveDateTime::MAX, Utc));
+		clock.advance(Duration::MAX);
+	}
+}
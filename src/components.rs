@@ -0,0 +1,202 @@
+//! Traits for getting the components of dates and times.
+//!
+//! These let generic code, such as a formatter, accept anything with a date
+//! or a time of day, without a separate function for each type.
+
+use crate::{Date, DateTime, Month, NaiveDateTime, Time, TimeZone, Weekday, Year};
+
+/// A type which has a date in the proleptic Gregorian calendar
+///
+/// # Example
+///
+/// ```
+/// use botic::{Datelike, Month, NaiveDateTime};
+///
+/// fn is_new_years_day(date: &impl Datelike) -> bool {
+///     date.ordinal() == 1
+/// }
+///
+/// let date_time = NaiveDateTime::from_ymd_hms(2000.into(), Month::January, 1, 12, 0, 0).unwrap();
+/// assert!(is_new_years_day(&date_time));
+/// assert!(is_new_years_day(&date_time.date()));
+/// ```
+pub trait Datelike {
+	/// Gets the year
+	fn year(&self) -> Year;
+
+	/// Gets the month
+	fn month(&self) -> Month;
+
+	/// Gets the day of the month, starting with 1
+	fn day(&self) -> u8;
+
+	/// Gets the day of the year, starting with 1 on January 1
+	fn ordinal(&self) -> u16;
+
+	/// Gets the day of the week
+	fn weekday(&self) -> Weekday;
+}
+
+/// A type which has a time of day
+pub trait Timelike {
+	/// Gets the hour, in the range `0..24`
+	fn hour(&self) -> u8;
+
+	/// Gets the minute within the hour, in the range `0..60`
+	fn minute(&self) -> u8;
+
+	/// Gets the second within the minute. This is 60 during a leap second.
+	fn second(&self) -> u8;
+
+	/// Gets the nanosecond within the second, in the range `0..1_000_000_000`
+	fn nanosecond(&self) -> u32;
+}
+
+impl Datelike for Date {
+	fn year(&self) -> Year {
+		Self::year(*self)
+	}
+
+	fn month(&self) -> Month {
+		Self::month(*self)
+	}
+
+	fn day(&self) -> u8 {
+		Self::day(*self)
+	}
+
+	fn ordinal(&self) -> u16 {
+		Self::ordinal(*self)
+	}
+
+	fn weekday(&self) -> Weekday {
+		Self::weekday(*self)
+	}
+}
+
+impl Datelike for NaiveDateTime {
+	fn year(&self) -> Year {
+		Self::year(*self)
+	}
+
+	fn month(&self) -> Month {
+		Self::month(*self)
+	}
+
+	fn day(&self) -> u8 {
+		Self::day(*self)
+	}
+
+	fn ordinal(&self) -> u16 {
+		self.date().ordinal()
+	}
+
+	fn weekday(&self) -> Weekday {
+		Self::weekday(*self)
+	}
+}
+
+/// The components of a `DateTime` are in its time zone, rather than in UTC
+impl<Tz: TimeZone> Datelike for DateTime<Tz> {
+	fn year(&self) -> Year {
+		Self::year(self)
+	}
+
+	fn month(&self) -> Month {
+		Self::month(self)
+	}
+
+	fn day(&self) -> u8 {
+		Self::day(self)
+	}
+
+	fn ordinal(&self) -> u16 {
+		self.to_naive_local_saturating().date().ordinal()
+	}
+
+	fn weekday(&self) -> Weekday {
+		Self::weekday(self)
+	}
+}
+
+impl Timelike for Time {
+	fn hour(&self) -> u8 {
+		Self::hour(*self)
+	}
+
+	fn minute(&self) -> u8 {
+		Self::minute(*self)
+	}
+
+	fn second(&self) -> u8 {
+		Self::second(*self)
+	}
+
+	fn nanosecond(&self) -> u32 {
+		Self::nanosecond(*self)
+	}
+}
+
+impl Timelike for NaiveDateTime {
+	fn hour(&self) -> u8 {
+		Self::hour(*self)
+	}
+
+	fn minute(&self) -> u8 {
+		Self::minute(*self)
+	}
+
+	fn second(&self) -> u8 {
+		Self::second(*self)
+	}
+
+	fn nanosecond(&self) -> u32 {
+		Self::nanosecond(*self)
+	}
+}
+
+impl<Tz: TimeZone> Timelike for DateTime<Tz> {
+	fn hour(&self) -> u8 {
+		Self::hour(self)
+	}
+
+	fn minute(&self) -> u8 {
+		Self::minute(self)
+	}
+
+	fn second(&self) -> u8 {
+		Self::second(self)
+	}
+
+	fn nanosecond(&self) -> u32 {
+		self.naive_utc().nanosecond()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::timezone::UtcOffset;
+
+	fn components(value: &(impl Datelike + Timelike)) -> (u16, Weekday, u8, u32) {
+		(
+			value.ordinal(),
+			value.weekday(),
+			value.hour(),
+			value.nanosecond(),
+		)
+	}
+
+	#[test]
+	fn generic_components() {
+		let naive = NaiveDateTime::from_ymd_hms_nano(2000.into(), Month::December, 31, 23, 0, 0, 5)
+			.unwrap();
+		assert_eq!(components(&naive), (366, Weekday::Sunday, 23, 5));
+
+		let local = naive
+			.assume_utc()
+			.into_timezone(UtcOffset::from_hours(1).unwrap());
+		assert_eq!(components(&local), (1, Weekday::Monday, 0, 5));
+		assert_eq!(Datelike::year(&local), 2001.into());
+	}
+}
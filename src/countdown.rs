@@ -0,0 +1,187 @@
+use core::fmt::Display;
+use core::time::Duration;
+
+/// A unit of time which can be shown in a [`Countdown`]
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum CountdownUnit {
+	Seconds,
+	Minutes,
+	Hours,
+	Days,
+}
+
+impl CountdownUnit {
+	/// The number of seconds in one of this unit
+	const fn seconds(self) -> u64 {
+		match self {
+			Self::Seconds => 1,
+			Self::Minutes => 60,
+			Self::Hours => 3600,
+			Self::Days => 86_400,
+		}
+	}
+
+	/// The letter written after a number of this unit
+	const fn suffix(self) -> char {
+		match self {
+			Self::Seconds => 's',
+			Self::Minutes => 'm',
+			Self::Hours => 'h',
+			Self::Days => 'd',
+		}
+	}
+
+	/// The next smaller unit, if there is one
+	const fn smaller(self) -> Option<Self> {
+		match self {
+			Self::Seconds => None,
+			Self::Minutes => Some(Self::Seconds),
+			Self::Hours => Some(Self::Minutes),
+			Self::Days => Some(Self::Hours),
+		}
+	}
+}
+
+/// Formats a duration as a countdown, such as `2d 4h 13m`.
+///
+/// By default, days, hours, and minutes are shown. Leading units which are
+/// zero are left out, and the time is rounded up to the smallest unit, so
+/// the countdown doesn't reach zero before the time is up.
+///
+/// # Example
+///
+/// ```
+/// use core::time::Duration;
+/// use botic::{Countdown, CountdownUnit};
+///
+/// let remaining = Duration::from_secs(2 * 86_400 + 4 * 3600 + 13 * 60 + 59);
+/// assert_eq!(Countdown::new(remaining).to_string(), "2d 4h 14m");
+///
+/// let countdown = Countdown::new(remaining)
+///     .largest_unit(CountdownUnit::Hours)
+///     .smallest_unit(CountdownUnit::Seconds);
+/// assert_eq!(countdown.to_string(), "52h 13m 59s");
+///
+/// assert_eq!(Countdown::new(Duration::from_secs(3600)).to_string(), "1h 0m");
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Countdown {
+	duration: Duration,
+	largest: CountdownUnit,
+	smallest: CountdownUnit,
+}
+
+impl Countdown {
+	/// Creates a countdown showing days, hours, and minutes
+	#[must_use]
+	pub const fn new(duration: Duration) -> Self {
+		Self {
+			duration,
+			largest: CountdownUnit::Days,
+			smallest: CountdownUnit::Minutes,
+		}
+	}
+
+	/// Sets the largest unit to show. Any larger units are counted in this
+	/// unit instead, such as `52h` instead of `2d 4h`.
+	#[must_use]
+	pub const fn largest_unit(self, unit: CountdownUnit) -> Self {
+		Self {
+			largest: unit,
+			..self
+		}
+	}
+
+	/// Sets the smallest unit to show. If this is larger than the largest
+	/// unit, then only the largest unit is shown.
+	#[must_use]
+	pub const fn smallest_unit(self, unit: CountdownUnit) -> Self {
+		Self {
+			smallest: unit,
+			..self
+		}
+	}
+}
+
+impl Display for Countdown {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		let smallest = self.smallest.min(self.largest);
+		let mut remaining = round_up(self.duration, smallest.seconds());
+		let mut unit = Some(self.largest);
+		let mut started = false;
+
+		while let Some(current) = unit.filter(|unit| *unit >= smallest) {
+			let count = remaining / current.seconds();
+			remaining %= current.seconds();
+
+			if count > 0 || started || current == smallest {
+				if started {
+					f.write_str(" ")?;
+				}
+				write!(f, "{count}{}", current.suffix())?;
+				started = true;
+			}
+
+			unit = current.smaller();
+		}
+
+		Ok(())
+	}
+}
+
+/// Gets the number of seconds in the duration, rounded up to a multiple of
+/// the given number of seconds. If that's too large, then it's rounded down.
+const fn round_up(duration: Duration, multiple: u64) -> u64 {
+	let seconds = duration.as_secs();
+	let rounded_down = seconds - seconds % multiple;
+	if rounded_down == seconds && duration.subsec_nanos() == 0 {
+		return seconds;
+	}
+
+	match rounded_down.checked_add(multiple) {
+		Some(rounded_up) => rounded_up,
+		None => rounded_down,
+	}
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+	use alloc::string::ToString;
+
+	use super::*;
+
+	#[test]
+	fn units() {
+		let countdown = |seconds, largest, smallest| {
+			Countdown::new(Duration::from_secs(seconds))
+				.largest_unit(largest)
+				.smallest_unit(smallest)
+				.to_string()
+		};
+
+		use CountdownUnit::*;
+		assert_eq!(countdown(0, Days, Minutes), "0m");
+		assert_eq!(countdown(59, Days, Minutes), "1m");
+		assert_eq!(countdown(60, Days, Minutes), "1m");
+		assert_eq!(countdown(61, Days, Minutes), "2m");
+		assert_eq!(countdown(3599, Days, Hours), "1h");
+		assert_eq!(countdown(86_400 + 1, Days, Seconds), "1d 0h 0m 1s");
+		assert_eq!(countdown(90_061, Minutes, Seconds), "1501m 1s");
+		assert_eq!(countdown(90_061, Hours, Days), "26h");
+		assert_eq!(countdown(90_061, Seconds, Seconds), "90061s");
+		assert_eq!(countdown(u64::MAX, Minutes, Minutes), "307445734561825860m");
+	}
+
+	#[test]
+	fn rounds_up_until_the_time_is_up() {
+		let countdown = |duration| Countdown::new(duration).to_string();
+		assert_eq!(countdown(Duration::from_nanos(1)), "1m");
+		assert_eq!(countdown(Duration::from_millis(59_999)), "1m");
+		assert_eq!(countdown(Duration::from_millis(60_001)), "2m");
+		assert_eq!(countdown(Duration::ZERO), "0m");
+
+		let seconds =
+			Countdown::new(Duration::from_millis(500)).smallest_unit(CountdownUnit::Seconds);
+		assert_eq!(seconds.to_string(), "1s");
+	}
+}
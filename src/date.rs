@@ -1,243 +1,1013 @@
-use crate::{Month, Year};
+use crate::error::{check_range, ComponentRange};
+#[cfg(feature = "std")]
+use crate::TimeZone;
+use crate::{Month, NaiveDateTime, Time, Weekday, Year};
 
-use core::cmp::Ordering;
 use core::fmt::Display;
-
-use thiserror::Error;
-
-#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+use core::iter::{FusedIterator, StepBy};
+use core::ops::{Range, RangeInclusive};
+
+/// A date in the proleptic Gregorian calendar.
+///
+/// This is stored as the number of days since 0001-01-01, so it's only four
+/// bytes, and it can be compared without splitting it into a year, month, and
+/// day.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
 pub struct Date {
-	year: Year,
-	month: Month,
-	day: u8,
+	days_after_common_era: i32,
 }
 
-#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Error)]
-#[error("Tried to construct {given_day} {month}, but {month} only has {month_max_day} days")]
-pub struct DayGreaterThanMaximumForMonthError {
-	month: Month,
-	given_day: u8,
-	month_max_day: u8,
-}
+/// The number of days from 0001-01-01 to [`Date::MIN`]
+const MIN_DAYS_AFTER_COMMON_ERA: i64 = days_from_ymd(Year::MIN, Month::January, 1);
 
-#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Error)]
-#[error("Tried to construct a leap day in {0} which is not a leap year")]
-pub struct LeapDayNotInLeapYearError(Year);
-
-#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Error)]
-pub enum InvalidDateError {
-	#[error("{0}")]
-	DayTooBig(DayGreaterThanMaximumForMonthError),
-	#[error("{0}")]
-	NonLeapYear(LeapDayNotInLeapYearError),
-}
+/// The number of days from 0001-01-01 to [`Date::MAX`]
+const MAX_DAYS_AFTER_COMMON_ERA: i64 = days_from_ymd(Year::MAX, Month::December, 31);
 
 impl Date {
 	/// The earliest date which can be represented
-	pub const MIN: Self = unsafe { Self::from_ymd_unchecked(Year::MIN, Month::January, 1) };
+	pub const MIN: Self = Self {
+		days_after_common_era: MIN_DAYS_AFTER_COMMON_ERA as i32,
+	};
 
 	/// The latest date which can be represented
-	pub const MAX: Self = unsafe { Self::from_ymd_unchecked(Year::MAX, Month::December, 31) };
+	pub const MAX: Self = Self {
+		days_after_common_era: MAX_DAYS_AFTER_COMMON_ERA as i32,
+	};
 
-	pub const UNIX_EPOCH: Self =
-		unsafe { Self::from_ymd_unchecked(Year::from_i16(1970), Month::January, 1) };
+	pub const UNIX_EPOCH: Self = Self::from_ymd_unchecked(Year::from_i16(1970), Month::January, 1);
 
 	// TODO validated from_calendar_date
 
 	/// Creates a date without checking to make sure that it's valid.
 	///
+	/// A day past the end of the month rolls over into the next month, and a
+	/// day of zero is the last day of the previous month. Dates outside of the
+	/// range from [`Date::MIN`] to [`Date::MAX`] wrap around to the other end.
+	///
 	/// # Example
 	///
 	/// ```
 	/// use botic::{Date, Month, Year};
 	///
-	/// let y2k = unsafe {
-	///     Date::from_ymd_unchecked(Year::from(2000), Month::January, 1)
-	/// };
+	/// let y2k = Date::from_ymd_unchecked(Year::from(2000), Month::January, 1);
+	/// let rolled_over = Date::from_ymd_unchecked(Year::from(1999), Month::December, 32);
+	/// assert_eq!(y2k, rolled_over);
 	/// ```
-	///
-	/// # Safety
-	///
-	/// This function results in undefined behavior if the given date is not a real date
 	#[must_use]
-	pub const unsafe fn from_ymd_unchecked(year: Year, month: Month, day: u8) -> Self {
-		Self { year, month, day }
+	pub const fn from_ymd_unchecked(year: Year, month: Month, day: u8) -> Self {
+		Self::from_days_after_common_era_unchecked(days_from_ymd(year, month, day))
 	}
 
-	pub const fn from_ymd(year: Year, month: Month, day: u8) -> Result<Self, InvalidDateError> {
-		if day == 29 && (month as u8) == (Month::February as u8) && !year.is_leap_year() {
-			return Err(InvalidDateError::NonLeapYear(LeapDayNotInLeapYearError(
-				year,
-			)));
-		}
+	pub const fn from_ymd(year: Year, month: Month, day: u8) -> Result<Self, ComponentRange> {
+		check_range!("day", day, 1, month.days(year.is_leap_year()));
 
-		let max_days_for_month = month.days(year.is_leap_year());
-		if day > max_days_for_month {
-			return Err(InvalidDateError::DayTooBig(
-				DayGreaterThanMaximumForMonthError {
-					month,
-					given_day: day,
-					month_max_day: max_days_for_month,
-				},
-			));
-		}
+		Ok(Self::from_ymd_unchecked(year, month, day))
+	}
 
-		unsafe { Ok(Self::from_ymd_unchecked(year, month, day)) }
+	/// Creates a date from a year, month, and day, panicking if the day isn't
+	/// in the month. This is meant for constants, where an invalid date
+	/// becomes a compile error.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use botic::{Date, Month, Year};
+	///
+	/// const LEAP_DAY: Date = Date::from_ymd_or_panic(Year::from_i16(2024), Month::February, 29);
+	/// assert_eq!(LEAP_DAY.day(), 29);
+	/// ```
+	///
+	/// ```compile_fail
+	/// use botic::{Date, Month, Year};
+	///
+	/// const LEAP_DAY: Date = Date::from_ymd_or_panic(Year::from_i16(2023), Month::February, 29);
+	/// ```
+	///
+	/// # Panics
+	///
+	/// Panics if the day is out of range for the month
+	#[must_use]
+	#[track_caller]
+	pub const fn from_ymd_or_panic(year: Year, month: Month, day: u8) -> Self {
+		match Self::from_ymd(year, month, day) {
+			Ok(date) => date,
+			Err(error) => error.panic(),
+		}
 	}
 
 	// TODO docs
 
 	#[must_use]
 	pub const fn year(self) -> Year {
-		self.year
+		self.ymd().0
 	}
 
 	#[must_use]
 	pub const fn month(self) -> Month {
-		self.month
+		self.ymd().1
 	}
 
 	#[must_use]
 	pub const fn day(self) -> u8 {
-		self.day
+		self.ymd().2
 	}
 
+	/// Gets the English ordinal suffix of the day of the month, which is
+	/// `st`, `nd`, `rd`, or `th`
+	///
+	/// # Example
+	///
+	/// ```
+	/// use botic::date;
+	///
+	/// assert_eq!("st", date!(2024-03-01).day_ordinal_suffix());
+	/// assert_eq!("nd", date!(2024-03-22).day_ordinal_suffix());
+	/// assert_eq!("th", date!(2024-03-12).day_ordinal_suffix());
+	/// ```
 	#[must_use]
-	pub const fn is_leap_year(self) -> bool {
-		self.year.is_leap_year()
+	pub const fn day_ordinal_suffix(self) -> &'static str {
+		match self.day() {
+			1 | 21 | 31 => "st",
+			2 | 22 => "nd",
+			3 | 23 => "rd",
+			_ => "th",
+		}
 	}
 
-	pub const fn add_years_overflowing(
-		self,
-		years: i16,
-	) -> Result<(Self, bool), LeapDayNotInLeapYearError> {
-		let (year, overflow) = self.year.overflowing_add(years);
+	/// Gets the day of the month, formatted with its English ordinal suffix,
+	/// such as `1st` or `22nd`
+	///
+	/// # Example
+	///
+	/// ```
+	/// use botic::date;
+	///
+	/// let date = date!(2024-03-01);
+	/// assert_eq!(format!("{} {}", date.month(), date.day_ordinal()), "March 1st");
+	/// ```
+	#[must_use]
+	pub const fn day_ordinal(self) -> DayOrdinal {
+		DayOrdinal(self)
+	}
 
-		if self.day == 29 && (self.month as u8) == (Month::February as u8) && !year.is_leap_year() {
-			Err(LeapDayNotInLeapYearError(self.year))
-		} else {
-			Ok((
-				Self {
-					year,
-					month: self.month,
-					day: self.day,
-				},
-				overflow,
-			))
+	/// Counts the days from `start` to `end` which are on the given day of the
+	/// week. This includes `start`, but not `end`, and it takes the same
+	/// amount of time for any length of time.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use botic::{Date, Month, Weekday};
+	///
+	/// let start = Date::from_ymd(2024.into(), Month::January, 1).unwrap();
+	/// let end = Date::from_ymd(2025.into(), Month::January, 1).unwrap();
+	/// assert_eq!(Date::count_weekday_between(start, end, Weekday::Monday), 53);
+	/// assert_eq!(Date::count_weekday_between(start, end, Weekday::Sunday), 52);
+	/// ```
+	#[must_use]
+	pub const fn count_weekday_between(start: Self, end: Self, weekday: Weekday) -> u32 {
+		if !start.is_before(end) {
+			return 0;
 		}
+
+		let days = (end.days_after_common_era - start.days_after_common_era) as u32;
+		let until_first = Self::days_until_weekday(start, weekday) as u32;
+		let partial_week = (until_first < days % 7) as u32;
+		days / 7 + partial_week
 	}
 
-	pub const fn add_months_overflowing(
-		self,
-		months: i8,
-	) -> Result<(Self, bool), DayGreaterThanMaximumForMonthError> {
-		let (month, years_to_add) = self.month.add_overflowing(months);
-		let (year, overflow) = self.year.overflowing_add(years_to_add as i16);
-		let max_days_for_month = month.days(year.is_leap_year());
+	/// Iterates over the days from `start` to `end` which are on the given day
+	/// of the week. This includes `start`, but not `end`.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use botic::{Date, Month, Weekday};
+	///
+	/// let start = Date::from_ymd(2024.into(), Month::February, 1).unwrap();
+	/// let end = Date::from_ymd(2024.into(), Month::March, 1).unwrap();
+	/// let fridays: Vec<u8> = Date::weekdays_between(start, end, Weekday::Friday)
+	///     .map(Date::day)
+	///     .collect();
+	/// assert_eq!(fridays, [2, 9, 16, 23]);
+	/// ```
+	pub fn weekdays_between(start: Self, end: Self, weekday: Weekday) -> StepBy<DateRange> {
+		// this can go past the end, but it won't overflow the i32
+		let first = start.days_after_common_era + Self::days_until_weekday(start, weekday);
+		DateRange {
+			days: first..end.days_after_common_era,
+		}
+		.step_by(7)
+	}
 
-		if self.day > max_days_for_month {
-			Err(DayGreaterThanMaximumForMonthError {
-				month,
-				given_day: self.day,
-				month_max_day: max_days_for_month,
-			})
-		} else {
-			Ok((
-				Self {
-					year,
-					month,
-					day: self.day,
-				},
-				overflow,
-			))
+	/// Gets the first day of this date's month
+	#[must_use]
+	pub const fn start_of_month(self) -> Self {
+		Self {
+			days_after_common_era: self.days_after_common_era - (self.day() as i32 - 1),
 		}
 	}
 
-	// TODO handle BCE properly
+	/// Gets the first day of this date's week, where weeks start on the given
+	/// day of the week. If the week starts before [`Date::MIN`], then
+	/// [`Date::MIN`] is returned instead.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use botic::{Date, Month, Weekday};
+	///
+	/// let thursday = Date::from_ymd(2024.into(), Month::February, 29).unwrap();
+	/// assert_eq!(thursday.start_of_week(Weekday::Monday).day(), 26);
+	/// assert_eq!(thursday.start_of_week(Weekday::Thursday), thursday);
+	/// ```
 	#[must_use]
-	pub const fn days_after_common_era(self) -> i64 {
-		let year = self.year.wrapping_sub(1);
-		let leap_years = (year.as_i16() / 4 - year.as_i16() / 100 + year.as_i16() / 400) as i64;
-		let month_last_day_ordinal =
-			self.month.previous().last_day_ordinal(self.is_leap_year()) as i64;
+	pub const fn start_of_week(self, first_day: Weekday) -> Self {
+		let days_since_start = (7 - Self::days_until_weekday(self, first_day)) % 7;
+		let days_after_common_era = self.days_after_common_era - days_since_start;
+		if days_after_common_era < Self::MIN.days_after_common_era {
+			return Self::MIN;
+		}
+
+		Self {
+			days_after_common_era,
+		}
+	}
 
-		year.as_i16() as i64 * 365 + leap_years + month_last_day_ordinal + self.day as i64 - 1
+	/// Gets the week of the month that this date is in, from 1 to 6, where
+	/// weeks start on the given day of the week. The first week is the one
+	/// which contains the first day of the month, so it may be a partial week.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use botic::{Date, Month, Weekday};
+	///
+	/// // March 2024 started on a Friday
+	/// let date = |day| Date::from_ymd(2024.into(), Month::March, day).unwrap();
+	/// assert_eq!(date(2).week_of_month(Weekday::Sunday), 1);
+	/// assert_eq!(date(3).week_of_month(Weekday::Sunday), 2);
+	/// assert_eq!(date(31).week_of_month(Weekday::Sunday), 6);
+	/// assert_eq!(date(31).week_of_month(Weekday::Monday), 5);
+	/// ```
+	#[must_use]
+	pub const fn week_of_month(self, first_day: Weekday) -> u8 {
+		let month_start = self.start_of_month();
+		let days_into_first_week = (7 - Self::days_until_weekday(month_start, first_day)) % 7;
+		((self.day() as i32 - 1 + days_into_first_week) / 7 + 1) as u8
 	}
 
-	// TODO test
+	/// Iterates over the first day of each month from `start` to `end`. This
+	/// includes `start`, but not `end`. Use [`Date::start_of_month`] to also
+	/// include the month that `start` is in.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use botic::{Date, Month};
+	///
+	/// let start = Date::from_ymd(2023.into(), Month::November, 15).unwrap();
+	/// let end = Date::from_ymd(2024.into(), Month::February, 1).unwrap();
+	/// let months: Vec<Month> = Date::month_starts_between(start, end)
+	///     .map(Date::month)
+	///     .collect();
+	/// assert_eq!(months, [Month::December, Month::January]);
+	/// ```
 	#[must_use]
-	pub const fn from_days_after_common_era(days: i64) -> Self {
-		let era = days / 146_097; // an era is a period of 400 year
-		let day_of_era = days - (era * 146_097);
-		let year_of_era = day_of_era / 365;
-		let year = year_of_era + (era * 400);
-		let ordinal = day_of_era - (365 * year + year / 4 - year / 100);
-		// TODO look at as's
-		let year = Year::from_i16(year as i16);
-		let month = Month::from_ordinal(ordinal as u16, year.is_leap_year());
-		let day = ordinal as u16 - month.previous().last_day_ordinal(year.is_leap_year());
-		let day = day as u8;
+	pub const fn month_starts_between(start: Self, end: Self) -> MonthStarts {
+		let next = if start.day() == 1 {
+			start.days_after_common_era
+		} else {
+			let month_start = start.start_of_month();
+			month_start.days_after_common_era + month_start.days_in_month() as i32
+		};
 
-		unsafe { Self::from_ymd_unchecked(year, month, day) }
+		MonthStarts {
+			next,
+			end: end.days_after_common_era,
+		}
 	}
 
+	/// Iterates over the first day of each week from `start` to `end`, where
+	/// weeks start on the given day of the week. This includes `start`, but
+	/// not `end`. Use [`Date::start_of_week`] to also include the week that
+	/// `start` is in.
+	pub fn week_starts_between(start: Self, end: Self, first_day: Weekday) -> StepBy<DateRange> {
+		Self::weekdays_between(start, end, first_day)
+	}
+
+	/// The number of days in this date's month
+	const fn days_in_month(self) -> u8 {
+		self.month().days(self.is_leap_year())
+	}
+
+	/// The number of days from this date until the next day which is on the
+	/// given day of the week, or zero if this date is on it
+	const fn days_until_weekday(self, weekday: Weekday) -> i32 {
+		let today = self.weekday().number_days_from_monday() as i32;
+		(weekday.number_days_from_monday() as i32 - today).rem_euclid(7)
+	}
+
+	/// Gets the earlier of two dates. Unlike [`Ord::min`], this can be
+	/// used in a `const`.
 	#[must_use]
-	pub const fn add_days_overflowing(self, days: i64) -> (Self, bool) {
-		let (total_days_since_ce, overflow) = self.days_after_common_era().overflowing_add(days);
-		(
-			Self::from_days_after_common_era(total_days_since_ce),
-			overflow,
-		)
+	pub const fn min(self, other: Self) -> Self {
+		if other.is_before(self) {
+			other
+		} else {
+			self
+		}
 	}
-}
 
-impl PartialOrd for Date {
-	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-		let year_ordering = self.year.cmp(&other.year);
-		let month_ordering = self.month.cmp(&other.month);
-		let day_ordering = self.day.cmp(&other.day);
-
-		if year_ordering != Ordering::Equal {
-			Some(year_ordering)
-		} else if month_ordering != Ordering::Equal {
-			Some(month_ordering)
-		} else if day_ordering != Ordering::Equal {
-			Some(day_ordering)
+	/// Gets the later of two dates. Unlike [`Ord::max`], this can be used
+	/// in a `const`.
+	#[must_use]
+	pub const fn max(self, other: Self) -> Self {
+		if other.is_before(self) {
+			self
 		} else {
-			Some(Ordering::Equal)
+			other
 		}
 	}
-}
 
-impl Ord for Date {
-	fn cmp(&self, other: &Self) -> Ordering {
-		let year_ordering = self.year.cmp(&other.year);
-		let month_ordering = self.month.cmp(&other.month);
-		let day_ordering = self.day.cmp(&other.day);
-
-		if year_ordering != Ordering::Equal {
-			year_ordering
-		} else if month_ordering != Ordering::Equal {
-			month_ordering
-		} else if day_ordering != Ordering::Equal {
-			day_ordering
+	/// Restricts the date to be from `min` to `max`. Unlike [`Ord::clamp`],
+	/// this can be used in a `const`.
+	///
+	/// # Panics
+	///
+	/// Panics if `min` is after `max`
+	#[must_use]
+	pub const fn clamp(self, min: Self, max: Self) -> Self {
+		assert!(!max.is_before(min), "the minimum is after the maximum");
+		if self.is_before(min) {
+			min
+		} else if max.is_before(self) {
+			max
 		} else {
-			Ordering::Equal
+			self
 		}
 	}
+
+	/// Checks if this date is before the other one
+	const fn is_before(self, other: Self) -> bool {
+		self.days_after_common_era < other.days_after_common_era
+	}
+
+	/// Gets the day of the year, starting with 1 on January 1
+	#[must_use]
+	pub const fn ordinal(self) -> u16 {
+		let (year, month, day) = self.ymd();
+		let days_before_month = match month {
+			Month::January => 0,
+			month => month.previous().last_day_ordinal(year.is_leap_year()),
+		};
+
+		days_before_month + day as u16
+	}
+
+	/// Gets the day of the week
+	///
+	/// # Example
+	///
+	/// ```
+	/// use botic::{Date, Month, Weekday};
+	///
+	/// let date = Date::from_ymd(2000.into(), Month::January, 1).unwrap();
+	/// assert_eq!(date.weekday(), Weekday::Saturday);
+	/// ```
+	#[must_use]
+	pub const fn weekday(self) -> Weekday {
+		// 0001-01-01 was a Monday
+		Weekday::from_days_after_monday(self.days_after_common_era as i64)
+	}
+
+	/// Combines the date with a time of day
+	///
+	/// # Example
+	///
+	/// ```
+	/// use botic::{Date, Month, Time};
+	///
+	/// let date = Date::from_ymd(2000.into(), Month::January, 1).unwrap();
+	/// let date_time = date.at(Time::from_hms(12, 0, 0).unwrap());
+	/// assert_eq!(date_time.to_string(), "2000-01-01 12:00:00");
+	/// ```
+	#[must_use]
+	pub const fn at(self, time: Time) -> NaiveDateTime {
+		NaiveDateTime::new(self, time)
+	}
+
+	/// Gets the start of the day
+	#[must_use]
+	pub const fn at_midnight(self) -> NaiveDateTime {
+		self.at(Time::MIDNIGHT)
+	}
+
+	/// Splits the date into its year, month, and day
+	const fn ymd(self) -> (Year, Month, u8) {
+		ymd_from_days(self.days_after_common_era as i64)
+	}
+
+	/// Gets the current date in the given time zone
+	#[cfg(feature = "std")]
+	#[must_use]
+	pub fn today(timezone: &impl TimeZone) -> Self {
+		NaiveDateTime::now_in(timezone).date()
+	}
+
+	#[must_use]
+	pub const fn is_leap_year(self) -> bool {
+		self.year().is_leap_year()
+	}
+
+	pub const fn add_years_overflowing(self, years: i16) -> Result<(Self, bool), ComponentRange> {
+		let (year, month, day) = self.ymd();
+		let (new_year, overflow) = year.overflowing_add(years);
+		check_range!("day", day, 1, month.days(new_year.is_leap_year()));
+
+		Ok((Self::from_ymd_unchecked(new_year, month, day), overflow))
+	}
+
+	pub const fn add_months_overflowing(self, months: i8) -> Result<(Self, bool), ComponentRange> {
+		let (year, month, day) = self.ymd();
+		let (month, years_to_add) = month.add_overflowing(months);
+		let (year, overflow) = year.overflowing_add(years_to_add as i16);
+		check_range!("day", day, 1, month.days(year.is_leap_year()));
+
+		Ok((Self::from_ymd_unchecked(year, month, day), overflow))
+	}
+
+	/// Gets the number of days since 0001-01-01. Earlier dates are negative.
+	#[must_use]
+	pub const fn days_after_common_era(self) -> i64 {
+		self.days_after_common_era as i64
+	}
+
+	/// Gets the date which is the given number of days after 0001-01-01.
+	/// Earlier dates are negative.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use botic::{Date, Month};
+	///
+	/// let date = Date::from_days_after_common_era(-1).unwrap();
+	/// assert_eq!(date, Date::from_ymd(0.into(), Month::December, 31).unwrap());
+	/// assert!(Date::from_days_after_common_era(i64::MAX).is_err());
+	/// ```
+	///
+	/// # Errors
+	///
+	/// Returns an error if the date would be outside of the range from
+	/// [`Date::MIN`] to [`Date::MAX`]
+	pub const fn from_days_after_common_era(days: i64) -> Result<Self, ComponentRange> {
+		check_range!(
+			"number of days",
+			days,
+			MIN_DAYS_AFTER_COMMON_ERA,
+			MAX_DAYS_AFTER_COMMON_ERA
+		);
+
+		Ok(Self {
+			days_after_common_era: days as i32,
+		})
+	}
+
+	/// Gets the date which is the given number of days after 0001-01-01. Days
+	/// outside of the range from [`Date::MIN`] to [`Date::MAX`] wrap around to
+	/// the other end.
+	#[must_use]
+	pub const fn from_days_after_common_era_unchecked(days: i64) -> Self {
+		Self::from_days_wrapping(days as i128).0
+	}
+
+	/// Wraps the number of days into the representable range, and reports if
+	/// it had to be wrapped
+	const fn from_days_wrapping(days: i128) -> (Self, bool) {
+		let min = MIN_DAYS_AFTER_COMMON_ERA as i128;
+		let max = MAX_DAYS_AFTER_COMMON_ERA as i128;
+		let wrapped = (days - min).rem_euclid(max - min + 1) + min;
+
+		(
+			Self {
+				days_after_common_era: wrapped as i32,
+			},
+			wrapped != days,
+		)
+	}
+
+	#[must_use]
+	pub const fn add_days_overflowing(self, days: i64) -> (Self, bool) {
+		Self::from_days_wrapping(self.days_after_common_era() as i128 + days as i128)
+	}
 }
 
 // TODO addition
 
+// These use the algorithms from "Euclidean affine functions and their
+// application to calendar algorithms" by Cassio Neri and Lorenz Schneider. The
+// calendar is shifted so that years start in March, which puts the leap day at
+// the end of the year, and then shifted forward by whole 400-year eras so that
+// every representable date is positive.
+
+/// The number of 400-year eras that the calendar is shifted forward by
+const ERAS_SHIFTED: u32 = 82;
+
+/// The number of days from the start of the shifted calendar to 0001-01-01
+const DAYS_SHIFTED: u32 = 306 + 146_097 * ERAS_SHIFTED;
+
+/// The number of years that the calendar is shifted forward by
+const YEARS_SHIFTED: u32 = 400 * ERAS_SHIFTED;
+
+/// Gets the number of days from 0001-01-01 to the given date
+const fn days_from_ymd(year: Year, month: Month, day: u8) -> i64 {
+	let in_january_or_february = (month.number() <= 2) as u32;
+	let year = (year.as_i16() as i32 as u32).wrapping_add(YEARS_SHIFTED) - in_january_or_february;
+	let month = month.number() as u32 + 12 * in_january_or_february;
+	let century = year / 100;
+
+	let year_days = 1461 * year / 4 - century + century / 4;
+	let month_days = (979 * month - 2919) / 32;
+	let days = year_days + month_days + day as u32 - 1;
+
+	days as i64 - DAYS_SHIFTED as i64
+}
+
+/// Gets the date which is the given number of days after 0001-01-01
+const fn ymd_from_days(days: i64) -> (Year, Month, u8) {
+	let days = (days + DAYS_SHIFTED as i64) as u32;
+
+	let n = 4 * days + 3;
+	let century = n / 146_097;
+	let day_of_century = n % 146_097 / 4;
+
+	let n = 4 * day_of_century as u64 + 3;
+	let p = 2_939_745 * n;
+	let year_of_century = (p >> 32) as u32;
+	let day_of_year = (p as u32) / 2_939_745 / 4;
+	let year = 100 * century + year_of_century;
+
+	let n = 2141 * day_of_year + 197_913;
+	let month = n / 65536;
+	let day = n % 65536 / 2141 + 1;
+
+	let after_december = day_of_year >= 306;
+	let year = year.wrapping_sub(YEARS_SHIFTED) as i32 + after_december as i32;
+	let month = month - 12 * after_december as u32;
+
+	let month = match Month::from_u8(month as u8) {
+		Some(month) => month,
+		None => unreachable!(),
+	};
+
+	(Year::from_i16(year as i16), month, day as u8)
+}
+
 impl Display for Date {
 	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
 		write!(
 			f,
 			"{:0width$}-{:02}-{:02}",
-			self.year,
-			self.month as u8,
-			self.day,
+			self.year(),
+			self.month() as u8,
+			self.day(),
 			width = 4 + usize::from(self.year() < 0.into())
 		)
 	}
 }
+
+/// A day of the month which is formatted with its ordinal suffix. See
+/// [`Date::day_ordinal`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct DayOrdinal(Date);
+
+impl Display for DayOrdinal {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(f, "{}{}", self.0.day(), self.0.day_ordinal_suffix())
+	}
+}
+
+/// An iterator over each day in a range of dates.
+///
+/// [`core::iter::Step`] isn't stable yet, so `start..=end` can't be iterated
+/// directly. Instead, the range can be converted into a `DateRange`.
+///
+/// # Example
+///
+/// ```
+/// use botic::{Date, DateRange, Month};
+///
+/// let start = Date::from_ymd(2024.into(), Month::February, 27).unwrap();
+/// let end = Date::from_ymd(2024.into(), Month::March, 1).unwrap();
+///
+/// let days: Vec<u8> = DateRange::from(start..=end).map(Date::day).collect();
+/// assert_eq!(days, [27, 28, 29, 1]);
+/// assert_eq!(DateRange::from(start..end).len(), 3);
+/// ```
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct DateRange {
+	days: Range<i32>,
+}
+
+impl DateRange {
+	/// Creates an iterator from `start` to `end`, including both. If `end` is
+	/// before `start`, then the iterator is empty.
+	#[must_use]
+	pub const fn inclusive(start: Date, end: Date) -> Self {
+		// the maximum date is far from the end of an i32, so this can't overflow
+		Self {
+			days: start.days_after_common_era..end.days_after_common_era + 1,
+		}
+	}
+
+	/// Creates an iterator from `start` to `end`, including `start`, but not
+	/// `end`. If `end` is not after `start`, then the iterator is empty.
+	#[must_use]
+	pub const fn exclusive(start: Date, end: Date) -> Self {
+		Self {
+			days: start.days_after_common_era..end.days_after_common_era,
+		}
+	}
+}
+
+impl From<Range<Date>> for DateRange {
+	fn from(range: Range<Date>) -> Self {
+		Self::exclusive(range.start, range.end)
+	}
+}
+
+impl From<RangeInclusive<Date>> for DateRange {
+	fn from(range: RangeInclusive<Date>) -> Self {
+		let (start, end) = range.into_inner();
+		Self::inclusive(start, end)
+	}
+}
+
+impl Iterator for DateRange {
+	type Item = Date;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		self.days.next().map(|days| Date {
+			days_after_common_era: days,
+		})
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.days.size_hint()
+	}
+
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		self.days.nth(n).map(|days| Date {
+			days_after_common_era: days,
+		})
+	}
+}
+
+impl DoubleEndedIterator for DateRange {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.days.next_back().map(|days| Date {
+			days_after_common_era: days,
+		})
+	}
+}
+
+impl ExactSizeIterator for DateRange {}
+
+impl FusedIterator for DateRange {}
+
+/// An iterator over the first day of each month in a range of dates. See
+/// [`Date::month_starts_between`].
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct MonthStarts {
+	/// The days after the common era of the next month's start
+	next: i32,
+	end: i32,
+}
+
+impl Iterator for MonthStarts {
+	type Item = Date;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.next >= self.end {
+			return None;
+		}
+
+		let date = Date {
+			days_after_common_era: self.next,
+		};
+		// the maximum date is far from the end of an i32, so this can't overflow
+		self.next += date.days_in_month() as i32;
+		Some(date)
+	}
+}
+
+impl FusedIterator for MonthStarts {}
+
+#[cfg(test)]
+mod tests {
+	#[cfg(feature = "alloc")]
+	use alloc::{string::ToString, vec::Vec};
+
+	use super::*;
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn today() {
+		use crate::{
+			timezone::{Utc, UtcOffset},
+			DateTime,
+		};
+
+		let ahead = UtcOffset::from_hours_unchecked(14);
+		let before = DateTime::<Utc>::now();
+		let today = Date::today(&ahead);
+		let after = DateTime::<Utc>::now();
+
+		let local_date =
+			|utc: DateTime<Utc>| utc.into_timezone(ahead).to_naive_local_saturating().date();
+		assert!(today == local_date(before) || today == local_date(after));
+		assert!(Date::today(&Utc) >= before.naive_utc().date());
+	}
+	#[test]
+	fn day_out_of_range() {
+		let error = Date::from_ymd(2023.into(), Month::February, 29).unwrap_err();
+		assert_eq!(error.name(), "day");
+		assert_eq!(
+			(error.value(), error.minimum(), error.maximum()),
+			(29, 1, 28)
+		);
+		assert!(Date::from_ymd(2024.into(), Month::February, 29).is_ok());
+		assert!(Date::from_ymd(2024.into(), Month::April, 0).is_err());
+
+		let leap_day = Date::from_ymd(2024.into(), Month::February, 29).unwrap();
+		assert_eq!(leap_day.add_years_overflowing(1).unwrap_err().maximum(), 28);
+	}
+
+	#[test]
+	fn unchecked_rolls_over() {
+		let date = |year: i16, month, day| Date::from_ymd_unchecked(year.into(), month, day);
+		assert_eq!(date(2023, Month::February, 29), date(2023, Month::March, 1));
+		assert_eq!(date(2024, Month::March, 0), date(2024, Month::February, 29));
+		assert_eq!(date(i16::MAX, Month::December, 32), Date::MIN);
+		assert_eq!(
+			Date::from_days_after_common_era_unchecked(Date::MIN.days_after_common_era() - 1),
+			Date::MAX
+		);
+		assert_eq!(Date::MAX.add_days_overflowing(1), (Date::MIN, true));
+		assert!(Date::MIN.add_days_overflowing(i64::MIN).1);
+	}
+
+	#[test]
+	fn ordinal() {
+		let ordinal =
+			|year: i16, month, day| Date::from_ymd(year.into(), month, day).unwrap().ordinal();
+		assert_eq!(ordinal(2023, Month::January, 1), 1);
+		assert_eq!(ordinal(2023, Month::March, 1), 60);
+		assert_eq!(ordinal(2024, Month::March, 1), 61);
+		assert_eq!(ordinal(2024, Month::December, 31), 366);
+	}
+
+	#[test]
+	fn date_range() {
+		assert_eq!(DateRange::from(Date::MAX..=Date::MAX).count(), 1);
+		assert_eq!(DateRange::from(Date::MAX..Date::MAX).count(), 0);
+		assert_eq!(DateRange::from(Date::MAX..=Date::MIN).count(), 0);
+
+		let start = Date::from_ymd(2023.into(), Month::December, 30).unwrap();
+		let end = Date::from_ymd(2024.into(), Month::January, 2).unwrap();
+		let mut range = DateRange::from(start..=end);
+		assert_eq!(range.len(), 4);
+		assert_eq!(range.next(), Some(start));
+		assert_eq!(range.next_back(), Some(end));
+		assert_eq!(range.nth(1).map(Date::day), Some(1));
+		assert_eq!(range.next(), None);
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn count_weekdays() {
+		let start = Date::from_ymd(2023.into(), Month::January, 1).unwrap();
+		for length in 0..30 {
+			let end = start.add_days_overflowing(length).0;
+			for weekday in [Weekday::Monday, Weekday::Thursday, Weekday::Sunday] {
+				let counted = DateRange::from(start..end)
+					.filter(|date| date.weekday() == weekday)
+					.count();
+				assert_eq!(
+					Date::count_weekday_between(start, end, weekday) as usize,
+					counted
+				);
+				assert_eq!(
+					Date::weekdays_between(start, end, weekday).collect::<Vec<_>>(),
+					DateRange::from(start..end)
+						.filter(|date| date.weekday() == weekday)
+						.collect::<Vec<_>>()
+				);
+			}
+		}
+
+		assert_eq!(
+			Date::count_weekday_between(Date::MAX, Date::MIN, Weekday::Monday),
+			0
+		);
+		let all = Date::count_weekday_between(Date::MIN, Date::MAX, Weekday::Monday);
+		assert_eq!(
+			all,
+			Date::weekdays_between(Date::MIN, Date::MAX, Weekday::Monday).len() as u32
+		);
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn month_and_week_starts() {
+		let date = |year: i16, month, day| Date::from_ymd(year.into(), month, day).unwrap();
+		let start = date(2024, Month::January, 1);
+		let end = date(2025, Month::January, 1);
+		let months: Vec<_> = Date::month_starts_between(start, end).collect();
+		assert_eq!(months.len(), 12);
+		assert!(months.iter().all(|month| month.day() == 1));
+		assert_eq!(months[2], date(2024, Month::March, 1));
+
+		assert_eq!(
+			Date::month_starts_between(Date::MAX, Date::MIN).next(),
+			None
+		);
+		assert_eq!(
+			Date::month_starts_between(Date::MAX.start_of_month(), Date::MAX).count(),
+			1
+		);
+
+		let sundays = Date::week_starts_between(start, end, Weekday::Sunday);
+		assert_eq!(sundays.len(), 52);
+		assert_eq!(
+			date(2024, Month::January, 6).start_of_week(Weekday::Sunday),
+			date(2023, Month::December, 31)
+		);
+		assert_eq!(Date::MIN.start_of_week(Date::MIN.weekday()), Date::MIN);
+		assert_eq!(
+			Date::MIN.start_of_week(Date::MIN.weekday().next()),
+			Date::MIN
+		);
+		assert_eq!(date(2024, Month::March, 31).start_of_month(), months[2]);
+	}
+
+	#[test]
+	fn week_of_month() {
+		// the weeks are counted the same way as the rows of a calendar
+		for weekday in [Weekday::Monday, Weekday::Wednesday, Weekday::Sunday] {
+			let start = Date::from_ymd(2024.into(), Month::January, 1).unwrap();
+			let mut week = 1;
+			for date in
+				DateRange::from(start..Date::from_ymd(2025.into(), Month::January, 1).unwrap())
+			{
+				if date.day() == 1 {
+					week = 1;
+				} else if date.weekday() == weekday {
+					week += 1;
+				}
+				assert_eq!(date.week_of_month(weekday), week, "{date}");
+			}
+		}
+	}
+
+	#[test]
+	fn four_bytes() {
+		assert_eq!(core::mem::size_of::<Date>(), 4);
+	}
+
+	#[test]
+	fn ymd_round_trip() {
+		let date = Date::from_ymd(2024.into(), Month::February, 29).unwrap();
+		assert_eq!(date.year(), Year::from(2024));
+		assert_eq!(date.month(), Month::February);
+		assert_eq!(date.day(), 29);
+		assert!(date < Date::from_ymd(2024.into(), Month::March, 1).unwrap());
+	}
+
+	#[test]
+	fn days_after_common_era() {
+		let date = |year: i16, month, day| Date::from_ymd(year.into(), month, day).unwrap();
+		assert_eq!(date(1, Month::January, 1).days_after_common_era(), 0);
+		assert_eq!(date(0, Month::December, 31).days_after_common_era(), -1);
+		assert_eq!(date(0, Month::March, 1).days_after_common_era(), -306);
+		assert_eq!(date(0, Month::February, 29).days_after_common_era(), -307);
+		assert_eq!(date(-1, Month::December, 31).days_after_common_era(), -367);
+		assert_eq!(
+			date(1970, Month::January, 1).days_after_common_era(),
+			719_162
+		);
+		assert_eq!(date(2000, Month::March, 1).days_after_common_era(), 730_179);
+	}
+
+	#[test]
+	fn leap_days_match_start_of_year() {
+		for year in [
+			i16::MIN,
+			-401,
+			-100,
+			-4,
+			-1,
+			0,
+			1,
+			4,
+			100,
+			400,
+			2024,
+			i16::MAX,
+		] {
+			let year = Year::from(year);
+			let start = Date::from_ymd(year, Month::January, 1).unwrap();
+			let days = 365 * (i64::from(year.as_i16()) - 1) + i64::from(year.leap_days_since_ce());
+			assert_eq!(start.days_after_common_era(), days, "{year}");
+		}
+	}
+
+	#[test]
+	fn from_days_after_common_era() {
+		let days = |days| {
+			let date = Date::from_days_after_common_era(days).unwrap();
+			(date.year().as_i16(), date.month(), date.day())
+		};
+		assert_eq!(days(0), (1, Month::January, 1));
+		assert_eq!(days(-1), (0, Month::December, 31));
+		assert_eq!(days(-307), (0, Month::February, 29));
+		assert_eq!(days(-367), (-1, Month::December, 31));
+		assert_eq!(days(-366), (0, Month::January, 1));
+		assert_eq!(days(730_179), (2000, Month::March, 1));
+
+		let error = Date::from_days_after_common_era(Date::MAX.days_after_common_era() + 1);
+		assert_eq!(error.unwrap_err().name(), "number of days");
+		assert_eq!(
+			Date::from_days_after_common_era(Date::MIN.days_after_common_era()),
+			Ok(Date::MIN)
+		);
+		assert!(Date::from_days_after_common_era(i64::MIN).is_err());
+	}
+
+	#[test]
+	fn consecutive_days() {
+		let mut previous = Date::from_days_after_common_era_unchecked(-800_000).ymd();
+		for days in -799_999..800_000 {
+			let date = Date::from_days_after_common_era_unchecked(days);
+			let (year, month, day) = date.ymd();
+			assert_eq!(
+				Date::from_ymd(year, month, day)
+					.unwrap()
+					.days_after_common_era(),
+				days
+			);
+
+			if day == 1 {
+				assert_eq!(previous.2, previous.1.days(previous.0.is_leap_year()));
+			} else {
+				assert_eq!((previous.0, previous.1, previous.2 + 1), (year, month, day));
+			}
+
+			previous = (year, month, day);
+		}
+	}
+
+	#[test]
+	fn add_negative_months() {
+		let date = Date::from_ymd(2024.into(), Month::January, 15).unwrap();
+		assert_eq!(
+			date.add_months_overflowing(-2),
+			Ok((
+				Date::from_ymd(2023.into(), Month::November, 15).unwrap(),
+				false
+			))
+		);
+		assert_eq!(
+			date.add_months_overflowing(-25),
+			Ok((
+				Date::from_ymd(2021.into(), Month::December, 15).unwrap(),
+				false
+			))
+		);
+
+		let date = Date::from_ymd(2024.into(), Month::January, 31).unwrap();
+		assert!(date.add_months_overflowing(-2).is_err());
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn day_ordinals() {
+		let march = |day| Date::from_ymd(2024.into(), Month::March, day).unwrap();
+		let suffixes: [_; 31] = core::array::from_fn(|i| march(i as u8 + 1).day_ordinal_suffix());
+		assert_eq!(&suffixes[..4], ["st", "nd", "rd", "th"]);
+		assert_eq!(&suffixes[10..13], ["th", "th", "th"]);
+		assert_eq!(&suffixes[20..24], ["st", "nd", "rd", "th"]);
+		assert_eq!(&suffixes[29..], ["th", "st"]);
+		assert_eq!(march(11).day_ordinal().to_string(), "11th");
+	}
+
+	#[test]
+	fn extremes() {
+		assert_eq!(Date::MIN.year(), Year::MIN);
+		assert_eq!(Date::MIN.month(), Month::January);
+		assert_eq!(Date::MIN.day(), 1);
+		assert_eq!(Date::MAX.year(), Year::MAX);
+		assert_eq!(Date::MAX.month(), Month::December);
+		assert_eq!(Date::MAX.day(), 31);
+	}
+}
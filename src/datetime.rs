@@ -1,19 +1,48 @@
+#[cfg(feature = "std")]
+use crate::{sys, timezone::IndeterminateOffsetError};
 use crate::{
-	date::{DayGreaterThanMaximumForMonthError, LeapDayNotInLeapYearError},
-	tai::Tai,
-	timezone::{Utc, UtcOffset},
-	Date, Month, Time, TimeZone, Timestamp, Year,
+	tai::{
+		self, GlobalLeapSeconds, InvalidLeapSecond, LeapSecondPolicy, LeapSecondProvider,
+		LeapSecondSlice, Tai, Tt, UnexpectedLeapSecond,
+	},
+	timezone::{LocalTimeError, LocalTimePolicy, Utc, UtcOffset},
+	ComponentRange, Date, KeepLocalError, Month, OutOfRange, Time, TimeZone, Timestamp, UnixNanos,
+	Weekday, Year,
 };
 
+use core::time::Duration;
 use core::{cmp::Ordering, fmt::Display, hash::Hash};
-use std::time::SystemTime;
 
-#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+/// A date and time, without a time zone.
+///
+/// This is stored as the number of days since the Unix epoch and a [`Time`],
+/// so comparisons and arithmetic don't need to split it into a [`Date`].
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
 pub struct NaiveDateTime {
-	date: Date,
+	/// days since 1970-01-01
+	days: i32,
 	time: Time,
 }
 
+const NANOS_PER_SECOND: u64 = 1_000_000_000;
+const NANOS_PER_DAY: u64 = 86_400 * NANOS_PER_SECOND;
+
+const UNIX_EPOCH_DAYS_AFTER_CE: i64 = Date::UNIX_EPOCH.days_after_common_era();
+const MIN_DAYS: i64 = Date::MIN.days_after_common_era() - UNIX_EPOCH_DAYS_AFTER_CE;
+const MAX_DAYS: i64 = Date::MAX.days_after_common_era() - UNIX_EPOCH_DAYS_AFTER_CE;
+
+/// Converts a number of nanoseconds into a [`Duration`], which is zero if the
+/// number is negative
+fn duration_from_nanos(nanoseconds: i128) -> Duration {
+	let nanoseconds = nanoseconds.max(0);
+	let seconds = nanoseconds / i128::from(NANOS_PER_SECOND);
+	let subsec_nanos = (nanoseconds % i128::from(NANOS_PER_SECOND)) as u32;
+	match u64::try_from(seconds) {
+		Ok(seconds) => Duration::new(seconds, subsec_nanos),
+		Err(_) => Duration::MAX,
+	}
+}
+
 #[derive(Copy, Clone, Eq, Debug)]
 pub struct DateTime<Tz: TimeZone> {
 	utc_datetime: NaiveDateTime,
@@ -21,38 +50,92 @@ pub struct DateTime<Tz: TimeZone> {
 }
 
 impl<Tz: TimeZone> DateTime<Tz> {
-	// TODO unix epoch constant
 	// TODO docs
 
-	pub fn from_utc(utc_datetime: NaiveDateTime, timezone: Tz) -> Self {
+	pub const fn from_utc(utc_datetime: NaiveDateTime, timezone: Tz) -> Self {
 		Self {
 			utc_datetime,
 			timezone,
 		}
 	}
 
+	/// Creates a date and time from a Unix timestamp, in the given time zone
+	///
+	/// # Errors
+	///
+	/// Returns an error if the timestamp is outside of the range from
+	/// [`NaiveDateTime::MIN`] to [`NaiveDateTime::MAX`]
+	pub fn from_unix_timestamp(timestamp: Timestamp, timezone: Tz) -> Result<Self, OutOfRange> {
+		let utc_datetime = NaiveDateTime::try_from(timestamp)?;
+		Ok(Self::from_utc(utc_datetime, timezone))
+	}
+
 	pub fn from_local(local_datetime: NaiveDateTime, timezone: Tz) -> Result<Self, Tz::Err> {
 		let offset = timezone.offset_from_local_naive(local_datetime)?;
 		// TODO overflow
 		let utc_datetime = local_datetime
-			.add_seconds_overflowing(-offset.seconds_ahead() as i64)
+			.add_offset_overflowing(-offset.seconds_ahead())
 			.0;
 
 		Ok(Self::from_utc(utc_datetime, timezone))
 	}
 
-	pub fn system_time(timezone: Tz) -> Self {
-		let system_time = SystemTime::now();
-		let (seconds, nanoseconds) = match system_time.duration_since(SystemTime::UNIX_EPOCH) {
-			Ok(duration) => (duration.as_secs() as i64, duration.subsec_nanos()),
-			Err(ste) => (
-				-(ste.duration().as_secs() as i64),
-				ste.duration().subsec_nanos(),
-			),
+	/// Creates a date and time from the local time in the given time zone,
+	/// using the policy if the local time was skipped or repeated by a change
+	/// in the offset.
+	///
+	/// The offset is assumed to change at most once in the day before or after
+	/// the local time.
+	///
+	/// # Errors
+	///
+	/// Returns an error if the policy is [`LocalTimePolicy::Reject`] and the
+	/// local time was skipped or repeated, or if the date and time in UTC is
+	/// out of range
+	pub fn from_local_with_policy(
+		local_datetime: NaiveDateTime,
+		timezone: Tz,
+		policy: LocalTimePolicy,
+	) -> Result<Self, LocalTimeError> {
+		let utc_with_offset = |offset: UtcOffset| match local_datetime
+			.add_offset_overflowing(-offset.seconds_ahead())
+		{
+			(utc, false) => Ok(utc),
+			(_, true) => Err(LocalTimeError::OutOfRange),
+		};
+		let offset_near = |days| {
+			let utc = local_datetime.add_days_overflowing(days).0;
+			timezone.utc_offset(DateTime::from_utc(utc, Utc))
+		};
+
+		// the offset before a change gives the earlier instant
+		let (before, after) = (offset_near(-1), offset_near(1));
+		let valid = |offset| {
+			let utc = utc_with_offset(offset)?;
+			let actual = timezone.utc_offset(DateTime::from_utc(utc, Utc));
+			Ok::<_, LocalTimeError>((actual == offset).then_some(utc))
 		};
-		let timestamp = Timestamp::new(seconds, nanoseconds);
-		let naive_dt = NaiveDateTime::from_timestamp(timestamp);
 
+		let utc = match (valid(before)?, valid(after)?) {
+			(Some(earlier), Some(later)) if earlier != later => match policy {
+				LocalTimePolicy::Compatible | LocalTimePolicy::Earlier => earlier,
+				LocalTimePolicy::Later => later,
+				LocalTimePolicy::Reject => return Err(LocalTimeError::Repeated),
+			},
+			(Some(utc), _) | (None, Some(utc)) => utc,
+			(None, None) => match policy {
+				LocalTimePolicy::Earlier => utc_with_offset(after)?,
+				LocalTimePolicy::Compatible | LocalTimePolicy::Later => utc_with_offset(before)?,
+				LocalTimePolicy::Reject => return Err(LocalTimeError::Skipped),
+			},
+		};
+
+		Ok(Self::from_utc(utc, timezone))
+	}
+
+	#[cfg(feature = "std")]
+	pub fn system_time(timezone: Tz) -> Self {
+		let naive_dt = NaiveDateTime::from_timestamp(Timestamp::now());
 		Self::from_utc(naive_dt, timezone)
 	}
 
@@ -61,88 +144,767 @@ impl<Tz: TimeZone> DateTime<Tz> {
 		self.timezone.utc_offset(utc)
 	}
 
-	pub fn timezone(&self) -> &Tz {
+	pub const fn timezone(&self) -> &Tz {
 		&self.timezone
 	}
 
-	pub fn naive_utc(&self) -> NaiveDateTime {
+	pub const fn naive_utc(&self) -> NaiveDateTime {
 		self.utc_datetime
 	}
 
-	pub fn to_naive_overflowing(&self) -> (NaiveDateTime, bool) {
+	/// Gets the date and time in this time zone.
+	///
+	/// A leap second stays a leap second if the offset is a whole number of
+	/// minutes. Otherwise, it's counted as part of the second after it.
+	///
+	/// # Errors
+	///
+	/// Returns an error if applying the offset moves the date and time outside
+	/// of the range from [`NaiveDateTime::MIN`] to [`NaiveDateTime::MAX`]
+	pub fn to_naive_local(&self) -> Result<NaiveDateTime, OutOfRange> {
+		match self.local_overflowing() {
+			(local, false) => Ok(local),
+			(_, true) => Err(OutOfRange),
+		}
+	}
+
+	/// Gets the date and time in this time zone. If applying the offset moves
+	/// it out of range, then this is clamped to [`NaiveDateTime::MIN`] or
+	/// [`NaiveDateTime::MAX`].
+	#[must_use]
+	pub fn to_naive_local_saturating(&self) -> NaiveDateTime {
+		match self.to_naive_local() {
+			Ok(local) => local,
+			Err(OutOfRange) if self.offset().seconds_ahead() > 0 => NaiveDateTime::MAX,
+			Err(OutOfRange) => NaiveDateTime::MIN,
+		}
+	}
+
+	/// Applies the offset, keeping a leap second if it can still be at the end
+	/// of a minute
+	fn local_overflowing(&self) -> (NaiveDateTime, bool) {
 		self.utc_datetime
-			.add_seconds_overflowing(self.offset().seconds_ahead().into())
+			.add_offset_overflowing(self.offset().seconds_ahead())
+	}
+
+	/// Gets the year in this time zone
+	#[must_use]
+	pub fn year(&self) -> Year {
+		self.to_naive_local_saturating().year()
+	}
+
+	/// Gets the month in this time zone
+	#[must_use]
+	pub fn month(&self) -> Month {
+		self.to_naive_local_saturating().month()
+	}
+
+	/// Gets the day of the month in this time zone
+	#[must_use]
+	pub fn day(&self) -> u8 {
+		self.to_naive_local_saturating().day()
+	}
+
+	/// Gets the day of the week in this time zone
+	#[must_use]
+	pub fn weekday(&self) -> Weekday {
+		self.to_naive_local_saturating().weekday()
+	}
+
+	/// Gets the hour in this time zone
+	#[must_use]
+	pub fn hour(&self) -> u8 {
+		self.to_naive_local_saturating().hour()
+	}
+
+	/// Gets the minute in this time zone. This can differ from the minute in
+	/// UTC, since some offsets aren't a whole number of hours.
+	#[must_use]
+	pub fn minute(&self) -> u8 {
+		self.to_naive_local_saturating().minute()
+	}
+
+	/// Gets the second in this time zone
+	#[must_use]
+	pub fn second(&self) -> u8 {
+		self.to_naive_local_saturating().second()
+	}
+
+	/// Gets the same local time a number of days later, in the same time zone.
+	///
+	/// The offset is recalculated for the new date, so a daily alarm stays at
+	/// the same time on the clock after daylight saving time starts or ends.
+	/// If the local time is skipped or repeated on the new date, then the
+	/// policy decides which instant to use.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use botic::{datetime, DateTime};
+	/// use botic::timezone::{LocalTimePolicy, UtcOffset};
+	///
+	/// let alarm = DateTime::from_local(datetime!(2024-03-10 07:00), UtcOffset::UTC).unwrap();
+	/// let tomorrow = alarm.same_local_time_after_days(1, LocalTimePolicy::Reject).unwrap();
+	/// assert_eq!(tomorrow.to_naive_local(), Ok(datetime!(2024-03-11 07:00)));
+	/// ```
+	///
+	/// # Errors
+	///
+	/// Returns an error if the policy is [`LocalTimePolicy::Reject`] and the
+	/// local time was skipped or repeated, or if the new date and time is out
+	/// of range
+	pub fn same_local_time_after_days(
+		&self,
+		days: i64,
+		policy: LocalTimePolicy,
+	) -> Result<Self, LocalTimeError>
+	where
+		Tz: Clone,
+	{
+		let local = self
+			.to_naive_local()
+			.map_err(|_| LocalTimeError::OutOfRange)?;
+		match local.add_days_overflowing(days) {
+			(local, false) => Self::from_local_with_policy(local, self.timezone.clone(), policy),
+			(_, true) => Err(LocalTimeError::OutOfRange),
+		}
+	}
+
+	/// Changes the local date and time, keeping the same time zone. The offset
+	/// is recalculated for the new local time, and the policy decides which
+	/// instant to use if it was skipped or repeated.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use botic::{datetime, time, DateTime, NaiveDateTime};
+	/// use botic::timezone::{LocalTimePolicy, UtcOffset};
+	///
+	/// let now = DateTime::from_local(datetime!(2024-03-10 15:42), UtcOffset::UTC).unwrap();
+	/// let meeting = now
+	///     .map_local(|local| NaiveDateTime::new(local.date(), time!(09:00)), LocalTimePolicy::Reject)
+	///     .unwrap();
+	/// assert_eq!(meeting.to_naive_local(), Ok(datetime!(2024-03-10 09:00)));
+	/// ```
+	///
+	/// # Errors
+	///
+	/// Returns an error if the policy is [`LocalTimePolicy::Reject`] and the
+	/// new local time was skipped or repeated, or if either local time is out
+	/// of range
+	pub fn map_local(
+		&self,
+		f: impl FnOnce(NaiveDateTime) -> NaiveDateTime,
+		policy: LocalTimePolicy,
+	) -> Result<Self, LocalTimeError>
+	where
+		Tz: Clone,
+	{
+		let local = self
+			.to_naive_local()
+			.map_err(|_| LocalTimeError::OutOfRange)?;
+		Self::from_local_with_policy(f(local), self.timezone.clone(), policy)
 	}
 
-	pub fn into_timezone<NewZone: TimeZone>(&self, timezone: NewZone) -> DateTime<NewZone> {
+	pub const fn into_timezone<NewZone: TimeZone>(&self, timezone: NewZone) -> DateTime<NewZone> {
 		DateTime::<NewZone>::from_utc(self.utc_datetime, timezone)
 	}
 
-	pub fn as_utc(&self) -> DateTime<Utc> {
+	/// Reinterprets the local date and time in a different time zone.
+	///
+	/// Unlike [`DateTime::into_timezone`], which keeps the same instant and
+	/// changes the local time, this keeps the same local time and changes the
+	/// instant. For example, 09:00 in UTC-5 becomes 09:00 in UTC+1, which is
+	/// six hours earlier.
+	///
+	/// # Errors
+	///
+	/// Returns an error if the local time doesn't exist in the new time zone,
+	/// or if the local time or the new instant is out of range
+	pub fn with_timezone_keep_local<NewZone: TimeZone>(
+		&self,
+		timezone: NewZone,
+	) -> Result<DateTime<NewZone>, KeepLocalError<NewZone::Err>> {
+		let local = self.to_naive_local()?;
+		let offset = timezone
+			.offset_from_local_naive(local)
+			.map_err(KeepLocalError::TimeZone)?;
+		match local.add_offset_overflowing(-offset.seconds_ahead()) {
+			(utc, false) => Ok(DateTime::from_utc(utc, timezone)),
+			(_, true) => Err(OutOfRange.into()),
+		}
+	}
+
+	pub const fn as_utc(&self) -> DateTime<Utc> {
 		self.into_timezone(Utc)
 	}
 
 	pub fn as_tai(&self) -> DateTime<Tai> {
-		self.into_timezone(Tai)
+		self.into_timezone(Tai::new())
+	}
+
+	/// Checks if this is during a leap second, according to the global leap
+	/// second table
+	#[must_use]
+	pub fn is_leap_second(&self) -> bool {
+		self.utc_datetime.is_leap_second()
 	}
 
-	pub fn unix_timestamp(&self) -> Timestamp {
+	pub const fn unix_timestamp(&self) -> Timestamp {
 		self.utc_datetime.timestamp()
 	}
 
-	// TODO should this overflow?
+	/// Gets the number of nanoseconds since the Unix epoch. A leap second is
+	/// counted as part of the second after it.
+	#[must_use]
+	pub const fn unix_nanos(&self) -> UnixNanos {
+		self.utc_datetime.unix_nanos()
+	}
+
+	/// Gets the date and time in UTC, using the given policy if it is a leap
+	/// second
+	///
+	/// # Errors
+	///
+	/// Returns an error if this is a leap second, and the policy is
+	/// [`LeapSecondPolicy::Error`]
+	pub fn naive_utc_with_policy(
+		&self,
+		policy: LeapSecondPolicy,
+	) -> Result<NaiveDateTime, UnexpectedLeapSecond> {
+		policy.resolve(self.as_utc())
+	}
+
+	/// Gets the Unix timestamp, using the given policy if this is a leap second
+	///
+	/// # Errors
+	///
+	/// Returns an error if this is a leap second, and the policy is
+	/// [`LeapSecondPolicy::Error`]
+	pub fn unix_timestamp_with_policy(
+		&self,
+		policy: LeapSecondPolicy,
+	) -> Result<Timestamp, UnexpectedLeapSecond> {
+		Ok(self.naive_utc_with_policy(policy)?.timestamp())
+	}
+
+	/// Gets the number of seconds since 1970-01-01 00:00:00 TAI. Unlike a Unix
+	/// timestamp, this counts leap seconds.
+	#[must_use]
 	pub fn tai_timestamp(&self) -> Timestamp {
-		self.as_tai().to_naive_overflowing().0.timestamp()
+		let tai_offset = GlobalLeapSeconds.tai_offset_at_utc(self.as_utc());
+		let nanoseconds = self.unix_timestamp().as_nanos()
+			+ i128::from(tai_offset) * i128::from(NANOS_PER_SECOND);
+
+		// a date and time is always far from the limits of a timestamp
+		Timestamp::from_nanos(nanoseconds).unwrap_or(Timestamp::MAX)
 	}
 
+	/// Gets the number of seconds since 1970-01-01 00:00:00 in Terrestrial Time.
+	/// See [`Tt`] for details.
 	#[must_use]
-	pub fn add_seconds_overflowing(self, seconds: i64) -> (Self, bool) {
-		let (tai_timestamp, overflow) = self.tai_timestamp().add_seconds_overflowing(seconds);
-		let tai_naive_dt = NaiveDateTime::from_timestamp(tai_timestamp);
-		let tai_dt = DateTime::from_local(tai_naive_dt, Tai).unwrap();
+	pub fn tt_timestamp(&self) -> Timestamp {
+		Tt::new().timestamp(self)
+	}
+
+	/// Gets the number of nanoseconds from `now` until this time, which is
+	/// negative if this time is earlier. Leap seconds are counted.
+	fn nanoseconds_after<Other: TimeZone>(&self, now: &DateTime<Other>) -> i128 {
+		self.tai_timestamp().as_nanos() - now.tai_timestamp().as_nanos()
+	}
+
+	/// Gets the number of nanoseconds from now until this time, which is
+	/// negative if this time has passed. Leap seconds are counted.
+	#[cfg(feature = "std")]
+	fn nanoseconds_from_now(&self) -> i128 {
+		self.nanoseconds_after(&DateTime::<Utc>::now())
+	}
+
+	/// Gets the amount of time from `now` until this time. If this time is
+	/// before `now`, then this returns zero. Leap seconds are counted.
+	///
+	/// See [`Countdown`](crate::Countdown) for showing the result.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use core::time::Duration;
+	/// use botic::datetime;
+	///
+	/// let deadline = datetime!(2017-01-01 00:00 UTC);
+	/// let now = datetime!(2016-12-31 23:59 UTC);
+	/// assert_eq!(deadline.time_until(&now), Duration::from_secs(61));
+	/// assert_eq!(now.time_until(&deadline), Duration::ZERO);
+	/// ```
+	#[must_use]
+	pub fn time_until<Other: TimeZone>(&self, now: &DateTime<Other>) -> Duration {
+		duration_from_nanos(self.nanoseconds_after(now))
+	}
+
+	/// Gets the amount of time from now until this time. If this time has
+	/// already passed, then this returns zero. Leap seconds are counted.
+	#[cfg(feature = "std")]
+	#[must_use]
+	pub fn duration_until_now(&self) -> Duration {
+		duration_from_nanos(self.nanoseconds_from_now())
+	}
+
+	/// Converts this time into an [`std::time::Instant`], which can be used as
+	/// a deadline for timers. If this time has already passed, the instant is
+	/// also in the past. If the instant can't be represented, then this time
+	/// is clamped to the current instant.
+	///
+	/// Since the instant is measured by a monotonic clock, it won't follow
+	/// changes to the system clock.
+	#[cfg(feature = "std")]
+	#[must_use]
+	pub fn to_instant(&self) -> std::time::Instant {
+		let now = std::time::Instant::now();
+		let nanoseconds = self.nanoseconds_from_now();
+		let distance = duration_from_nanos(nanoseconds.abs());
+
+		let instant = if nanoseconds >= 0 {
+			now.checked_add(distance)
+		} else {
+			now.checked_sub(distance)
+		};
 
-		(tai_dt.into_timezone(self.timezone), overflow)
+		instant.unwrap_or(now)
+	}
+
+	/// Adds seconds, counting leap seconds. This returns a tuple of the
+	/// result and a boolean indicating if the date overflowed.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use botic::datetime;
+	///
+	/// let before = datetime!(2016-12-31 23:59:59 UTC);
+	/// let (leap_second, _) = before.add_seconds_overflowing(1);
+	/// assert_eq!(leap_second.naive_utc(), datetime!(2016-12-31 23:59:60));
+	/// ```
+	#[must_use]
+	pub fn add_seconds_overflowing(self, seconds: i64) -> (Self, bool) {
+		self.add_tai_nanoseconds_overflowing(i128::from(seconds) * i128::from(NANOS_PER_SECOND))
 	}
 
+	/// Adds nanoseconds, counting leap seconds. This returns a tuple of the
+	/// result and a boolean indicating if the date overflowed.
 	#[must_use]
 	pub fn add_nanoseconds_overflowing(self, nanoseconds: i64) -> (Self, bool) {
-		let (tai_timestamp, overflow) = self
-			.tai_timestamp()
-			.add_nanoseconds_overflowing(nanoseconds);
-		let tai_naive_dt = NaiveDateTime::from_timestamp(tai_timestamp);
-		let tai_dt = DateTime::from_local(tai_naive_dt, Tai).unwrap();
+		self.add_tai_nanoseconds_overflowing(i128::from(nanoseconds))
+	}
+
+	/// Adds nanoseconds in TAI, so that leap seconds are counted
+	pub(crate) fn add_tai_nanoseconds_overflowing(self, nanoseconds: i128) -> (Self, bool) {
+		let tai_nanos = UnixNanos::new(self.tai_timestamp().as_nanos() + nanoseconds);
+		let (tai_naive_dt, overflow) = NaiveDateTime::from_unix_nanos_overflowing(tai_nanos);
+		match Tai::new().to_utc(tai_naive_dt) {
+			Ok(utc) if !overflow => (utc.into_timezone(self.timezone), false),
+			_ => (DateTime::from_utc(tai_naive_dt, self.timezone), true),
+		}
+	}
+}
+
+impl DateTime<Utc> {
+	/// Midnight UTC on January 1, 1970
+	pub const UNIX_EPOCH: Self = Self::from_utc(NaiveDateTime::UNIX_EPOCH, Utc);
+
+	/// Creates a date and time from a number of seconds since the Unix epoch
+	///
+	/// # Example
+	///
+	/// ```
+	/// use botic::DateTime;
+	///
+	/// let date_time = DateTime::from_unix_seconds(951_782_400).unwrap();
+	/// assert_eq!(date_time.to_string(), "2000-02-29 00:00:00 UTC");
+	/// ```
+	///
+	/// # Errors
+	///
+	/// Returns an error if the date is after the year 32767, or before the
+	/// year -32768
+	pub const fn from_unix_seconds(seconds: i64) -> Result<Self, OutOfRange> {
+		match NaiveDateTime::from_timestamp_overflowing(Timestamp::new(seconds, 0)) {
+			(utc_datetime, false) => Ok(Self::from_utc(utc_datetime, Utc)),
+			(_, true) => Err(OutOfRange),
+		}
+	}
+
+	/// Gets the current date and time in UTC
+	#[cfg(feature = "std")]
+	#[must_use]
+	pub fn now() -> Self {
+		Self::from_utc(NaiveDateTime::from_timestamp(Timestamp::now()), Utc)
+	}
+}
+
+impl DateTime<Tai> {
+	/// Gets the current date and time in TAI.
+	///
+	/// On Linux, this uses the kernel's TAI clock if its offset from UTC has
+	/// been set, such as by an NTP daemon. Otherwise, the current UTC time is
+	/// converted using the global leap second table.
+	#[cfg(feature = "std")]
+	#[must_use]
+	pub fn now() -> Self {
+		sys::tai_now()
+			.and_then(|(seconds, nanoseconds)| {
+				Self::from_tai_clock(Timestamp::new(seconds, nanoseconds))
+			})
+			.unwrap_or_else(|| DateTime::<Utc>::now().as_tai())
+	}
+
+	/// Converts a reading from a TAI clock, which is the number of seconds
+	/// since 1970-01-01 00:00:00 TAI. Returns `None` if it's out of range.
+	#[cfg(feature = "std")]
+	fn from_tai_clock(timestamp: Timestamp) -> Option<Self> {
+		let tai = NaiveDateTime::try_from(timestamp).ok()?;
+		let utc = Tai::new().to_utc(tai).ok()?;
+		Some(utc.into_timezone(Tai::new()))
+	}
+}
+
+impl DateTime<UtcOffset> {
+	/// Creates a date and time from the local date and time at the given
+	/// offset. This returns a tuple of the result and a boolean indicating if
+	/// converting to UTC overflowed.
+	///
+	/// Unlike [`DateTime::from_local`], this can be used in a `const`.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use botic::{Date, DateTime, Month, NaiveDateTime, Time, Year};
+	/// use botic::timezone::UtcOffset;
+	///
+	/// const LAUNCH: DateTime<UtcOffset> = {
+	///     let date = match Date::from_ymd(Year::from_i16(2024), Month::March, 10) {
+	///         Ok(date) => date,
+	///         Err(_) => panic!("invalid date"),
+	///     };
+	///     let local = NaiveDateTime::new(date, Time::MIDNIGHT);
+	///     DateTime::from_naive_local(local, UtcOffset::from_hours_unchecked(-5)).0
+	/// };
+	///
+	/// assert_eq!(LAUNCH.naive_utc().hour(), 5);
+	/// ```
+	#[must_use]
+	pub const fn from_naive_local(
+		local_datetime: NaiveDateTime,
+		offset: UtcOffset,
+	) -> (Self, bool) {
+		let (utc_datetime, overflow) =
+			local_datetime.add_offset_overflowing(-offset.seconds_ahead());
+		(Self::from_utc(utc_datetime, offset), overflow)
+	}
+
+	/// Gets the date and time at this offset. This returns a tuple of the
+	/// result and a boolean indicating if applying the offset overflowed.
+	///
+	/// Unlike [`DateTime::to_naive_local`], this can be used in a
+	/// `const`.
+	#[must_use]
+	pub const fn naive_local_overflowing(&self) -> (NaiveDateTime, bool) {
+		self.utc_datetime
+			.add_offset_overflowing(self.timezone.seconds_ahead())
+	}
 
-		(tai_dt.into_timezone(self.timezone), overflow)
+	/// Gets the current date and time, using the system's current offset from
+	/// UTC. See [`UtcOffset::current_local`] for when this can fail.
+	///
+	/// # Errors
+	///
+	/// Returns an error if the local offset can't be safely determined
+	#[cfg(feature = "std")]
+	pub fn now_local() -> Result<Self, IndeterminateOffsetError> {
+		let now = DateTime::<Utc>::now();
+		let offset = UtcOffset::local_at(now.unix_timestamp().total_seconds())?;
+		Ok(now.into_timezone(offset))
 	}
 }
 
 impl NaiveDateTime {
+	/// The earliest date and time which can be represented
+	pub const MIN: Self = Self::new(Date::MIN, Time::MIDNIGHT);
+
+	/// The latest date and time which can be represented. This is during a
+	/// leap second, which sorts after every other time in the day.
+	pub const MAX: Self = Self::new(
+		Date::MAX,
+		Time::from_hms_nano_unchecked(23, 59, 60, 999_999_999),
+	);
+
+	/// Midnight on January 1, 1970
+	pub const UNIX_EPOCH: Self = Self::new(Date::UNIX_EPOCH, Time::MIDNIGHT);
+
 	// TODO docs
 
 	#[must_use]
 	pub const fn new(date: Date, time: Time) -> Self {
-		Self { date, time }
+		Self {
+			days: (date.days_after_common_era() - UNIX_EPOCH_DAYS_AFTER_CE) as i32,
+			time,
+		}
+	}
+
+	/// Creates a date and time, checking that a leap second is on a day which
+	/// actually had one, according to the global leap second table. A
+	/// [`Time`] can be 23:59:60 on any day, since it doesn't know its date.
+	/// Use [`NaiveDateTime::new`] to skip this check.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use botic::{date, time, NaiveDateTime};
+	///
+	/// assert!(NaiveDateTime::new_checked(date!(2016-12-31), time!(23:59:60)).is_ok());
+	/// assert!(NaiveDateTime::new_checked(date!(2017-12-31), time!(23:59:60)).is_err());
+	/// assert!(NaiveDateTime::new_checked(date!(2017-12-31), time!(23:59:59)).is_ok());
+	/// ```
+	///
+	/// # Errors
+	///
+	/// Returns an error if the time is a leap second, but the leap second
+	/// table doesn't have a leap second at the end of that day
+	pub fn new_checked(date: Date, time: Time) -> Result<Self, InvalidLeapSecond> {
+		let date_time = Self::new(date, time);
+		if time.second() == 60 && !date_time.is_leap_second() {
+			return Err(InvalidLeapSecond::new(date_time));
+		}
+
+		Ok(date_time)
 	}
 
+	/// Creates a date and time, checking that a leap second is in the given
+	/// list of leap seconds. See [`NaiveDateTime::new_checked`].
+	///
+	/// # Errors
+	///
+	/// Returns an error if the time is a leap second, but the list doesn't
+	/// have a leap second at the end of that day
+	pub fn new_checked_with(
+		date: Date,
+		time: Time,
+		leap_seconds: LeapSecondSlice<'_>,
+	) -> Result<Self, InvalidLeapSecond> {
+		let date_time = Self::new(date, time);
+		if time.second() == 60 && !leap_seconds.is_leap_second(date_time) {
+			return Err(InvalidLeapSecond::new(date_time));
+		}
+
+		Ok(date_time)
+	}
+
+	/// Creates a date and time from its components
+	///
+	/// # Example
+	///
+	/// ```
+	/// use botic::{Month, NaiveDateTime};
+	///
+	/// let date_time = NaiveDateTime::from_ymd_hms(2000.into(), Month::February, 29, 12, 30, 0).unwrap();
+	/// assert_eq!(date_time.to_string(), "2000-02-29 12:30:00");
+	///
+	/// let error = NaiveDateTime::from_ymd_hms(2000.into(), Month::February, 29, 12, 60, 0).unwrap_err();
+	/// assert_eq!(error.name(), "minute");
+	/// ```
+	///
+	/// # Errors
+	///
+	/// Returns an error naming the first component which is out of range
+	pub const fn from_ymd_hms(
+		year: Year,
+		month: Month,
+		day: u8,
+		hour: u8,
+		minute: u8,
+		second: u8,
+	) -> Result<Self, ComponentRange> {
+		Self::from_ymd_hms_nano(year, month, day, hour, minute, second, 0)
+	}
+
+	/// Creates a date and time from its components, including the nanosecond.
+	/// See [`NaiveDateTime::from_ymd_hms`].
+	///
+	/// # Errors
+	///
+	/// Returns an error naming the first component which is out of range
+	pub const fn from_ymd_hms_nano(
+		year: Year,
+		month: Month,
+		day: u8,
+		hour: u8,
+		minute: u8,
+		second: u8,
+		nanosecond: u32,
+	) -> Result<Self, ComponentRange> {
+		let date = match Date::from_ymd(year, month, day) {
+			Ok(date) => date,
+			Err(e) => return Err(e),
+		};
+		let time = match Time::from_hms_nano(hour, minute, second, nanosecond) {
+			Ok(time) => time,
+			Err(e) => return Err(e),
+		};
+
+		Ok(Self::new(date, time))
+	}
+
+	/// Treats this as a date and time in UTC
+	#[must_use]
+	pub const fn assume_utc(self) -> DateTime<Utc> {
+		DateTime::from_utc(self, Utc)
+	}
+
+	/// Treats this as the local date and time at the given offset from UTC
+	///
+	/// # Example
+	///
+	/// ```
+	/// use botic::{Month, NaiveDateTime};
+	/// use botic::timezone::UtcOffset;
+	///
+	/// let local = NaiveDateTime::from_ymd_hms(2000.into(), Month::January, 1, 9, 0, 0).unwrap();
+	/// let date_time = local.assume_offset(UtcOffset::from_hours(-5).unwrap()).unwrap();
+	/// assert_eq!(date_time.naive_utc().hour(), 14);
+	/// ```
+	///
+	/// # Errors
+	///
+	/// Returns an error if the date and time in UTC is out of range
+	pub const fn assume_offset(self, offset: UtcOffset) -> Result<DateTime<UtcOffset>, OutOfRange> {
+		match DateTime::from_naive_local(self, offset) {
+			(date_time, false) => Ok(date_time),
+			(_, true) => Err(OutOfRange),
+		}
+	}
+
+	/// Treats this as the local date and time in the given time zone. This is
+	/// the same as [`DateTime::from_local`].
+	///
+	/// # Errors
+	///
+	/// Returns an error if this local time doesn't exist in the time zone
+	pub fn and_timezone<Tz: TimeZone>(self, timezone: Tz) -> Result<DateTime<Tz>, Tz::Err> {
+		DateTime::from_local(self, timezone)
+	}
+
+	/// Creates a date and time from a number of days since the Unix epoch,
+	/// wrapping around if it's out of range
+	const fn from_days_wrapping(days: i64, time: Time) -> (Self, bool) {
+		let overflow = days < MIN_DAYS || days > MAX_DAYS;
+		let days = (days - MIN_DAYS).rem_euclid(MAX_DAYS - MIN_DAYS + 1) + MIN_DAYS;
+
+		(
+			Self {
+				days: days as i32,
+				time,
+			},
+			overflow,
+		)
+	}
+
+	/// The number of nanoseconds since the Unix epoch, counting a leap second
+	/// as part of the following second
+	const fn total_nanoseconds(self) -> i128 {
+		self.days as i128 * NANOS_PER_DAY as i128 + self.time.nanoseconds_from_midnight() as i128
+	}
+
+	/// Adds nanoseconds, counting a leap second as an extra second. The
+	/// result is only a leap second if it's still during the same one.
+	const fn add_total_nanoseconds_overflowing(self, nanoseconds: i128) -> (Self, bool) {
+		let (time_nanos, leap_second) = self.time.add_to_nanoseconds_overflowing(nanoseconds);
+		let total = self.days as i128 * NANOS_PER_DAY as i128 + time_nanos;
+		let days = total.div_euclid(NANOS_PER_DAY as i128);
+		let nanoseconds = total.rem_euclid(NANOS_PER_DAY as i128) as u64;
+
+		// at most i64::MAX hours are added, which is fewer than i64::MAX days
+		Self::from_days_wrapping(days as i64, Time::from_parts(nanoseconds, leap_second))
+	}
+
+	/// Gets the current local date and time in the given time zone
+	#[cfg(feature = "std")]
+	pub(crate) fn now_in(timezone: &impl TimeZone) -> Self {
+		Self::local_in(DateTime::<Utc>::now(), timezone)
+	}
+
+	/// Gets the local date and time in the given time zone. If applying the
+	/// offset moves it out of range, then this is clamped to
+	/// [`NaiveDateTime::MIN`] or [`NaiveDateTime::MAX`].
+	#[cfg(feature = "std")]
+	fn local_in(utc: DateTime<Utc>, timezone: &impl TimeZone) -> Self {
+		let offset = timezone.utc_offset(utc).seconds_ahead();
+		match utc.naive_utc().add_seconds_overflowing(offset.into()) {
+			(local, false) => local,
+			(_, true) if offset > 0 => Self::MAX,
+			(_, true) => Self::MIN,
+		}
+	}
+
+	/// Creates a date and time from a timestamp. If the timestamp is out of
+	/// range, then it wraps around. Use the [`TryFrom`] implementation to
+	/// check for this instead.
+	///
+	/// A timestamp with between one and two billion nanoseconds is a leap
+	/// second. More nanoseconds than that carry into the following seconds.
+	#[must_use]
 	pub const fn from_timestamp(timestamp: Timestamp) -> Self {
-		const UNIX_EPOCH_DAYS_AFTER_CE: i64 = Date::UNIX_EPOCH.days_after_common_era();
-		let days_after_unix_epoch = timestamp.total_seconds() / 86_400;
-		let days_after_ce = days_after_unix_epoch + UNIX_EPOCH_DAYS_AFTER_CE as i64;
-		let date = Date::from_days_after_common_era(days_after_ce);
-		let seconds_after_midnight = timestamp.total_seconds() % 86_400;
-		let nanoseconds = timestamp.nanosecond();
-		let time = Time::MIDNIGHT
-			.add_seconds_overflowing(seconds_after_midnight as isize)
-			.0
-			.add_nanoseconds_overflowing(nanoseconds as isize)
-			.0;
+		Self::from_timestamp_overflowing(timestamp).0
+	}
+
+	/// Creates a date and time from a timestamp. This returns a tuple of the
+	/// result and a boolean indicating if it was out of range, in which case
+	/// it wraps around.
+	#[must_use]
+	pub const fn from_timestamp_overflowing(timestamp: Timestamp) -> (Self, bool) {
+		let nanosecond = timestamp.nanosecond() as i128;
+		let leap_second = timestamp.is_leap_second() && nanosecond < 2 * NANOS_PER_SECOND as i128;
+		let nanosecond = nanosecond - leap_second as i128 * NANOS_PER_SECOND as i128;
+		let nanoseconds = timestamp.total_seconds() as i128 * NANOS_PER_SECOND as i128 + nanosecond;
+
+		Self::from_total_nanoseconds_overflowing(nanoseconds, leap_second)
+	}
+
+	/// Creates a date and time from a number of nanoseconds since the Unix
+	/// epoch. This returns a tuple of the result and a boolean indicating if
+	/// it was out of range, in which case it wraps around.
+	#[must_use]
+	pub const fn from_unix_nanos_overflowing(nanoseconds: UnixNanos) -> (Self, bool) {
+		Self::from_total_nanoseconds_overflowing(nanoseconds.as_i128(), false)
+	}
+
+	/// Creates a date and time from a number of nanoseconds since the Unix
+	/// epoch, not counting the leap second if there is one
+	const fn from_total_nanoseconds_overflowing(
+		nanoseconds: i128,
+		leap_second: bool,
+	) -> (Self, bool) {
+		let days = nanoseconds.div_euclid(NANOS_PER_DAY as i128);
+		let time = Time::from_parts(
+			nanoseconds.rem_euclid(NANOS_PER_DAY as i128) as u64,
+			leap_second,
+		);
+
+		// wrap in an i128 first, since the days might not fit in an i64
+		let range = (MAX_DAYS - MIN_DAYS + 1) as i128;
+		let wrapped = (days - MIN_DAYS as i128).rem_euclid(range) + MIN_DAYS as i128;
+		let (date_time, _) = Self::from_days_wrapping(wrapped as i64, time);
+		(date_time, wrapped != days)
+	}
 
-		Self::new(date, time)
+	/// Gets the number of nanoseconds since the Unix epoch. A leap second is
+	/// counted as part of the second after it.
+	#[must_use]
+	pub const fn unix_nanos(self) -> UnixNanos {
+		UnixNanos::new(self.total_nanoseconds())
 	}
 
 	#[must_use]
 	pub const fn date(self) -> Date {
-		self.date
+		Date::from_days_after_common_era_unchecked(self.days as i64 + UNIX_EPOCH_DAYS_AFTER_CE)
 	}
 
 	#[must_use]
@@ -152,17 +914,29 @@ impl NaiveDateTime {
 
 	#[must_use]
 	pub const fn year(self) -> Year {
-		self.date.year()
+		self.date().year()
+	}
+
+	/// Checks if this is during a leap second, according to the global leap
+	/// second table. This assumes that the date and time is in UTC.
+	#[must_use]
+	pub fn is_leap_second(self) -> bool {
+		tai::is_leap_second(self)
 	}
 
 	#[must_use]
 	pub const fn month(self) -> Month {
-		self.date.month()
+		self.date().month()
 	}
 
 	#[must_use]
 	pub const fn day(self) -> u8 {
-		self.date.day()
+		self.date().day()
+	}
+
+	#[must_use]
+	pub const fn weekday(self) -> Weekday {
+		self.date().weekday()
 	}
 
 	#[must_use]
@@ -197,128 +971,138 @@ impl NaiveDateTime {
 
 	#[must_use]
 	pub const fn timestamp(self) -> Timestamp {
-		const UNIX_EPOCH_DAYS: i64 = Date::UNIX_EPOCH.days_after_common_era();
-		// TODO don't require the .date()
-		let days = (self.date.days_after_common_era() - UNIX_EPOCH_DAYS) as i64;
-		let seconds = days * 86_400 + self.time().seconds_from_midnight() as i64;
-		let nanoseconds = self.nanosecond();
+		// a leap second is stored as an extra second of nanoseconds, after the
+		// second before it
+		let time = self.time;
+		let seconds = time.nanoseconds_without_leap_second() / NANOS_PER_SECOND;
+		let seconds = self.days as i64 * 86_400 + seconds as i64;
+		let nanoseconds =
+			time.nanosecond() + time.in_leap_second() as u32 * NANOS_PER_SECOND as u32;
 
 		Timestamp::new(seconds, nanoseconds)
 	}
 
-	pub const fn add_years_overflowing(
-		self,
-		years: i16,
-	) -> Result<(Self, bool), LeapDayNotInLeapYearError> {
-		let (date, overflow) = match self.date.add_years_overflowing(years) {
+	pub const fn add_years_overflowing(self, years: i16) -> Result<(Self, bool), ComponentRange> {
+		let (date, overflow) = match self.date().add_years_overflowing(years) {
 			Ok(v) => v,
 			Err(e) => return Err(e),
 		};
 
-		Ok((
-			Self {
-				date,
-				time: self.time,
-			},
-			overflow,
-		))
+		Ok((self.with_date(date), overflow))
 	}
 
-	pub const fn add_months_overflowing(
-		self,
-		months: i8,
-	) -> Result<(Self, bool), DayGreaterThanMaximumForMonthError> {
-		let (date, overflow) = match self.date.add_months_overflowing(months) {
+	pub const fn add_months_overflowing(self, months: i8) -> Result<(Self, bool), ComponentRange> {
+		let (date, overflow) = match self.date().add_months_overflowing(months) {
 			Ok(v) => v,
 			Err(e) => return Err(e),
 		};
 
-		Ok((
-			Self {
-				date,
-				time: self.time,
-			},
-			overflow,
-		))
+		Ok((self.with_date(date), overflow))
 	}
 
+	/// Gets the earlier of two dates and times. Unlike [`Ord::min`], this can be
+	/// used in a `const`.
 	#[must_use]
-	pub const fn add_days_overflowing(self, days: i64) -> (Self, bool) {
-		let (date, overflow) = self.date.add_days_overflowing(days);
-
-		(
-			Self {
-				date,
-				time: self.time,
-			},
-			overflow,
-		)
+	pub const fn min(self, other: Self) -> Self {
+		if other.is_before(self) {
+			other
+		} else {
+			self
+		}
 	}
 
+	/// Gets the later of two dates and times. Unlike [`Ord::max`], this can be used
+	/// in a `const`.
 	#[must_use]
-	pub const fn add_hours_overflowing(self, hours: i64) -> (Self, bool) {
-		let timestamp: Timestamp = self.timestamp();
-		let (timestamp, overflow) = timestamp.add_hours_overflowing(hours);
-		let datetime: NaiveDateTime = Self::from_timestamp(timestamp);
-
-		(datetime, overflow)
+	pub const fn max(self, other: Self) -> Self {
+		if other.is_before(self) {
+			self
+		} else {
+			other
+		}
 	}
 
+	/// Restricts the date and time to be from `min` to `max`. Unlike [`Ord::clamp`],
+	/// this can be used in a `const`.
+	///
+	/// # Panics
+	///
+	/// Panics if `min` is after `max`
 	#[must_use]
-	pub const fn add_minutes_overflowing(self, minutes: i64) -> (Self, bool) {
-		let timestamp: Timestamp = self.timestamp();
-		let (timestamp, overflow) = timestamp.add_minutes_overflowing(minutes);
-		let datetime: NaiveDateTime = Self::from_timestamp(timestamp);
+	pub const fn clamp(self, min: Self, max: Self) -> Self {
+		assert!(!max.is_before(min), "the minimum is after the maximum");
+		if self.is_before(min) {
+			min
+		} else if max.is_before(self) {
+			max
+		} else {
+			self
+		}
+	}
 
-		(datetime, overflow)
+	/// Checks if this date and time is before the other one
+	const fn is_before(self, other: Self) -> bool {
+		self.days < other.days
+			|| (self.days == other.days && self.time.sort_key() < other.time.sort_key())
 	}
 
-	#[must_use]
-	pub const fn add_seconds_overflowing(self, seconds: i64) -> (Self, bool) {
-		let timestamp: Timestamp = self.timestamp();
-		let (timestamp, overflow) = timestamp.add_seconds_overflowing(seconds);
-		let datetime: NaiveDateTime = Self::from_timestamp(timestamp);
+	/// Keeps the date, but changes the time
+	const fn with_time(self, time: Time) -> Self {
+		Self { time, ..self }
+	}
+
+	/// Applies an offset from UTC, or removes it if the offset is negated. A
+	/// leap second stays a leap second if the offset is a whole number of
+	/// minutes, since it's still at the end of a minute.
+	pub(crate) const fn add_offset_overflowing(self, seconds: i32) -> (Self, bool) {
+		if seconds % 60 != 0 || !self.time.in_leap_second() {
+			return self.add_seconds_overflowing(seconds as i64);
+		}
+
+		// the offset is added to the second before the leap second
+		let before_leap_second =
+			Time::from_parts(self.time.nanoseconds_without_leap_second(), false);
+		let (shifted, overflow) = self
+			.with_time(before_leap_second)
+			.add_seconds_overflowing(seconds as i64);
+		let time = Time::from_parts(shifted.time.nanoseconds_without_leap_second(), true);
+		(shifted.with_time(time), overflow)
+	}
 
-		(datetime, overflow)
+	/// Keeps the time, but changes the date
+	const fn with_date(self, date: Date) -> Self {
+		Self {
+			days: (date.days_after_common_era() - UNIX_EPOCH_DAYS_AFTER_CE) as i32,
+			..self
+		}
 	}
 
 	#[must_use]
-	pub const fn add_nanoseconds_overflowing(self, nanoseconds: i64) -> (Self, bool) {
-		let timestamp: Timestamp = self.timestamp();
-		let (timestamp, overflow) = timestamp.add_nanoseconds_overflowing(nanoseconds);
-		let datetime: NaiveDateTime = Self::from_timestamp(timestamp);
+	pub const fn add_days_overflowing(self, days: i64) -> (Self, bool) {
+		let (days, overflow) = (self.days as i64).overflowing_add(days);
+		let (datetime, out_of_range) = Self::from_days_wrapping(days, self.time);
 
-		(datetime, overflow)
+		(datetime, overflow || out_of_range)
 	}
-}
 
-impl PartialOrd for NaiveDateTime {
-	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-		let date_ordering = self.date.cmp(&other.date);
-		let time_ordering = self.time.cmp(&other.time);
+	#[must_use]
+	pub const fn add_hours_overflowing(self, hours: i64) -> (Self, bool) {
+		self.add_total_nanoseconds_overflowing(hours as i128 * 3600 * NANOS_PER_SECOND as i128)
+	}
 
-		if date_ordering != Ordering::Equal {
-			Some(date_ordering)
-		} else if time_ordering != Ordering::Equal {
-			Some(time_ordering)
-		} else {
-			Some(Ordering::Equal)
-		}
+	#[must_use]
+	pub const fn add_minutes_overflowing(self, minutes: i64) -> (Self, bool) {
+		self.add_total_nanoseconds_overflowing(minutes as i128 * 60 * NANOS_PER_SECOND as i128)
 	}
-}
 
-impl Ord for NaiveDateTime {
-	fn cmp(&self, other: &Self) -> Ordering {
-		let date_ordering = self.date.cmp(&other.date);
-		let time_ordering = self.time.cmp(&other.time);
+	#[must_use]
+	pub const fn add_seconds_overflowing(self, seconds: i64) -> (Self, bool) {
+		self.add_total_nanoseconds_overflowing(seconds as i128 * NANOS_PER_SECOND as i128)
+	}
 
-		if date_ordering != Ordering::Equal {
-			date_ordering
-		} else if time_ordering != Ordering::Equal {
-			time_ordering
-		} else {
-			Ordering::Equal
-		}
+	#[must_use]
+	pub const fn add_nanoseconds_overflowing(self, nanoseconds: i64) -> (Self, bool) {
+		self.add_total_nanoseconds_overflowing(nanoseconds as i128)
 	}
 }
 
@@ -347,9 +1131,35 @@ impl<Tz: TimeZone> Ord for DateTime<Tz> {
 	}
 }
 
+/// Compares the instants, so a leap second is equal to a timestamp with more
+/// than a billion nanoseconds
+impl<Tz: TimeZone> PartialEq<Timestamp> for DateTime<Tz> {
+	fn eq(&self, other: &Timestamp) -> bool {
+		self.unix_timestamp() == *other
+	}
+}
+
+impl<Tz: TimeZone> PartialEq<DateTime<Tz>> for Timestamp {
+	fn eq(&self, other: &DateTime<Tz>) -> bool {
+		*self == other.unix_timestamp()
+	}
+}
+
+impl<Tz: TimeZone> PartialOrd<Timestamp> for DateTime<Tz> {
+	fn partial_cmp(&self, other: &Timestamp) -> Option<Ordering> {
+		self.unix_timestamp().partial_cmp(other)
+	}
+}
+
+impl<Tz: TimeZone> PartialOrd<DateTime<Tz>> for Timestamp {
+	fn partial_cmp(&self, other: &DateTime<Tz>) -> Option<Ordering> {
+		self.partial_cmp(&other.unix_timestamp())
+	}
+}
+
 impl Display for NaiveDateTime {
 	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-		write!(f, "{} {}", self.date, self.time)
+		write!(f, "{} {}", self.date(), self.time())
 	}
 }
 
@@ -359,19 +1169,586 @@ impl<Tz: TimeZone> Display for DateTime<Tz> {
 	}
 }
 
-// TODO there's a lossy cast somewhere here or in the into(). Where is it?
-impl From<Timestamp> for NaiveDateTime {
-	fn from(timestamp: Timestamp) -> Self {
-		const UNIX_EPOCH_DAYS_AFTER_CE: i64 = Date::UNIX_EPOCH.days_after_common_era();
-		let days_after_unix_epoch = timestamp.total_seconds() / 86_400;
-		let days_after_ce = days_after_unix_epoch + UNIX_EPOCH_DAYS_AFTER_CE as i64;
-		let date = Date::from_days_after_common_era(days_after_ce);
-		let seconds_after_midnight = timestamp.total_seconds() % 86_400;
-		let nanoseconds = timestamp.nanosecond();
-		let time = Time::MIDNIGHT
-			.add_seconds(seconds_after_midnight as isize)
-			.add_nanoseconds(nanoseconds as isize);
-
-		Self::new(date, time)
+impl TryFrom<Timestamp> for NaiveDateTime {
+	type Error = OutOfRange;
+
+	fn try_from(timestamp: Timestamp) -> Result<Self, Self::Error> {
+		match Self::from_timestamp_overflowing(timestamp) {
+			(date_time, false) => Ok(date_time),
+			(_, true) => Err(OutOfRange),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// US Eastern time, with only the daylight saving time changes in 2024
+	#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+	struct Eastern2024;
+
+	impl Display for Eastern2024 {
+		fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+			write!(f, "America/New_York")
+		}
+	}
+
+	/// The local time was skipped when daylight saving time started
+	#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+	struct SkippedTime;
+
+	impl TimeZone for Eastern2024 {
+		type Err = SkippedTime;
+
+		fn utc_offset(&self, date_time: DateTime<Utc>) -> UtcOffset {
+			let daylight = crate::datetime!(2024-03-10 07:00)..crate::datetime!(2024-11-03 06:00);
+			let hours = if daylight.contains(&date_time.naive_utc()) {
+				-4
+			} else {
+				-5
+			};
+			UtcOffset::from_hours_unchecked(hours)
+		}
+
+		fn offset_from_local_naive(
+			&self,
+			date_time: NaiveDateTime,
+		) -> Result<UtcOffset, Self::Err> {
+			// a repeated time is given the earlier offset
+			let skipped = crate::datetime!(2024-03-10 02:00)..crate::datetime!(2024-03-10 03:00);
+			let daylight = crate::datetime!(2024-03-10 03:00)..crate::datetime!(2024-11-03 02:00);
+			if skipped.contains(&date_time) {
+				return Err(SkippedTime);
+			}
+
+			let hours = if daylight.contains(&date_time) {
+				-4
+			} else {
+				-5
+			};
+			Ok(UtcOffset::from_hours_unchecked(hours))
+		}
+	}
+
+	#[test]
+	fn eastern_fixture() {
+		use crate::datetime;
+
+		let offset = |local| Eastern2024.offset_from_local_naive(local);
+		let hours = |hours| Ok(UtcOffset::from_hours_unchecked(hours));
+		assert_eq!(offset(datetime!(2024-03-10 01:59)), hours(-5));
+		assert_eq!(offset(datetime!(2024-03-10 02:30)), Err(SkippedTime));
+		assert_eq!(offset(datetime!(2024-03-10 03:00)), hours(-4));
+		assert_eq!(offset(datetime!(2024-11-03 01:30)), hours(-4));
+		assert_eq!(offset(datetime!(2024-11-03 02:00)), hours(-5));
+
+		for utc in [
+			datetime!(2024-01-01 12:00),
+			datetime!(2024-07-01 12:00),
+			datetime!(2024-11-03 05:30),
+		] {
+			let date_time = DateTime::from_utc(utc, Eastern2024);
+			let local = date_time.to_naive_local().unwrap();
+			assert_eq!(offset(local), Ok(date_time.offset()));
+		}
+	}
+
+	#[test]
+	fn keep_local() {
+		use crate::datetime;
+
+		let zone = |hours| UtcOffset::from_hours_unchecked(hours);
+		let eastern = datetime!(2024-03-10 14:00 UTC).into_timezone(zone(-5));
+		let moved = eastern.with_timezone_keep_local(zone(1)).unwrap();
+		assert_eq!(moved.naive_utc(), datetime!(2024-03-10 08:00));
+		assert_eq!(moved.to_naive_local(), eastern.to_naive_local());
+
+		let skipped = datetime!(2024-03-10 07:30 UTC).into_timezone(zone(-5));
+		assert_eq!(
+			skipped.with_timezone_keep_local(Eastern2024),
+			Err(KeepLocalError::TimeZone(SkippedTime))
+		);
+
+		// the local time is out of range in the old time zone
+		let latest = DateTime::from_utc(NaiveDateTime::MAX, Utc);
+		assert_eq!(
+			latest.into_timezone(zone(1)).with_timezone_keep_local(Utc),
+			Err(KeepLocalError::OutOfRange(OutOfRange))
+		);
+
+		// and the instant is out of range in the new time zone
+		assert_eq!(
+			latest.with_timezone_keep_local(zone(-1)),
+			Err(KeepLocalError::OutOfRange(OutOfRange))
+		);
+	}
+
+	#[test]
+	fn timestamp_round_trip() {
+		for (seconds, nanoseconds) in [
+			(0, 0),
+			(951_782_400, 500),
+			(-1, 999_999_999),
+			(-86_400 * 365, 0),
+			(1_483_228_799, 1_250_000_000),
+		] {
+			let timestamp = Timestamp::new(seconds, nanoseconds);
+			assert_eq!(
+				NaiveDateTime::from_timestamp(timestamp).timestamp(),
+				timestamp
+			);
+		}
+	}
+
+	#[test]
+	fn accessors() {
+		let datetime = NaiveDateTime::from_timestamp(Timestamp::new(951_825_845, 123_456_789));
+		assert_eq!(
+			datetime.date(),
+			Date::from_ymd(2000.into(), Month::February, 29).unwrap()
+		);
+		assert_eq!(datetime.hour(), 12);
+		assert_eq!(datetime.minute(), 4);
+		assert_eq!(datetime.second(), 5);
+		assert_eq!(datetime.millisecond(), 123);
+		assert_eq!(datetime.nanosecond(), 123_456_789);
+
+		let time = Time::from_hms_nano_unchecked(12, 4, 5, 123_456_789);
+		assert_eq!(NaiveDateTime::new(datetime.date(), time), datetime);
+	}
+
+	#[test]
+	fn leap_second_ordering() {
+		let date = Date::from_ymd(2016.into(), Month::December, 31).unwrap();
+		let at = |second, nanosecond| {
+			let time = Time::from_hms_nano_unchecked(23, 59, second, nanosecond);
+			NaiveDateTime::new(date, time)
+		};
+
+		let leap_second = at(60, 0);
+		assert_eq!(leap_second.second(), 60);
+		assert!(at(59, 999_999_999) < leap_second);
+		assert!(leap_second < at(60, 1));
+		assert!(at(60, 999_999_999) < leap_second.add_days_overflowing(1).0);
+
+		let next_day = |second, nanosecond| {
+			let time = Time::from_hms_nano_unchecked(0, 0, second, nanosecond);
+			NaiveDateTime::new(date.add_days_overflowing(1).0, time)
+		};
+		let add = |date_time: NaiveDateTime, nanoseconds| {
+			date_time.add_nanoseconds_overflowing(nanoseconds).0
+		};
+		assert_eq!(
+			at(60, 500_000_000).add_seconds_overflowing(1).0,
+			next_day(0, 500_000_000)
+		);
+		assert_eq!(leap_second.add_seconds_overflowing(1).0, next_day(0, 0));
+		assert_eq!(add(leap_second, 999_999_999), at(60, 999_999_999));
+		assert_eq!(add(leap_second, -1), at(59, 999_999_999));
+		assert_eq!(add(at(60, 500_000_000), -1_500_000_000), at(59, 0));
+	}
+
+	#[test]
+	fn checked_leap_seconds() {
+		const MIDNIGHT: DateTime<Utc> = crate::datetime!(2017-01-01 00:00 UTC);
+		let leap_seconds = LeapSecondSlice::new(&[MIDNIGHT], None);
+		let leap_second = crate::time!(23:59:60.5);
+
+		let date_time = NaiveDateTime::new_checked_with(
+			crate::date!(2016 - 12 - 31),
+			leap_second,
+			leap_seconds,
+		);
+		assert_eq!(date_time, Ok(crate::datetime!(2016-12-31 23:59:60.5)));
+
+		let error = NaiveDateTime::new_checked_with(
+			crate::date!(2015 - 06 - 30),
+			leap_second,
+			leap_seconds,
+		)
+		.unwrap_err();
+		assert_eq!(error.date_time(), crate::datetime!(2015-06-30 23:59:60.5));
+		assert!(NaiveDateTime::new_checked(crate::date!(2015 - 06 - 30), leap_second).is_ok());
+	}
+
+	#[test]
+	fn local_components() {
+		let utc = NaiveDateTime::new(
+			Date::from_ymd(2016.into(), Month::December, 31).unwrap(),
+			Time::from_hms(23, 59, 60).unwrap(),
+		);
+		let local = DateTime::from_utc(utc, UtcOffset::from_seconds(5400).unwrap());
+		assert_eq!(
+			(local.year(), local.month(), local.day(), local.weekday()),
+			(2017.into(), Month::January, 1, Weekday::Sunday)
+		);
+		assert_eq!((local.hour(), local.minute(), local.second()), (1, 29, 60));
+
+		let utc = DateTime::from_utc(utc, Utc);
+		assert_eq!((utc.hour(), utc.second()), (23, 60));
+		assert_eq!(utc.weekday(), Weekday::Saturday);
+	}
+
+	#[test]
+	fn naive_local_out_of_range() {
+		let ahead = UtcOffset::from_hours(1).unwrap();
+		let behind = UtcOffset::from_hours(-1).unwrap();
+		let max = DateTime::from_utc(NaiveDateTime::MAX, ahead);
+		let min = DateTime::from_utc(NaiveDateTime::MIN, behind);
+		assert_eq!(max.to_naive_local(), Err(OutOfRange));
+		assert_eq!(min.to_naive_local(), Err(OutOfRange));
+		assert_eq!(max.to_naive_local_saturating(), NaiveDateTime::MAX);
+		assert_eq!(min.to_naive_local_saturating(), NaiveDateTime::MIN);
+
+		let epoch = DateTime::from_utc(NaiveDateTime::UNIX_EPOCH, behind);
+		assert_eq!(epoch.to_naive_local().unwrap().hour(), 23);
+	}
+
+	#[test]
+	fn timestamp_boundaries() {
+		let convert =
+			|seconds, nanoseconds| NaiveDateTime::try_from(Timestamp::new(seconds, nanoseconds));
+
+		let min = NaiveDateTime::MIN.timestamp();
+		let max = NaiveDateTime::MAX.timestamp();
+		assert_eq!(convert(min.total_seconds(), 0), Ok(NaiveDateTime::MIN));
+		assert_eq!(
+			convert(max.total_seconds(), 1_999_999_999),
+			Ok(NaiveDateTime::MAX)
+		);
+		assert_eq!(
+			convert(min.total_seconds() - 1, 999_999_999),
+			Err(OutOfRange)
+		);
+		assert_eq!(convert(max.total_seconds() + 1, 0), Err(OutOfRange));
+
+		for seconds in [i64::MIN, i64::MIN + 1, -1, 0, i64::MAX - 1, i64::MAX] {
+			for nanoseconds in [0, 999_999_999, 1_000_000_000, 1_999_999_999, u32::MAX] {
+				let (date_time, overflow) =
+					NaiveDateTime::from_timestamp_overflowing(Timestamp::new(seconds, nanoseconds));
+				assert!(NaiveDateTime::MIN <= date_time && date_time <= NaiveDateTime::MAX);
+				assert_eq!(overflow, seconds.unsigned_abs() > 1 << 40);
+			}
+		}
+	}
+
+	#[test]
+	fn negative_timestamps() {
+		let date_time = convert_timestamp(-1, 500_000_000);
+		assert_eq!(
+			date_time.date(),
+			Date::from_ymd(1969.into(), Month::December, 31).unwrap()
+		);
+		assert_eq!(
+			date_time.time(),
+			Time::from_hms_milli(23, 59, 59, 500).unwrap()
+		);
+
+		// 1969-12-31 was not really a leap second, but it can be represented
+		let leap_second = convert_timestamp(-1, 1_500_000_000);
+		assert_eq!(
+			leap_second.time(),
+			Time::from_hms_milli(23, 59, 60, 500).unwrap()
+		);
+
+		let carried = convert_timestamp(-86_401, 2_000_000_000);
+		assert_eq!(carried.timestamp(), Timestamp::new(-86_399, 0));
+	}
+
+	fn convert_timestamp(seconds: i64, nanoseconds: u32) -> NaiveDateTime {
+		NaiveDateTime::try_from(Timestamp::new(seconds, nanoseconds)).unwrap()
+	}
+
+	#[test]
+	fn from_unix_timestamp() {
+		let offset = UtcOffset::from_hours(-5).unwrap();
+		let date_time = DateTime::from_unix_timestamp(Timestamp::new(86_400, 5), offset).unwrap();
+		assert_eq!(date_time.timezone(), &offset);
+		assert_eq!(date_time.naive_utc(), convert_timestamp(86_400, 5));
+		assert_eq!(date_time.hour(), 19);
+
+		assert_eq!(
+			DateTime::from_unix_seconds(-1).unwrap().unix_timestamp(),
+			Timestamp::new(-1, 0)
+		);
+		assert_eq!(DateTime::from_unix_seconds(i64::MAX), Err(OutOfRange));
+		assert_eq!(
+			DateTime::from_unix_timestamp(Timestamp::MIN, offset),
+			Err(OutOfRange)
+		);
+	}
+
+	#[test]
+	fn from_ymd_hms() {
+		let date_time =
+			NaiveDateTime::from_ymd_hms_nano(2016.into(), Month::December, 31, 23, 59, 60, 5)
+				.unwrap();
+		assert_eq!(
+			date_time.timestamp(),
+			Timestamp::new(1_483_228_799, 1_000_000_005)
+		);
+
+		let error =
+			NaiveDateTime::from_ymd_hms(2023.into(), Month::February, 29, 0, 0, 0).unwrap_err();
+		assert_eq!(error.name(), "day");
+		let error =
+			NaiveDateTime::from_ymd_hms(2023.into(), Month::March, 1, 24, 0, 0).unwrap_err();
+		assert_eq!(error.name(), "hour");
+	}
+
+	#[test]
+	fn assume_timezone() {
+		let local = NaiveDateTime::from_ymd_hms(2000.into(), Month::January, 1, 0, 30, 0).unwrap();
+		assert_eq!(local.assume_utc(), DateTime::from_utc(local, Utc));
+
+		let offset = UtcOffset::from_hours(1).unwrap();
+		let date_time = local.assume_offset(offset).unwrap();
+		assert_eq!(date_time.naive_utc().day(), 31);
+		assert_eq!(local.and_timezone(offset), Ok(date_time));
+		assert_eq!(NaiveDateTime::MIN.assume_offset(offset), Err(OutOfRange));
+	}
+
+	#[test]
+	fn const_min_max() {
+		const EARLIEST: NaiveDateTime = NaiveDateTime::UNIX_EPOCH.min(NaiveDateTime::MIN);
+		assert_eq!(EARLIEST, NaiveDateTime::MIN);
+		assert_eq!(
+			NaiveDateTime::MIN.clamp(NaiveDateTime::UNIX_EPOCH, NaiveDateTime::MAX),
+			NaiveDateTime::UNIX_EPOCH
+		);
+		assert_eq!(Date::MIN.max(Date::UNIX_EPOCH), Date::UNIX_EPOCH);
+	}
+
+	#[test]
+	fn compare_with_timestamp() {
+		let offset = UtcOffset::from_hours(-5).unwrap();
+		let date_time = DateTime::from_unix_timestamp(Timestamp::new(100, 0), offset).unwrap();
+		assert!(date_time == Timestamp::new(100, 0));
+		assert!(Timestamp::new(100, 0) == date_time);
+		assert!(date_time < Timestamp::new(100, 1));
+		assert!(Timestamp::new(99, 999_999_999) < date_time);
+
+		let leap_second = DateTime::from_utc(convert_timestamp(1_483_228_799, 1_500_000_000), Utc);
+		assert!(leap_second > Timestamp::new(1_483_228_799, 999_999_999));
+		assert!(leap_second < Timestamp::new(1_483_228_800, 0));
+	}
+
+	#[test]
+	fn constants() {
+		assert_eq!(NaiveDateTime::UNIX_EPOCH.timestamp(), Timestamp::EPOCH);
+		assert_eq!(
+			DateTime::<Utc>::UNIX_EPOCH.naive_utc(),
+			NaiveDateTime::UNIX_EPOCH
+		);
+		assert!(NaiveDateTime::MIN < NaiveDateTime::UNIX_EPOCH);
+		assert!(NaiveDateTime::MAX > NaiveDateTime::new(Date::MAX, Time::MIDNIGHT));
+		assert!(NaiveDateTime::MAX.add_nanoseconds_overflowing(1).1);
+		assert!(NaiveDateTime::MIN.add_nanoseconds_overflowing(-1).1);
+	}
+
+	#[test]
+	fn unix_nanos_round_trip() {
+		let datetime = NaiveDateTime::from_timestamp(Timestamp::new(951_825_845, 123_456_789));
+		let nanos = datetime.unix_nanos();
+		assert_eq!(nanos, UnixNanos::new(951_825_845_123_456_789));
+		assert_eq!(
+			NaiveDateTime::from_unix_nanos_overflowing(nanos),
+			(datetime, false)
+		);
+
+		let before_epoch = NaiveDateTime::from_unix_nanos_overflowing(UnixNanos::new(-1)).0;
+		assert_eq!(before_epoch.timestamp(), Timestamp::new(-1, 999_999_999));
+
+		let leap_second =
+			NaiveDateTime::from_timestamp(Timestamp::new(1_483_228_799, 1_500_000_000));
+		assert_eq!(
+			leap_second.unix_nanos(),
+			UnixNanos::new(1_483_228_800_500_000_000)
+		);
+
+		let (_, overflow) = NaiveDateTime::from_unix_nanos_overflowing(UnixNanos::new(i128::MAX));
+		assert!(overflow);
+	}
+
+	#[test]
+	fn add_out_of_range() {
+		let max = NaiveDateTime::new(Date::MAX, Time::MIDNIGHT);
+		let (wrapped, overflow) = max.add_days_overflowing(1);
+		assert!(overflow);
+		assert_eq!(wrapped.date(), Date::MIN);
+	}
+
+	#[test]
+	fn tai_arithmetic() {
+		use crate::datetime;
+
+		let new_year = datetime!(2017-01-01 00:00 UTC);
+		assert_eq!(
+			new_year.tai_timestamp(),
+			Timestamp::new(1_483_228_800 + 37, 0)
+		);
+		assert_eq!(
+			datetime!(2016-12-31 23:59:60.5 UTC).tai_timestamp(),
+			Timestamp::new(1_483_228_800 + 36, 500_000_000)
+		);
+		assert_eq!(new_year.as_tai().offset().seconds_ahead(), 37);
+
+		let before = datetime!(2016-12-31 23:59:59 UTC);
+		assert_eq!(
+			before.add_seconds_overflowing(1).0.naive_utc(),
+			datetime!(2016-12-31 23:59:60)
+		);
+		assert_eq!(before.add_seconds_overflowing(2), (new_year, false));
+		assert_eq!(
+			new_year.add_nanoseconds_overflowing(-500_000_000).0,
+			datetime!(2016-12-31 23:59:60.5 UTC)
+		);
+		assert_eq!(new_year.time_until(&before), Duration::from_secs(2));
+	}
+
+	#[test]
+	fn time_until_long_spans() {
+		// more than u64::MAX nanoseconds apart
+		let far_future = DateTime::from_utc(NaiveDateTime::MAX, Utc);
+		let far_past = DateTime::from_utc(NaiveDateTime::MIN, Utc);
+		let span = far_future.time_until(&far_past);
+		assert!(span > Duration::from_secs(65_535 * 365 * 86_400));
+		assert_ne!(span.subsec_nanos(), 0);
+		assert_eq!(far_past.time_until(&far_future), Duration::ZERO);
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn local_in_saturates() {
+		let ahead = UtcOffset::from_hours_unchecked(14);
+		let behind = UtcOffset::from_hours_unchecked(-12);
+		let latest = DateTime::from_utc(NaiveDateTime::MAX, Utc);
+		let earliest = DateTime::from_utc(NaiveDateTime::MIN, Utc);
+
+		assert_eq!(NaiveDateTime::local_in(latest, &ahead), NaiveDateTime::MAX);
+		assert_eq!(
+			NaiveDateTime::local_in(earliest, &behind),
+			NaiveDateTime::MIN
+		);
+		assert_eq!(
+			NaiveDateTime::local_in(latest, &behind),
+			NaiveDateTime::MAX.add_hours_overflowing(-12).0
+		);
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn tai_clock() {
+		use crate::datetime;
+
+		let reading =
+			|seconds| DateTime::<Tai>::from_tai_clock(Timestamp::new(seconds, 500_000_000));
+		assert_eq!(
+			reading(1_718_000_000 + 37).unwrap().naive_utc(),
+			NaiveDateTime::from_timestamp(Timestamp::new(1_718_000_000, 500_000_000))
+		);
+		assert_eq!(
+			reading(1_483_228_800 + 36).unwrap().naive_utc(),
+			datetime!(2016-12-31 23:59:60.5)
+		);
+		assert_eq!(
+			reading(1_483_228_800 + 37).unwrap().naive_utc(),
+			datetime!(2017-01-01 00:00:00.5)
+		);
+		assert!(reading(i64::MAX).is_none());
+	}
+
+	#[test]
+	fn map_local_across_daylight_saving() {
+		use crate::{datetime, time};
+
+		let evening = DateTime::from_utc(datetime!(2024-03-10 23:00), Eastern2024);
+		let at = |time, policy| {
+			evening
+				.map_local(|local| NaiveDateTime::new(local.date(), time), policy)
+				.map(|date_time| date_time.naive_utc())
+		};
+
+		// the offset changes from -5 to -4 between the two local times
+		assert_eq!(
+			at(time!(01:00), LocalTimePolicy::Reject),
+			Ok(datetime!(2024-03-10 06:00))
+		);
+		assert_eq!(
+			at(time!(09:00), LocalTimePolicy::Reject),
+			Ok(datetime!(2024-03-10 13:00))
+		);
+		assert_eq!(
+			at(time!(02:30), LocalTimePolicy::Reject),
+			Err(LocalTimeError::Skipped)
+		);
+		assert_eq!(
+			at(time!(02:30), LocalTimePolicy::Compatible),
+			Ok(datetime!(2024-03-10 07:30))
+		);
+	}
+
+	#[test]
+	fn same_local_time_across_daylight_saving() {
+		use crate::datetime;
+
+		let local =
+			|local| DateTime::from_local_with_policy(local, Eastern2024, LocalTimePolicy::Reject);
+		let next_day = |local: NaiveDateTime, policy| {
+			let date_time =
+				DateTime::from_utc(local.add_seconds_overflowing(5 * 3600).0, Eastern2024);
+			date_time
+				.same_local_time_after_days(1, policy)
+				.map(|date_time| date_time.naive_utc())
+		};
+
+		// the day is 23 hours long, but the alarm stays at 09:00
+		let alarm = next_day(datetime!(2024-03-09 09:00), LocalTimePolicy::Reject);
+		assert_eq!(alarm, Ok(datetime!(2024-03-10 13:00)));
+
+		// 02:30 is skipped
+		let skipped = datetime!(2024-03-09 02:30);
+		assert_eq!(
+			next_day(skipped, LocalTimePolicy::Reject),
+			Err(LocalTimeError::Skipped)
+		);
+		assert_eq!(
+			next_day(skipped, LocalTimePolicy::Compatible),
+			Ok(datetime!(2024-03-10 07:30))
+		);
+		assert_eq!(
+			next_day(skipped, LocalTimePolicy::Later),
+			Ok(datetime!(2024-03-10 07:30))
+		);
+		assert_eq!(
+			next_day(skipped, LocalTimePolicy::Earlier),
+			Ok(datetime!(2024-03-10 06:30))
+		);
+
+		// 01:30 happens twice
+		let repeated = datetime!(2024-11-03 01:30);
+		assert_eq!(local(repeated), Err(LocalTimeError::Repeated));
+		let policy = |policy| {
+			DateTime::from_local_with_policy(repeated, Eastern2024, policy)
+				.map(|date_time| date_time.naive_utc())
+		};
+		assert_eq!(
+			policy(LocalTimePolicy::Compatible),
+			Ok(datetime!(2024-11-03 05:30))
+		);
+		assert_eq!(
+			policy(LocalTimePolicy::Earlier),
+			Ok(datetime!(2024-11-03 05:30))
+		);
+		assert_eq!(
+			policy(LocalTimePolicy::Later),
+			Ok(datetime!(2024-11-03 06:30))
+		);
+
+		assert_eq!(
+			local(datetime!(2024-11-03 03:00)).map(|date_time| date_time.naive_utc()),
+			Ok(datetime!(2024-11-03 08:00))
+		);
 	}
 }
@@ -0,0 +1,97 @@
+//! defmt support, for logging times from firmware.
+//!
+//! Each type is sent as a few integers, and formatted by the host. Times are
+//! shown the same way as their `Display` implementations, except that the
+//! fractional seconds always have nine digits.
+
+use ::defmt::{write, Format, Formatter};
+
+use crate::{
+	smear::SmearedUtc,
+	tai::{LeapSecondProvider, Tai},
+	timezone::{Utc, UtcOffset},
+	Date, DateTime, NaiveDateTime, Time, TimeZone, Year,
+};
+
+impl Format for Year {
+	fn format(&self, f: Formatter<'_>) {
+		write!(f, "{=i16}", self.as_i16());
+	}
+}
+
+impl Format for Date {
+	fn format(&self, f: Formatter<'_>) {
+		write!(
+			f,
+			"{=i16}-{=u8:02}-{=u8:02}",
+			self.year().as_i16(),
+			self.month().number(),
+			self.day()
+		);
+	}
+}
+
+impl Format for Time {
+	fn format(&self, f: Formatter<'_>) {
+		write!(
+			f,
+			"{=u8:02}:{=u8:02}:{=u8:02}.{=u32:09}",
+			self.hour(),
+			self.minute(),
+			self.second(),
+			self.nanosecond()
+		);
+	}
+}
+
+impl Format for NaiveDateTime {
+	fn format(&self, f: Formatter<'_>) {
+		write!(f, "{} {}", self.date(), self.time());
+	}
+}
+
+impl<Tz: TimeZone + Format> Format for DateTime<Tz> {
+	fn format(&self, f: Formatter<'_>) {
+		write!(f, "{} {}", self.naive_utc(), self.timezone());
+	}
+}
+
+impl Format for UtcOffset {
+	fn format(&self, f: Formatter<'_>) {
+		let seconds = self.seconds_ahead();
+		if seconds == 0 {
+			write!(f, "UTC");
+			return;
+		}
+
+		let sign = if seconds.is_negative() { '-' } else { '+' };
+		let seconds = seconds.unsigned_abs();
+		let (hours, minutes) = ((seconds / 3600) as u8, (seconds % 3600 / 60) as u8);
+		match (seconds % 60) as u8 {
+			0 => write!(f, "UTC{=char}{=u8:02}:{=u8:02}", sign, hours, minutes),
+			seconds => write!(
+				f,
+				"UTC{=char}{=u8:02}:{=u8:02}:{=u8:02}",
+				sign, hours, minutes, seconds
+			),
+		}
+	}
+}
+
+impl Format for Utc {
+	fn format(&self, f: Formatter<'_>) {
+		write!(f, "UTC");
+	}
+}
+
+impl Format for SmearedUtc {
+	fn format(&self, f: Formatter<'_>) {
+		write!(f, "Smeared UTC");
+	}
+}
+
+impl<P: LeapSecondProvider> Format for Tai<P> {
+	fn format(&self, f: Formatter<'_>) {
+		write!(f, "TAI");
+	}
+}
@@ -0,0 +1,303 @@
+use core::fmt::Display;
+
+use thiserror::Error;
+
+/// A component of a date, time, or offset was outside of its valid range.
+///
+/// The component is named, so an invalid hour can be told apart from an
+/// invalid nanosecond without looking at the error message.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Error)]
+#[error("The {name} must be in the range {minimum}..={maximum}, but it was {value}")]
+pub struct ComponentRange {
+	name: &'static str,
+	value: i64,
+	minimum: i64,
+	maximum: i64,
+}
+
+impl ComponentRange {
+	/// Checks that the component is in the range `minimum..=maximum`
+	pub(crate) const fn check(
+		name: &'static str,
+		value: i64,
+		minimum: i64,
+		maximum: i64,
+	) -> Result<(), Self> {
+		if value < minimum || value > maximum {
+			return Err(Self {
+				name,
+				value,
+				minimum,
+				maximum,
+			});
+		}
+
+		Ok(())
+	}
+
+	/// Panics with the same message as the error. Unlike formatting the
+	/// error, this can be used in a `const`.
+	#[track_caller]
+	pub(crate) const fn panic(self) -> ! {
+		panic!("{}", self.message().as_str())
+	}
+
+	/// Writes the error message without formatting, so that it can be built
+	/// in a `const`
+	const fn message(&self) -> Message {
+		Message::new()
+			.push_str("The ")
+			.push_str(self.name)
+			.push_str(" must be in the range ")
+			.push_i64(self.minimum)
+			.push_str("..=")
+			.push_i64(self.maximum)
+			.push_str(", but it was ")
+			.push_i64(self.value)
+	}
+
+	/// The name of the component, such as `"hour"` or `"nanosecond"`
+	#[must_use]
+	pub const fn name(&self) -> &'static str {
+		self.name
+	}
+
+	/// The value which was given for the component
+	#[must_use]
+	pub const fn value(&self) -> i64 {
+		self.value
+	}
+
+	/// The smallest valid value of the component
+	#[must_use]
+	pub const fn minimum(&self) -> i64 {
+		self.minimum
+	}
+
+	/// The largest valid value of the component. This may depend on the other
+	/// components, such as the number of days in a month.
+	#[must_use]
+	pub const fn maximum(&self) -> i64 {
+		self.maximum
+	}
+}
+
+/// The length of the longest [`ComponentRange`] message, with room for a long
+/// name and three of the longest `i64`s
+const MAX_MESSAGE_LEN: usize = 160;
+
+/// An error message which is built in a fixed-size buffer, because strings
+/// can't be formatted or allocated in a `const`
+struct Message {
+	bytes: [u8; MAX_MESSAGE_LEN],
+	len: usize,
+}
+
+impl Message {
+	const fn new() -> Self {
+		Self {
+			bytes: [0; MAX_MESSAGE_LEN],
+			len: 0,
+		}
+	}
+
+	/// Appends a byte, dropping it if the buffer is full
+	const fn push_byte(mut self, byte: u8) -> Self {
+		if self.len < MAX_MESSAGE_LEN {
+			self.bytes[self.len] = byte;
+			self.len += 1;
+		}
+
+		self
+	}
+
+	const fn push_str(mut self, string: &str) -> Self {
+		let bytes = string.as_bytes();
+		let mut i = 0;
+		while i < bytes.len() {
+			self = self.push_byte(bytes[i]);
+			i += 1;
+		}
+
+		self
+	}
+
+	const fn push_i64(mut self, value: i64) -> Self {
+		if value < 0 {
+			self = self.push_byte(b'-');
+		}
+
+		// the digits are found from least to most significant
+		let mut digits = [0; 20];
+		let mut count = 0;
+		let mut remaining = value.unsigned_abs();
+		loop {
+			digits[count] = b'0' + (remaining % 10) as u8;
+			count += 1;
+			remaining /= 10;
+			if remaining == 0 {
+				break;
+			}
+		}
+
+		while count > 0 {
+			count -= 1;
+			self = self.push_byte(digits[count]);
+		}
+
+		self
+	}
+
+	const fn as_str(&self) -> &str {
+		match core::str::from_utf8(self.bytes.split_at(self.len).0) {
+			Ok(message) => message,
+			// a multi-byte character was cut off at the end of the buffer
+			Err(_) => "A component is out of range",
+		}
+	}
+}
+
+/// A date and time was outside of the range which can be represented, such as
+/// after an offset from UTC is applied to [`NaiveDateTime::MAX`].
+///
+/// [`NaiveDateTime::MAX`]: crate::NaiveDateTime::MAX
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Error)]
+#[error("The date and time is outside of the representable range")]
+pub struct OutOfRange;
+
+/// A local date and time couldn't be moved to another time zone by
+/// [`DateTime::with_timezone_keep_local`]
+///
+/// [`DateTime::with_timezone_keep_local`]: crate::DateTime::with_timezone_keep_local
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Error)]
+pub enum KeepLocalError<E> {
+	/// The local date and time, or the instant in the new time zone, is out
+	/// of range
+	#[error(transparent)]
+	OutOfRange(#[from] OutOfRange),
+	/// The local date and time doesn't exist in the new time zone
+	#[error("The local date and time is invalid in the time zone: {0}")]
+	TimeZone(E),
+}
+
+/// The longest name which can be suggested by [`closest_match`]
+const MAX_SUGGESTION_LEN: usize = 15;
+
+/// Finds the name which is closest to the input, ignoring letter case, so that
+/// a parsing error can suggest what was meant. Returns `None` if none of the
+/// names are close enough for the input to be a likely typo.
+pub(crate) fn closest_match<T>(
+	input: &str,
+	candidates: impl IntoIterator<Item = (&'static str, T)>,
+) -> Option<T> {
+	candidates
+		.into_iter()
+		.map(|(name, value)| (edit_distance(input, name), name, value))
+		.filter(|(distance, name, _)| *distance <= name.len() / 3)
+		.min_by_key(|(distance, _, _)| *distance)
+		.map(|(_, _, value)| value)
+}
+
+/// The number of insertions, deletions, substitutions, and swaps of adjacent
+/// letters needed to turn the input into the name, ignoring letter case. The
+/// name must be ASCII, and no longer than [`MAX_SUGGESTION_LEN`].
+fn edit_distance(input: &str, name: &str) -> usize {
+	let name = name.as_bytes();
+	debug_assert!(name.len() <= MAX_SUGGESTION_LEN);
+
+	// only the last three rows of the table are needed
+	let mut before_previous = [0; MAX_SUGGESTION_LEN + 1];
+	let mut previous = [0; MAX_SUGGESTION_LEN + 1];
+	let mut current = [0; MAX_SUGGESTION_LEN + 1];
+	for (j, distance) in previous.iter_mut().enumerate() {
+		*distance = j;
+	}
+
+	let mut last = None;
+	for (i, c) in input.chars().enumerate() {
+		let c = c.to_ascii_lowercase();
+		current[0] = i + 1;
+		for j in 1..=name.len() {
+			let matches = |k: usize| c == char::from(name[k].to_ascii_lowercase());
+			let substitution = previous[j - 1] + usize::from(!matches(j - 1));
+			current[j] = substitution.min(previous[j] + 1).min(current[j - 1] + 1);
+
+			let swapped = j > 1
+				&& matches(j - 2)
+				&& last == Some(char::from(name[j - 1].to_ascii_lowercase()));
+			if swapped {
+				current[j] = current[j].min(before_previous[j - 2] + 1);
+			}
+		}
+
+		before_previous = previous;
+		previous = current;
+		last = Some(c);
+	}
+
+	previous[name.len()]
+}
+
+/// Formats a suggestion at the end of an error message, if there is one
+pub(crate) struct DidYouMean<'a, T>(pub(crate) &'a Option<T>);
+
+impl<T: Display> Display for DidYouMean<'_, T> {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self.0 {
+			Some(suggestion) => write!(f, ". Did you mean {suggestion}?"),
+			None => Ok(()),
+		}
+	}
+}
+
+/// Returns early with the error if the component isn't in range
+macro_rules! check_range {
+	($name:literal, $value:expr, $minimum:expr, $maximum:expr) => {
+		if let Err(error) = $crate::error::ComponentRange::check(
+			$name,
+			$value as i64,
+			$minimum as i64,
+			$maximum as i64,
+		) {
+			return Err(error);
+		}
+	};
+}
+
+pub(crate) use check_range;
+
+#[cfg(test)]
+mod tests {
+	#[cfg(feature = "alloc")]
+	use alloc::string::ToString;
+
+	use super::*;
+
+	#[test]
+	fn edit_distances() {
+		assert_eq!(edit_distance("January", "January"), 0);
+		assert_eq!(edit_distance("JANUARY", "January"), 0);
+		assert_eq!(edit_distance("Janury", "January"), 1);
+		assert_eq!(edit_distance("Janurary", "January"), 1);
+		assert_eq!(edit_distance("Marhc", "March"), 1);
+		assert_eq!(edit_distance("Fbe", "Feb"), 1);
+		assert_eq!(edit_distance("", "May"), 3);
+		assert_eq!(edit_distance("Tuesdya", "Tuesday"), 1);
+		assert_eq!(edit_distance("Wednesday", "Sunday"), 5);
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn panic_message_matches_error() {
+		for (value, minimum, maximum) in [(60, 0, 59), (-1, 1, 31), (i64::MIN, i64::MIN, i64::MAX)]
+		{
+			let error = ComponentRange {
+				name: "minute",
+				value,
+				minimum,
+				maximum,
+			};
+			assert_eq!(error.message().as_str(), error.to_string());
+		}
+	}
+}
@@ -0,0 +1,196 @@
+//! Reading the timestamps which are embedded in unique IDs.
+//!
+//! Many kinds of IDs start with the time they were created, so that they sort
+//! in the order they were made. This means that the creation time of a
+//! message or an account can be found from its ID alone.
+
+use crate::{timezone::Utc, DateTime, NaiveDateTime, OutOfRange, Timestamp};
+
+/// The epoch of Twitter's snowflake IDs, 2010-11-04 01:42:54.657 UTC
+pub const TWITTER_EPOCH: Timestamp = Timestamp::from_millis(1_288_834_974_657);
+
+/// The epoch of Discord's snowflake IDs, which is the first second of 2015
+pub const DISCORD_EPOCH: Timestamp = Timestamp::from_millis(1_420_070_400_000);
+
+/// The number of bits in a snowflake which come after the timestamp. These
+/// hold the worker ID, process ID, and sequence number.
+const SNOWFLAKE_TIMESTAMP_SHIFT: u32 = 22;
+
+/// The number of bits in a ULID or UUIDv7 which come after the timestamp
+const PREFIX_SHIFT: u32 = 80;
+
+/// The largest number of milliseconds which fit in the 48-bit prefix
+const MAX_PREFIX: i128 = (1 << 48) - 1;
+
+/// Gets the time that a snowflake ID was created, given the epoch that the
+/// ID's timestamp is counted from. The timestamp is the number of milliseconds
+/// since the epoch, in the top 42 bits of the ID.
+///
+/// Returns `None` if the time can't be represented, which is only possible if
+/// the epoch is near [`Timestamp::MAX`].
+///
+/// # Example
+///
+/// ```
+/// use botic::id::{snowflake_timestamp, DISCORD_EPOCH};
+/// use botic::Timestamp;
+///
+/// let timestamp = snowflake_timestamp(175_928_847_299_117_063, DISCORD_EPOCH);
+/// assert_eq!(timestamp, Some(Timestamp::from_millis(1_462_015_105_796)));
+/// ```
+#[must_use]
+pub const fn snowflake_timestamp(id: u64, epoch: Timestamp) -> Option<Timestamp> {
+	let milliseconds = (id >> SNOWFLAKE_TIMESTAMP_SHIFT) as i128;
+	Timestamp::from_nanos(epoch.as_nanos() + milliseconds * 1_000_000)
+}
+
+/// Gets the date and time that a snowflake ID was created, given the epoch
+/// that the ID's timestamp is counted from. See [`snowflake_timestamp`].
+///
+/// # Example
+///
+/// ```
+/// use botic::id::{snowflake_datetime, TWITTER_EPOCH};
+///
+/// let created = snowflake_datetime(1_541_815_603_606_036_480, TWITTER_EPOCH).unwrap();
+/// assert_eq!(created.to_string(), "2022-06-28 16:07:40.105 UTC");
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if the date is after the year 32767, which is only
+/// possible with an unusual epoch
+pub fn snowflake_datetime(id: u64, epoch: Timestamp) -> Result<DateTime<Utc>, OutOfRange> {
+	let timestamp = snowflake_timestamp(id, epoch).ok_or(OutOfRange)?;
+	Ok(DateTime::from_utc(NaiveDateTime::try_from(timestamp)?, Utc))
+}
+
+/// Gets the time that a ULID was created. The top 48 bits of a ULID are the
+/// number of milliseconds since the Unix epoch.
+///
+/// # Example
+///
+/// ```
+/// use botic::id::ulid_timestamp;
+/// use botic::Timestamp;
+///
+/// // 01ARZ3NDEKTSV4RRFFQ69G5FAV
+/// let ulid = 0x0156_3E3A_B5D3_D676_4C61_EFB9_9302_BD5B;
+/// assert_eq!(ulid_timestamp(ulid), Timestamp::from_millis(1_469_922_850_259));
+/// ```
+#[must_use]
+pub const fn ulid_timestamp(ulid: u128) -> Timestamp {
+	Timestamp::from_millis((ulid >> PREFIX_SHIFT) as i64)
+}
+
+/// Gets the date and time that a ULID was created. See [`ulid_timestamp`].
+#[must_use]
+pub const fn ulid_datetime(ulid: u128) -> DateTime<Utc> {
+	DateTime::from_utc(NaiveDateTime::from_timestamp(ulid_timestamp(ulid)), Utc)
+}
+
+/// Gets the time that a version 7 UUID was created, or `None` if this is a
+/// different version of UUID. Like a ULID, the top 48 bits are the number of
+/// milliseconds since the Unix epoch.
+///
+/// # Example
+///
+/// ```
+/// use botic::id::uuid_v7_timestamp;
+/// use botic::Timestamp;
+///
+/// // 017f22e2-79b0-7cc3-98c4-dc0c0c07398f, from RFC 9562
+/// let uuid = 0x017F_22E2_79B0_7CC3_98C4_DC0C_0C07_398F;
+/// assert_eq!(uuid_v7_timestamp(uuid), Some(Timestamp::from_millis(1_645_557_742_000)));
+/// assert_eq!(uuid_v7_timestamp(0x017F_22E2_79B0_4CC3_98C4_DC0C_0C07_398F), None);
+/// ```
+#[must_use]
+pub const fn uuid_v7_timestamp(uuid: u128) -> Option<Timestamp> {
+	if (uuid >> 76) & 0xF != 7 {
+		return None;
+	}
+
+	Some(ulid_timestamp(uuid))
+}
+
+/// Gets the date and time that a version 7 UUID was created, or `None` if
+/// this is a different version of UUID. See [`uuid_v7_timestamp`].
+#[must_use]
+pub const fn uuid_v7_datetime(uuid: u128) -> Option<DateTime<Utc>> {
+	match uuid_v7_timestamp(uuid) {
+		Some(timestamp) => Some(DateTime::from_utc(
+			NaiveDateTime::from_timestamp(timestamp),
+			Utc,
+		)),
+		None => None,
+	}
+}
+
+/// Gets the 48-bit millisecond prefix of a ULID or UUIDv7 which is created at
+/// the given time. The prefix goes in the top 48 bits of the ID, so the IDs
+/// sort in the order they're created.
+///
+/// Returns `None` if the time is before the Unix epoch, or after the year
+/// 10889, which is the last time a 48-bit prefix can hold.
+///
+/// # Example
+///
+/// ```
+/// use botic::id::{timestamp_prefix, ulid_timestamp};
+/// use botic::Timestamp;
+///
+/// let now = Timestamp::from_millis(1_469_922_850_259);
+/// let prefix = timestamp_prefix(now).unwrap();
+/// let random_bits = 0xD676_4C61_EFB9_9302_BD5B;
+/// let ulid = (u128::from(prefix) << 80) | random_bits;
+/// assert_eq!(ulid_timestamp(ulid), now);
+/// ```
+#[must_use]
+pub const fn timestamp_prefix(timestamp: Timestamp) -> Option<u64> {
+	let milliseconds = timestamp.as_millis();
+	if milliseconds < 0 || milliseconds > MAX_PREFIX {
+		return None;
+	}
+
+	Some(milliseconds as u64)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn snowflakes() {
+		assert_eq!(snowflake_timestamp(0, DISCORD_EPOCH), Some(DISCORD_EPOCH));
+		assert_eq!(
+			snowflake_timestamp(u64::MAX, Timestamp::EPOCH),
+			Some(Timestamp::from_millis((1 << 42) - 1))
+		);
+		assert_eq!(snowflake_timestamp(1 << 22, Timestamp::MAX), None);
+		assert_eq!(
+			snowflake_datetime(0, DISCORD_EPOCH).unwrap(),
+			crate::datetime!(2015-01-01 00:00 UTC)
+		);
+		assert!(snowflake_datetime(u64::MAX, Timestamp::new(1 << 40, 0)).is_err());
+	}
+
+	#[test]
+	fn prefixes() {
+		let latest = Timestamp::from_millis(MAX_PREFIX as i64);
+		assert_eq!(ulid_timestamp(u128::MAX), latest);
+		assert_eq!(ulid_datetime(u128::MAX).year().as_i16(), 10889);
+		assert_eq!(timestamp_prefix(latest), Some((1 << 48) - 1));
+		assert_eq!(
+			timestamp_prefix(Timestamp::from_millis(MAX_PREFIX as i64 + 1)),
+			None
+		);
+		assert_eq!(timestamp_prefix(Timestamp::new(-1, 999_999_999)), None);
+		assert_eq!(timestamp_prefix(Timestamp::EPOCH), Some(0));
+
+		let uuid = 0x017F_22E2_79B0_7CC3_98C4_DC0C_0C07_398F;
+		assert_eq!(
+			uuid_v7_datetime(uuid).unwrap(),
+			crate::datetime!(2022-02-22 19:22:22 UTC)
+		);
+	}
+}
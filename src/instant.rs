@@ -0,0 +1,206 @@
+use core::ops::{Add, AddAssign, Sub, SubAssign};
+use std::time::Duration;
+
+use crate::{tai::Tai, DateTime, NaiveDateTime};
+
+/// A measurement of a monotonically nondecreasing clock.
+///
+/// Unlike [`DateTime`], this is never affected by changes to the system clock,
+/// so it's useful for measuring how long something takes. Subtracting an
+/// earlier instant never panics, and returns zero instead.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct Instant(std::time::Instant);
+
+impl Instant {
+	/// Gets the current value of the monotonic clock
+	#[must_use]
+	pub fn now() -> Self {
+		Self(std::time::Instant::now())
+	}
+
+	/// Gets the amount of time that has passed since this instant
+	#[must_use]
+	pub fn elapsed(&self) -> Duration {
+		self.0.elapsed()
+	}
+
+	/// Gets the amount of time from `earlier` to this instant, or zero if
+	/// `earlier` is after this instant
+	#[must_use]
+	pub fn duration_since(&self, earlier: Self) -> Duration {
+		self.0.saturating_duration_since(earlier.0)
+	}
+
+	/// Gets the amount of time from `earlier` to this instant, or `None` if
+	/// `earlier` is after this instant
+	#[must_use]
+	pub fn checked_duration_since(&self, earlier: Self) -> Option<Duration> {
+		self.0.checked_duration_since(earlier.0)
+	}
+
+	/// Adds a duration to this instant, or returns `None` if the result can't
+	/// be represented
+	#[must_use]
+	pub fn checked_add(&self, duration: Duration) -> Option<Self> {
+		self.0.checked_add(duration).map(Self)
+	}
+
+	/// Subtracts a duration from this instant, or returns `None` if the result
+	/// can't be represented
+	#[must_use]
+	pub fn checked_sub(&self, duration: Duration) -> Option<Self> {
+		self.0.checked_sub(duration).map(Self)
+	}
+
+	/// Adds a duration to this instant. If the result can't be represented,
+	/// this gives the latest instant that can be.
+	#[must_use]
+	pub fn saturating_add(&self, duration: Duration) -> Self {
+		Self(saturate(self.0, duration, std::time::Instant::checked_add))
+	}
+
+	/// Subtracts a duration from this instant. If the result can't be
+	/// represented, this gives the earliest instant that can be.
+	#[must_use]
+	pub fn saturating_sub(&self, duration: Duration) -> Self {
+		Self(saturate(self.0, duration, std::time::Instant::checked_sub))
+	}
+
+	/// Estimates the TAI date and time of this instant, by comparing it to the
+	/// current time. Since the system clock may have been adjusted since this
+	/// instant, this is only an estimate.
+	///
+	/// If the date and time is out of range, then it's clamped to
+	/// [`NaiveDateTime::MIN`] or [`NaiveDateTime::MAX`].
+	#[must_use]
+	pub fn to_tai(&self) -> DateTime<Tai> {
+		let now = Self::now();
+		let tai_now = DateTime::<Tai>::now();
+
+		let nanoseconds =
+			|duration: Duration| i128::try_from(duration.as_nanos()).unwrap_or(i128::MAX);
+		let offset = if now >= *self {
+			-nanoseconds(now.duration_since(*self))
+		} else {
+			nanoseconds(self.duration_since(now))
+		};
+
+		match tai_now.add_tai_nanoseconds_overflowing(offset) {
+			(then, false) => then,
+			(_, true) if offset < 0 => DateTime::from_utc(NaiveDateTime::MIN, Tai::new()),
+			(_, true) => DateTime::from_utc(NaiveDateTime::MAX, Tai::new()),
+		}
+	}
+}
+
+/// Moves an instant by as much of the duration as can be represented.
+/// [`std::time::Instant`] doesn't have a maximum or minimum, so this finds it
+/// by halving the remaining step each time it overflows.
+fn saturate(
+	mut instant: std::time::Instant,
+	duration: Duration,
+	checked_move: fn(&std::time::Instant, Duration) -> Option<std::time::Instant>,
+) -> std::time::Instant {
+	let mut remaining = duration;
+	let mut step = duration;
+	while !remaining.is_zero() && !step.is_zero() {
+		step = step.min(remaining);
+		match checked_move(&instant, step) {
+			Some(moved) => {
+				instant = moved;
+				remaining -= step;
+			}
+			None => step /= 2,
+		}
+	}
+
+	instant
+}
+
+impl From<std::time::Instant> for Instant {
+	fn from(instant: std::time::Instant) -> Self {
+		Self(instant)
+	}
+}
+
+impl From<Instant> for std::time::Instant {
+	fn from(instant: Instant) -> Self {
+		instant.0
+	}
+}
+
+impl Add<Duration> for Instant {
+	type Output = Self;
+
+	fn add(self, rhs: Duration) -> Self::Output {
+		self.checked_add(rhs)
+			.expect("overflow when adding duration to instant")
+	}
+}
+
+impl AddAssign<Duration> for Instant {
+	fn add_assign(&mut self, rhs: Duration) {
+		*self = *self + rhs;
+	}
+}
+
+impl Sub<Duration> for Instant {
+	type Output = Self;
+
+	fn sub(self, rhs: Duration) -> Self::Output {
+		self.checked_sub(rhs)
+			.expect("overflow when subtracting duration from instant")
+	}
+}
+
+impl SubAssign<Duration> for Instant {
+	fn sub_assign(&mut self, rhs: Duration) {
+		*self = *self - rhs;
+	}
+}
+
+impl Sub<Instant> for Instant {
+	type Output = Duration;
+
+	fn sub(self, rhs: Instant) -> Self::Output {
+		self.duration_since(rhs)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn saturating() {
+		let now = Instant::now();
+		let latest = now.saturating_add(Duration::MAX);
+		assert!(latest > now);
+		assert_eq!(latest.checked_add(Duration::from_secs(1)), None);
+		assert_eq!(latest.saturating_add(Duration::from_secs(1)), latest);
+		assert_eq!(
+			now.saturating_add(Duration::from_secs(1)),
+			now + Duration::from_secs(1)
+		);
+
+		let earliest = now.saturating_sub(Duration::MAX);
+		assert!(earliest <= now);
+		assert_eq!(earliest.checked_sub(Duration::from_secs(1)), None);
+		assert_eq!(earliest.saturating_sub(Duration::from_secs(1)), earliest);
+	}
+
+	#[test]
+	fn to_tai() {
+		let now = Instant::now();
+		let later = now + Duration::from_secs(3600);
+		let difference = later.to_tai().time_until(&now.to_tai());
+		assert!(difference.abs_diff(Duration::from_secs(3600)) < Duration::from_secs(1));
+		assert!(DateTime::<Tai>::now().time_until(&now.to_tai()) < Duration::from_secs(1));
+
+		let latest = now.saturating_add(Duration::MAX);
+		assert_eq!(
+			latest.to_tai(),
+			DateTime::from_utc(NaiveDateTime::MAX, Tai::new())
+		);
+	}
+}
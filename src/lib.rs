@@ -1,23 +1,69 @@
 #![doc = include_str!("../README.md")]
+#![cfg_attr(not(feature = "std"), no_std)]
 
-// TODO serde support
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
+mod builder;
+#[cfg(feature = "std")]
+pub mod clock;
+mod components;
+mod countdown;
 mod date;
 mod datetime;
+#[cfg(feature = "defmt")]
+mod defmt;
+mod error;
+pub mod id;
+#[cfg(feature = "std")]
+mod instant;
+mod macros;
 mod month;
+pub mod parse;
+#[cfg(feature = "serde")]
+mod serde;
+pub mod sidereal;
+pub mod smear;
+#[cfg(feature = "std")]
+mod sys;
 pub mod tai;
 mod time;
+#[cfg(any(feature = "async-std", feature = "smol"))]
+pub mod timer;
 mod timestamp;
 pub mod timezone;
+#[cfg(feature = "tokio")]
+pub mod tokio;
+#[cfg(any(feature = "tokio", feature = "async-std", feature = "smol"))]
+mod wall_clock;
 mod weekday;
 mod year;
 
+pub use builder::BuildDateTimeError;
+pub use builder::DateTimeBuilder;
+pub use components::Datelike;
+pub use components::Timelike;
+pub use countdown::Countdown;
+pub use countdown::CountdownUnit;
 pub use date::Date;
+pub use date::DateRange;
+pub use date::DayOrdinal;
+pub use date::MonthStarts;
 pub use datetime::DateTime;
 pub use datetime::NaiveDateTime;
+pub use error::ComponentRange;
+pub use error::KeepLocalError;
+pub use error::OutOfRange;
+#[cfg(feature = "std")]
+pub use instant::Instant;
+#[doc(hidden)]
+pub use macros::__private;
 pub use month::Month;
 pub use time::Time;
 pub use timestamp::Timestamp;
+pub use timestamp::UnixNanos;
 pub use timezone::TimeZone;
 pub use weekday::Weekday;
+pub use year::Era;
 pub use year::Year;
+pub use year::YearWithEra;
@@ -0,0 +1,343 @@
+//! Macros for writing dates and times as literals.
+//!
+//! Each macro expands to a `const`, so an invalid literal is a compile error.
+
+/// Creates a [`Date`](crate::Date) from a literal, such as `2024-03-01`. The
+/// date is checked at compile time.
+///
+/// # Example
+///
+/// ```
+/// use botic::{date, Date, Month};
+///
+/// const LEAP_DAY: Date = date!(2024-02-29);
+/// assert_eq!(LEAP_DAY, Date::from_ymd(2024.into(), Month::February, 29).unwrap());
+/// assert_eq!(date!(-44-03-15).year().as_i16(), -44);
+/// ```
+///
+/// ```compile_fail
+/// let date = botic::date!(2023-02-29);
+/// ```
+#[macro_export]
+macro_rules! date {
+	(- $year:literal - $month:literal - $day:literal) => {{
+		const DATE: $crate::Date = $crate::__private::date(
+			true,
+			stringify!($year),
+			stringify!($month),
+			stringify!($day),
+		);
+		DATE
+	}};
+	($year:literal - $month:literal - $day:literal) => {{
+		const DATE: $crate::Date = $crate::__private::date(
+			false,
+			stringify!($year),
+			stringify!($month),
+			stringify!($day),
+		);
+		DATE
+	}};
+}
+
+/// Creates a [`Time`](crate::Time) from a literal, such as `12:30` or
+/// `12:30:00.5`. The time is checked at compile time.
+///
+/// # Example
+///
+/// ```
+/// use botic::{time, Time};
+///
+/// const LUNCH: Time = time!(12:30:00.5);
+/// assert_eq!(LUNCH.minute(), 30);
+/// assert_eq!(LUNCH.millisecond(), 500);
+/// assert_eq!(time!(00:00), Time::MIDNIGHT);
+/// ```
+///
+/// ```compile_fail
+/// let time = botic::time!(24:00);
+/// ```
+#[macro_export]
+macro_rules! time {
+	($hour:literal : $minute:literal $(: $second:literal)?) => {{
+		const TIME: $crate::Time =
+			$crate::__private::time(
+			stringify!($hour),
+			stringify!($minute),
+			stringify!($($second)?),
+		);
+		TIME
+	}};
+}
+
+/// Creates a [`UtcOffset`](crate::timezone::UtcOffset) from a literal, such as
+/// `+05:30`, `-5`, or `UTC`. The offset is checked at compile time.
+///
+/// # Example
+///
+/// ```
+/// use botic::offset;
+/// use botic::timezone::UtcOffset;
+///
+/// assert_eq!(offset!(+05:30).seconds_ahead(), 19_800);
+/// assert_eq!(offset!(-5), UtcOffset::from_hours(-5).unwrap());
+/// assert_eq!(offset!(UTC), UtcOffset::UTC);
+/// ```
+#[macro_export]
+macro_rules! offset {
+	(UTC) => {
+		$crate::timezone::UtcOffset::UTC
+	};
+	(+ $hours:literal $(: $minutes:literal $(: $seconds:literal)?)?) => {{
+		const OFFSET: $crate::timezone::UtcOffset =
+			$crate::__private::offset(
+			false,
+			stringify!($hours),
+			stringify!($($minutes)?),
+			stringify!($($($seconds)?)?),
+		);
+		OFFSET
+	}};
+	(- $hours:literal $(: $minutes:literal $(: $seconds:literal)?)?) => {{
+		const OFFSET: $crate::timezone::UtcOffset =
+			$crate::__private::offset(
+			true,
+			stringify!($hours),
+			stringify!($($minutes)?),
+			stringify!($($($seconds)?)?),
+		);
+		OFFSET
+	}};
+}
+
+/// Creates a date and time from a literal, which is checked at compile time.
+///
+/// With `UTC` at the end, such as `2024-03-01 12:30 UTC`, this creates a
+/// [`DateTime<Utc>`](crate::DateTime). With an offset, such as
+/// `2024-03-01 12:30 +05:30`, it creates a `DateTime<UtcOffset>` for that
+/// local time. Without a time zone, it creates a
+/// [`NaiveDateTime`](crate::NaiveDateTime).
+///
+/// # Example
+///
+/// ```
+/// use botic::{datetime, date, time, DateTime, NaiveDateTime};
+/// use botic::timezone::{Utc, UtcOffset};
+///
+/// const LAUNCH: DateTime<Utc> = datetime!(2024-03-01 12:30 UTC);
+/// assert_eq!(LAUNCH.naive_utc(), NaiveDateTime::new(date!(2024-03-01), time!(12:30)));
+///
+/// let local: DateTime<UtcOffset> = datetime!(2024-03-01 12:30:15 +05:30);
+/// assert_eq!(local.naive_utc().hour(), 7);
+/// ```
+#[macro_export]
+macro_rules! datetime {
+	(@ $date:expr, $time:expr ;) => {{
+		const DATE_TIME: $crate::NaiveDateTime = $crate::NaiveDateTime::new($date, $time);
+		DATE_TIME
+	}};
+	(@ $date:expr, $time:expr ; UTC) => {{
+		const DATE_TIME: $crate::DateTime<$crate::timezone::Utc> = $crate::DateTime::from_utc(
+			$crate::NaiveDateTime::new($date, $time),
+			$crate::timezone::Utc,
+		);
+		DATE_TIME
+	}};
+	(@ $date:expr, $time:expr ; $($offset:tt)+) => {{
+		const DATE_TIME: $crate::DateTime<$crate::timezone::UtcOffset> =
+			$crate::__private::datetime_at_offset(
+				$crate::NaiveDateTime::new($date, $time),
+				$crate::offset!($($offset)+),
+			);
+		DATE_TIME
+	}};
+	(@ $date:expr, $hour:literal, $minute:literal ; : $second:literal $($zone:tt)*) => {
+		$crate::datetime!(@ $date, $crate::time!($hour : $minute : $second); $($zone)*)
+	};
+	(@ $date:expr, $hour:literal, $minute:literal ; $($zone:tt)*) => {
+		$crate::datetime!(@ $date, $crate::time!($hour : $minute); $($zone)*)
+	};
+	(- $year:literal - $month:literal - $day:literal $hour:literal : $minute:literal $($rest:tt)*) => {
+		$crate::datetime!(@ $crate::date!(- $year - $month - $day), $hour, $minute; $($rest)*)
+	};
+	($year:literal - $month:literal - $day:literal $hour:literal : $minute:literal $($rest:tt)*) => {
+		$crate::datetime!(@ $crate::date!($year - $month - $day), $hour, $minute; $($rest)*)
+	};
+}
+
+/// Functions used by the macros. These panic instead of returning errors, so
+/// that an invalid literal fails to compile.
+#[doc(hidden)]
+pub mod __private {
+	use crate::{timezone::UtcOffset, Date, DateTime, Month, NaiveDateTime, Time, Year};
+
+	/// Parses a number from a literal, such as `"03"`. An empty string is zero.
+	/// The literals are parsed from strings, so that leading zeros don't
+	/// trigger lints in the caller's code.
+	const fn number(literal: &str) -> u32 {
+		let bytes = literal.as_bytes();
+		let mut number = 0_u32;
+		let mut i = 0;
+		while i < bytes.len() {
+			assert!(bytes[i].is_ascii_digit(), "expected a decimal number");
+			number = match number.checked_mul(10) {
+				Some(number) => number + (bytes[i] - b'0') as u32,
+				None => panic!("the number is too large"),
+			};
+			i += 1;
+		}
+
+		number
+	}
+
+	pub const fn date(negative: bool, year: &str, month: &str, day: &str) -> Date {
+		let (year, month, day) = (number(year), number(month), number(day));
+		assert!(year <= i16::MAX as u32, "the year is out of range");
+		assert!(
+			month >= 1 && month <= 12,
+			"the month must be in the range 1..=12"
+		);
+		assert!(day <= 31, "the day is out of range for the month");
+
+		let Some(month) = Month::from_u8(month as u8) else {
+			unreachable!()
+		};
+		let year = if negative {
+			-(year as i16)
+		} else {
+			year as i16
+		};
+		match Date::from_ymd(Year::from_i16(year), month, day as u8) {
+			Ok(date) => date,
+			Err(_) => panic!("the day is out of range for the month"),
+		}
+	}
+
+	/// Creates a time, given the seconds as written in the literal, such as
+	/// `"00.5"`
+	pub const fn time(hour: &str, minute: &str, second: &str) -> Time {
+		let (hour, minute) = (number(hour), number(minute));
+		assert!(hour <= 23, "the hour must be in the range 0..=23");
+		assert!(minute <= 59, "the minute must be in the range 0..=59");
+
+		let (seconds, fraction) = match second.as_bytes() {
+			[] => (0, 0),
+			bytes => split_fraction(bytes),
+		};
+		assert!(seconds <= 60, "the second must be in the range 0..=60");
+
+		match Time::from_hms_nano(hour as u8, minute as u8, seconds as u8, fraction) {
+			Ok(time) => time,
+			Err(_) => panic!("a leap second must be at 23:59:60"),
+		}
+	}
+
+	/// Splits a number of seconds, such as `"00.5"`, into the whole seconds
+	/// and the nanoseconds
+	const fn split_fraction(bytes: &[u8]) -> (u32, u32) {
+		let (mut seconds, mut nanoseconds, mut digits) = (0_u32, 0_u32, None);
+		let mut i = 0;
+		while i < bytes.len() {
+			let byte = bytes[i];
+			i += 1;
+
+			if byte == b'.' {
+				assert!(
+					digits.is_none(),
+					"the seconds can only have one decimal point"
+				);
+				digits = Some(0);
+				continue;
+			}
+
+			assert!(
+				byte.is_ascii_digit(),
+				"the seconds must be a decimal number"
+			);
+			let digit = (byte - b'0') as u32;
+			match digits {
+				None => {
+					seconds = seconds * 10 + digit;
+					assert!(seconds <= 60, "the second must be in the range 0..=60");
+				}
+				Some(count) => {
+					assert!(
+						count < 9,
+						"the seconds can't be more precise than nanoseconds"
+					);
+					nanoseconds = nanoseconds * 10 + digit;
+					digits = Some(count + 1);
+				}
+			}
+		}
+
+		if let Some(count) = digits {
+			nanoseconds *= 10_u32.pow(9 - count);
+		}
+
+		(seconds, nanoseconds)
+	}
+
+	pub const fn offset(negative: bool, hours: &str, minutes: &str, seconds: &str) -> UtcOffset {
+		let (hours, minutes, seconds) = (number(hours), number(minutes), number(seconds));
+		assert!(
+			minutes <= 59,
+			"the offset's minutes must be in the range 0..=59"
+		);
+		assert!(
+			seconds <= 59,
+			"the offset's seconds must be in the range 0..=59"
+		);
+		assert!(hours <= 26, "the offset must be within 26 hours of UTC");
+
+		let total = (hours * 3600 + minutes * 60 + seconds) as i32;
+		let total = if negative { -total } else { total };
+		match UtcOffset::from_seconds(total) {
+			Ok(offset) => offset,
+			Err(_) => panic!("the offset must be within 26 hours of UTC"),
+		}
+	}
+
+	pub const fn datetime_at_offset(
+		local: NaiveDateTime,
+		offset: UtcOffset,
+	) -> DateTime<UtcOffset> {
+		let (date_time, overflow) = DateTime::from_naive_local(local, offset);
+		assert!(!overflow, "the date and time is out of range");
+		date_time
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{timezone::Utc, Date, DateTime, Month, NaiveDateTime, Time, Year};
+
+	#[test]
+	fn literals_match_constructors() {
+		assert_eq!(
+			date!(-44 - 03 - 15),
+			Date::from_ymd(Year::from_i16(-44), Month::March, 15).unwrap()
+		);
+		assert_eq!(
+			time!(23:59:60.000000001),
+			Time::from_hms_nano(23, 59, 60, 1).unwrap()
+		);
+		assert_eq!(time!(08:09:10), Time::from_hms(8, 9, 10).unwrap());
+		assert_eq!(offset!(-03:30:15).seconds_ahead(), -12_615);
+	}
+
+	#[test]
+	fn datetime_forms() {
+		let naive = NaiveDateTime::new(date!(2016 - 12 - 31), time!(23:59:60));
+		assert_eq!(datetime!(2016-12-31 23:59:60), naive);
+		assert_eq!(
+			datetime!(2016-12-31 23:59:60 UTC),
+			DateTime::from_utc(naive, Utc)
+		);
+		assert_eq!(
+			datetime!(2017-01-01 00:59 +1).naive_utc(),
+			datetime!(2016-12-31 23:59)
+		);
+		assert_eq!(datetime!(-1-01-01 00:00).year().as_i16(), -1);
+	}
+}
@@ -2,12 +2,17 @@ use derive_more::Display;
 
 use thiserror::Error;
 
+use crate::error::{closest_match, DidYouMean};
+
 use self::Month::*;
 
+use core::array::IntoIter;
+use core::ops::{Add, AddAssign, Sub, SubAssign};
 use core::str::FromStr;
 
 /// Months of the year
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Display, PartialOrd, Ord)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[repr(u8)]
 pub enum Month {
 	January = 1,
@@ -55,6 +60,46 @@ impl Month {
 		}
 	}
 
+	/// Iterates over the months of the year, starting with January
+	///
+	/// # Example
+	///
+	/// ```
+	/// use botic::Month;
+	///
+	/// let mut months = Month::iter();
+	/// assert_eq!(Some(Month::January), months.next());
+	/// assert_eq!(Some(Month::December), months.next_back());
+	/// assert_eq!(10, months.len());
+	/// ```
+	#[must_use]
+	pub fn iter() -> IntoIter<Self, 12> {
+		[
+			January, February, March, April, May, June, July, August, September, October, November,
+			December,
+		]
+		.into_iter()
+	}
+
+	/// Iterates over the twelve months of a year which starts in the given
+	/// month, wrapping around after December. This is useful for fiscal or
+	/// academic years.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use botic::Month;
+	///
+	/// let mut months = Month::iter_from(Month::April);
+	/// assert_eq!(Some(Month::April), months.next());
+	/// assert_eq!(Some(Month::March), months.last());
+	/// assert_eq!(Month::iter_from(Month::October).nth(3), Some(Month::January));
+	/// ```
+	#[must_use]
+	pub fn iter_from(start: Self) -> IntoIter<Self, 12> {
+		core::array::from_fn(|i| start.add_overflowing(i as i8).0).into_iter()
+	}
+
 	/// Get the month from the given string,
 	/// which is assumed to be the month's abbreviation.
 	/// Returns `None` if the string is not a valid abbrevation of a month
@@ -399,16 +444,74 @@ impl Month {
 		}
 	}
 
-	pub const fn add_overflowing(self, months: i8) -> (Self, u8) {
-		let zero_indexed_num = ((self as u16) - 1) + months as u16;
-		let wraps = (zero_indexed_num as u8) / 12;
-		let zero_indexed_month = zero_indexed_num % 12;
+	/// Adds a number of months, which may be negative. This returns a tuple
+	/// of the new month and the number of years which were carried over.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use botic::Month;
+	///
+	/// assert_eq!((Month::February, 1), Month::November.add_overflowing(3));
+	/// assert_eq!((Month::November, -1), Month::February.add_overflowing(-3));
+	/// assert_eq!((Month::March, 0), Month::March.add_overflowing(0));
+	/// ```
+	#[must_use]
+	pub const fn add_overflowing(self, months: i8) -> (Self, i8) {
+		let zero_indexed_num = (self as i16 - 1) + months as i16;
+		let wraps = zero_indexed_num.div_euclid(12);
+		let zero_indexed_month = zero_indexed_num.rem_euclid(12);
 		let month = match Self::from_u8((zero_indexed_month as u8) + 1) {
 			Some(month) => month,
 			None => unsafe { core::hint::unreachable_unchecked() },
 		};
 
-		(month, wraps)
+		(month, wraps as i8)
+	}
+
+	/// Gets the number of months from this month until the next time it's
+	/// the other month. This is zero if they're the same month.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use botic::Month;
+	///
+	/// assert_eq!(2, Month::November.months_until(Month::January));
+	/// assert_eq!(0, Month::May.months_until(Month::May));
+	/// ```
+	#[must_use]
+	pub const fn months_until(self, other: Self) -> u8 {
+		(other as u8 + 12 - self as u8) % 12
+	}
+}
+
+impl Add<i8> for Month {
+	type Output = Self;
+
+	fn add(self, rhs: i8) -> Self::Output {
+		self.add_overflowing(rhs).0
+	}
+}
+
+impl Sub<i8> for Month {
+	type Output = Self;
+
+	fn sub(self, rhs: i8) -> Self::Output {
+		// negating i8::MIN would overflow, but the remainder can't
+		self.add_overflowing(-(rhs % 12)).0
+	}
+}
+
+impl AddAssign<i8> for Month {
+	fn add_assign(&mut self, rhs: i8) {
+		*self = *self + rhs;
+	}
+}
+
+impl SubAssign<i8> for Month {
+	fn sub_assign(&mut self, rhs: i8) {
+		*self = *self - rhs;
 	}
 }
 
@@ -418,10 +521,44 @@ impl From<Month> for u8 {
 	}
 }
 
+/// The string wasn't the number, name, or abbreviation of a month
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Error)]
-#[error("Failed to parse the month")]
-// TODO Consider trying to figure out what month the user meant to use
-pub struct ParseMonthError;
+#[error("Failed to parse the month{}", DidYouMean(.suggestion))]
+pub struct ParseMonthError {
+	suggestion: Option<Month>,
+}
+
+impl ParseMonthError {
+	/// Creates an error for the given string, guessing which month was meant
+	fn new(s: &str) -> Self {
+		let names =
+			Month::iter().flat_map(|month| [(month.name(), month), (month.abbreviation(), month)]);
+		Self {
+			suggestion: closest_match(s, names),
+		}
+	}
+
+	/// The month which was most likely meant, if the string was close to the
+	/// name or abbreviation of one. This can be used to show a helpful error
+	/// message.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use botic::Month;
+	///
+	/// let error = "Janury".parse::<Month>().unwrap_err();
+	/// assert_eq!(error.suggestion(), Some(Month::January));
+	/// assert_eq!(error.to_string(), "Failed to parse the month. Did you mean January?");
+	///
+	/// let error = "Hello".parse::<Month>().unwrap_err();
+	/// assert_eq!(error.suggestion(), None);
+	/// ```
+	#[must_use]
+	pub const fn suggestion(&self) -> Option<Month> {
+		self.suggestion
+	}
+}
 
 // TODO optimize to look like this: https://github.com/chronotope/chrono/blob/main/src/format/scan.rs#L102
 // TODO make case-insensitive
@@ -433,14 +570,41 @@ impl FromStr for Month {
 			if let Some(month) = Month::from_u8(num) {
 				Ok(month)
 			} else {
-				Err(ParseMonthError)
+				Err(ParseMonthError { suggestion: None })
 			}
 		} else if let Some(month) = Month::from_abbreviation(s) {
 			Ok(month)
 		} else if let Some(month) = Month::from_name(s) {
 			Ok(month)
 		} else {
-			Err(ParseMonthError)
+			Err(ParseMonthError::new(s))
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn arithmetic() {
+		assert_eq!(January - 1, December);
+		assert_eq!(January + 25, February);
+		assert_eq!(March - i8::MIN, November);
+		assert_eq!(March + i8::MIN, July);
+		assert_eq!(December.add_overflowing(i8::MAX), (July, 11));
+		assert_eq!(January.add_overflowing(i8::MIN), (May, -11));
+
+		let mut month = June;
+		month += 7;
+		assert_eq!(month, January);
+		month -= 13;
+		assert_eq!(month, December);
+
+		for month in Month::iter() {
+			for other in Month::iter() {
+				assert_eq!(month + month.months_until(other) as i8, other);
+			}
 		}
 	}
 }
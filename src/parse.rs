@@ -0,0 +1,775 @@
+//! Parsing dates and times from common text formats.
+//!
+//! [`rfc3339`] and [`rfc2822`] each parse a single format. [`parse_any`] tries
+//! several formats, for input where the format isn't known ahead of time, such
+//! as log files and CSV data.
+
+use core::fmt::Display;
+use core::str::FromStr;
+
+use thiserror::Error;
+
+use crate::{timezone::UtcOffset, Date, DateTime, Month, NaiveDateTime, OutOfRange, Time};
+use crate::{Timestamp, Weekday, Year};
+
+/// A format which [`parse_any`] can detect
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Format {
+	/// An RFC 3339 date and time, such as `2024-03-10T09:30:00-05:00`
+	Rfc3339,
+	/// An RFC 2822 date and time, as used by email, such as
+	/// `Sun, 10 Mar 2024 09:30:00 -0500`
+	Rfc2822,
+	/// A number of seconds since the Unix epoch, such as `1710081000`
+	UnixSeconds,
+	/// A number of milliseconds since the Unix epoch, such as
+	/// `1710081000000`
+	UnixMillis,
+	/// A date with the month first, as in the United States, such as
+	/// `03/10/2024`
+	MonthDayYear,
+	/// A date with the day first, as in much of Europe, such as `10.03.2024`
+	DayMonthYear,
+}
+
+/// The string wasn't a date and time in the expected format
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Error)]
+#[error("The string is not a date and time in a recognized format")]
+pub struct ParseError;
+
+/// A date and time which was parsed by [`parse_any`]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Parsed {
+	local: NaiveDateTime,
+	offset: Option<UtcOffset>,
+	format: Format,
+}
+
+impl Parsed {
+	/// Gets the date and time as it was written. If the format doesn't
+	/// include a time, then this is midnight.
+	#[must_use]
+	pub const fn naive_local(&self) -> NaiveDateTime {
+		self.local
+	}
+
+	/// Gets the offset from UTC, or `None` if the format doesn't include one.
+	/// Unix timestamps are always in UTC.
+	#[must_use]
+	pub const fn offset(&self) -> Option<UtcOffset> {
+		self.offset
+	}
+
+	/// Gets the format which was detected
+	#[must_use]
+	pub const fn format(&self) -> Format {
+		self.format
+	}
+
+	/// Gets the date and time at its offset from UTC, using `default` if the
+	/// string didn't include an offset
+	///
+	/// # Errors
+	///
+	/// Returns an error if the date and time in UTC is out of range
+	pub const fn with_default_offset(
+		&self,
+		default: UtcOffset,
+	) -> Result<DateTime<UtcOffset>, OutOfRange> {
+		let offset = match self.offset {
+			Some(offset) => offset,
+			None => default,
+		};
+
+		self.local.assume_offset(offset)
+	}
+}
+
+/// An RFC 3339 or RFC 2822 date and time which remembers the offset that was
+/// written, so that it can be written back the same way.
+///
+/// Some protocols, such as email and iCalendar, expect a reply to use the
+/// sender's offset. Unlike a [`DateTime`], two `OffsetDateTime`s are only
+/// equal if they were written with the same offset. An offset of `-00:00` in
+/// RFC 3339, or `-0000` in RFC 2822, means that the offset is unknown, and is
+/// kept as well. In RFC 3339, whether UTC was written as `Z` or `+00:00`,
+/// and whether the date and time were separated by a `T` or a space, are also
+/// kept.
+///
+/// # Example
+///
+/// ```
+/// use botic::parse::OffsetDateTime;
+/// use botic::timezone::UtcOffset;
+///
+/// let date_time = OffsetDateTime::parse_rfc2822("10 Mar 2024 09:30 EST").unwrap();
+/// assert_eq!(date_time.original_offset(), UtcOffset::from_hours(-5).unwrap());
+/// assert_eq!(date_time.to_string(), "Sun, 10 Mar 2024 09:30:00 -0500");
+///
+/// let date_time = OffsetDateTime::parse_rfc3339("2024-03-10T14:30:00.25-00:00").unwrap();
+/// assert!(date_time.is_offset_unknown());
+/// assert_eq!(date_time.to_string(), "2024-03-10T14:30:00.25-00:00");
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct OffsetDateTime {
+	local: NaiveDateTime,
+	offset: UtcOffset,
+	unknown_offset: bool,
+	format: Format,
+	/// The character between the date and the time in RFC 3339
+	separator: char,
+	/// The letter which UTC was written as in RFC 3339, if any
+	utc_letter: Option<char>,
+}
+
+impl OffsetDateTime {
+	/// Parses an RFC 3339 date and time. See [`rfc3339`].
+	///
+	/// # Errors
+	///
+	/// Returns an error if the string isn't an RFC 3339 date and time, or if
+	/// the date and time is out of range
+	pub fn parse_rfc3339(s: &str) -> Result<Self, ParseError> {
+		let (local, offset, unknown_offset) = parse_rfc3339(s).ok_or(ParseError)?;
+		let mut date_time = Self::new(local, offset, unknown_offset, Format::Rfc3339)?;
+
+		// the string was checked by parse_rfc3339, so these are ASCII
+		date_time.separator = char::from(s.as_bytes()[10]);
+		date_time.utc_letter = s
+			.ends_with(['Z', 'z'])
+			.then(|| char::from(s.as_bytes()[s.len() - 1]));
+		Ok(date_time)
+	}
+
+	/// Parses an RFC 2822 date and time. See [`rfc2822`]. An offset which
+	/// was written as a zone name, such as `EST`, is written back as a
+	/// number, since RFC 2822 says that the names shouldn't be generated.
+	///
+	/// # Errors
+	///
+	/// Returns an error if the string isn't an RFC 2822 date and time, or if
+	/// the date and time is out of range
+	pub fn parse_rfc2822(s: &str) -> Result<Self, ParseError> {
+		let (local, offset, unknown_offset) = parse_rfc2822(s).ok_or(ParseError)?;
+		Self::new(local, offset, unknown_offset, Format::Rfc2822)
+	}
+
+	/// Checks that the date and time is in range in UTC
+	fn new(
+		local: NaiveDateTime,
+		offset: UtcOffset,
+		unknown_offset: bool,
+		format: Format,
+	) -> Result<Self, ParseError> {
+		local.assume_offset(offset).map_err(|_| ParseError)?;
+		Ok(Self {
+			local,
+			offset,
+			unknown_offset,
+			format,
+			separator: ' ',
+			utc_letter: None,
+		})
+	}
+
+	/// Gets the date and time at the offset it was written with
+	#[must_use]
+	pub const fn date_time(&self) -> DateTime<UtcOffset> {
+		// the date and time was checked to be in range when it was parsed
+		DateTime::from_naive_local(self.local, self.offset).0
+	}
+
+	/// Gets the date and time as it was written
+	#[must_use]
+	pub const fn naive_local(&self) -> NaiveDateTime {
+		self.local
+	}
+
+	/// Gets the offset from UTC which was written. If the offset is unknown,
+	/// then this is UTC.
+	#[must_use]
+	pub const fn original_offset(&self) -> UtcOffset {
+		self.offset
+	}
+
+	/// Checks if the offset was written as unknown. This also includes the
+	/// military zone letters in RFC 2822.
+	#[must_use]
+	pub const fn is_offset_unknown(&self) -> bool {
+		self.unknown_offset
+	}
+
+	/// Gets the format that the date and time was written in, which is
+	/// either [`Format::Rfc3339`] or [`Format::Rfc2822`]
+	#[must_use]
+	pub const fn format(&self) -> Format {
+		self.format
+	}
+}
+
+impl From<OffsetDateTime> for DateTime<UtcOffset> {
+	fn from(date_time: OffsetDateTime) -> Self {
+		date_time.date_time()
+	}
+}
+
+impl Display for OffsetDateTime {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		let (date, time) = (self.local.date(), self.local.time());
+		let seconds = self.offset.seconds_ahead();
+		let sign = if seconds < 0 || self.unknown_offset {
+			'-'
+		} else {
+			'+'
+		};
+		let (hours, minutes) = (
+			seconds.unsigned_abs() / 3600,
+			seconds.unsigned_abs() / 60 % 60,
+		);
+
+		if self.format == Format::Rfc2822 {
+			return write!(
+				f,
+				"{}, {} {} {} {:02}:{:02}:{:02} {sign}{hours:02}{minutes:02}",
+				date.weekday().abbreviation(),
+				date.day(),
+				date.month().abbreviation(),
+				date.year(),
+				time.hour(),
+				time.minute(),
+				time.second(),
+			);
+		}
+
+		write!(f, "{date}{}{time}", self.separator)?;
+		match self.utc_letter {
+			Some(letter) => write!(f, "{letter}"),
+			None => write!(f, "{sign}{hours:02}:{minutes:02}"),
+		}
+	}
+}
+
+/// Parses an RFC 3339 date and time, such as `2024-03-10T09:30:00.5-05:00`.
+/// A lowercase `t` or a space may also separate the date and time.
+///
+/// # Example
+///
+/// ```
+/// use botic::parse;
+///
+/// let date_time = parse::rfc3339("2024-03-10T09:30:00Z").unwrap();
+/// assert_eq!(date_time.unix_timestamp().total_seconds(), 1_710_063_000);
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if the string isn't an RFC 3339 date and time, or if the
+/// date and time is out of range
+pub fn rfc3339(s: &str) -> Result<DateTime<UtcOffset>, ParseError> {
+	let (local, offset, _) = parse_rfc3339(s).ok_or(ParseError)?;
+	local.assume_offset(offset).map_err(|_| ParseError)
+}
+
+/// Parses an RFC 2822 date and time, such as
+/// `Sun, 10 Mar 2024 09:30:00 -0500`. The day of the week is optional, and
+/// isn't checked against the date.
+///
+/// Since old emails often don't follow the standard exactly, this also accepts
+/// the obsolete syntax which RFC 2822 allows readers to parse. Two-digit years
+/// from `50` to `99` are in the 1900s, and the rest are in the 2000s. Zones may
+/// be named, such as `GMT` or `EST`, and military zone letters are treated as
+/// UTC. With the `abbreviations` feature, other abbreviations such as `CET`
+/// are also accepted, using `UtcOffset::from_abbreviation`. Names are
+/// case-insensitive, and there may be comments in parentheses or extra
+/// whitespace between any of the parts.
+///
+/// # Example
+///
+/// ```
+/// use botic::parse;
+///
+/// let date_time = parse::rfc2822("10 Mar 2024 09:30 -0500").unwrap();
+/// assert_eq!(date_time.unix_timestamp().total_seconds(), 1_710_081_000);
+///
+/// let old = parse::rfc2822("Sun,10 Mar 24 09 : 30 EST (Eastern)").unwrap();
+/// assert_eq!(old, date_time);
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if the string isn't an RFC 2822 date and time, or if the
+/// date and time is out of range
+pub fn rfc2822(s: &str) -> Result<DateTime<UtcOffset>, ParseError> {
+	let (local, offset, _) = parse_rfc2822(s).ok_or(ParseError)?;
+	local.assume_offset(offset).map_err(|_| ParseError)
+}
+
+/// Parses a date and time in any of the formats in [`Format`], and reports
+/// which one matched.
+///
+/// The formats are tried in the order that they're listed in. A number is a
+/// Unix timestamp, in milliseconds if it has at least 12 digits, and in
+/// seconds otherwise. Twelve digits of seconds would be after the year 5000.
+/// Since dates like `03/04/2024` are ambiguous, a slash always means the month
+/// is first, and a dot always means the day is first.
+///
+/// # Example
+///
+/// ```
+/// use botic::parse::{self, Format};
+///
+/// let parsed = parse::parse_any("1710081000000").unwrap();
+/// assert_eq!(parsed.format(), Format::UnixMillis);
+///
+/// let parsed = parse::parse_any("10.03.2024").unwrap();
+/// assert_eq!(parsed.format(), Format::DayMonthYear);
+/// assert_eq!(parsed.offset(), None);
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if none of the formats match, or if the date and time is
+/// out of range
+pub fn parse_any(s: &str) -> Result<Parsed, ParseError> {
+	let s = s.trim();
+	let parsed = |local, offset, format| Parsed {
+		local,
+		offset,
+		format,
+	};
+
+	if let Some((local, offset, _)) = parse_rfc3339(s) {
+		return Ok(parsed(local, Some(offset), Format::Rfc3339));
+	}
+
+	if let Some((local, offset, _)) = parse_rfc2822(s) {
+		return Ok(parsed(local, Some(offset), Format::Rfc2822));
+	}
+
+	let digits = s.strip_prefix('-').unwrap_or(s);
+	if !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit()) {
+		let number = i64::from_str(s).map_err(|_| ParseError)?;
+		let (timestamp, format) = if digits.len() >= 12 {
+			(Timestamp::from_millis(number), Format::UnixMillis)
+		} else {
+			(Timestamp::new(number, 0), Format::UnixSeconds)
+		};
+
+		let utc = NaiveDateTime::try_from(timestamp).map_err(|_| ParseError)?;
+		return Ok(parsed(utc, Some(UtcOffset::UTC), format));
+	}
+
+	if let Some(date) = parse_numeric_date(s, '/', [1, 0, 2]) {
+		return Ok(parsed(date.at_midnight(), None, Format::MonthDayYear));
+	}
+
+	if let Some(date) = parse_numeric_date(s, '.', [0, 1, 2]) {
+		return Ok(parsed(date.at_midnight(), None, Format::DayMonthYear));
+	}
+
+	Err(ParseError)
+}
+
+/// Parses a number made of only ASCII digits
+pub(crate) fn parse_number<T: FromStr>(s: &str) -> Option<T> {
+	if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+		return None;
+	}
+
+	s.parse().ok()
+}
+
+/// Parses a date in the form `YYYY-MM-DD`. The year may have a sign, and may
+/// have more or less than four digits.
+pub(crate) fn parse_date(s: &str) -> Option<Date> {
+	let (negative, s) = match s.strip_prefix('-') {
+		Some(s) => (true, s),
+		None => (false, s.strip_prefix('+').unwrap_or(s)),
+	};
+
+	let mut parts = s.split('-');
+	let year: i16 = parse_number(parts.next()?)?;
+	let month = Month::from_u8(parse_number(parts.next()?)?)?;
+	let day = parse_number(parts.next()?)?;
+	if parts.next().is_some() {
+		return None;
+	}
+
+	let year = if negative { -year } else { year };
+	Date::from_ymd(Year::from(year), month, day).ok()
+}
+
+/// Parses a time, returning the rest of the string
+pub(crate) fn parse_time(s: &str) -> Option<(Time, &str)> {
+	let hms = s.get(..8)?;
+	let mut parts = hms.split(':');
+	let hour = parse_number(parts.next()?)?;
+	let minute = parse_number(parts.next()?)?;
+	let second: u8 = parse_number(parts.next()?)?;
+
+	// the offset can move a leap second from 23:59:60 to the end of any minute
+	if second > 60 {
+		return None;
+	}
+	Time::from_hms(hour, minute, second.min(59)).ok()?;
+	let time = Time::from_hms_unchecked(hour, minute, second);
+
+	let rest = &s[8..];
+	let Some(fraction) = rest.strip_prefix('.') else {
+		return Some((time, rest));
+	};
+
+	let digits = fraction.bytes().take_while(u8::is_ascii_digit).count();
+	if digits == 0 || digits > 9 {
+		return None;
+	}
+
+	let nanosecond: u32 = parse_number(&fraction[..digits])?;
+	let nanosecond = nanosecond * 10_u32.pow(9 - digits as u32);
+	let time = Time::from_hms_nano_unchecked(hour, minute, second, nanosecond);
+	Some((time, &fraction[digits..]))
+}
+
+/// Parses an RFC 3339 date and time into its local time and offset, and
+/// whether the offset was written as unknown, with `-00:00`
+fn parse_rfc3339(s: &str) -> Option<(NaiveDateTime, UtcOffset, bool)> {
+	// RFC 3339 always has a four digit year
+	let date = s.get(..10)?;
+	if !date.as_bytes()[0].is_ascii_digit()
+		|| !matches!(s.as_bytes()[10..].first(), Some(b'T' | b't' | b' '))
+	{
+		return None;
+	}
+
+	let date = parse_date(date)?;
+	let (time, offset) = parse_time(&s[11..])?;
+	let offset = match offset.as_bytes() {
+		[b'Z' | b'z'] => UtcOffset::UTC,
+		[b'+' | b'-', _, _, b':', _, _] => UtcOffset::parse(offset).ok()?,
+		_ => return None,
+	};
+
+	Some((date.at(time), offset, s.ends_with("-00:00")))
+}
+
+/// The zone names which RFC 2822 allows in old messages, with their offsets
+/// in hours
+const OBSOLETE_ZONES: [(&str, i32); 10] = [
+	("UT", 0),
+	("GMT", 0),
+	("EST", -5),
+	("EDT", -4),
+	("CST", -6),
+	("CDT", -5),
+	("MST", -7),
+	("MDT", -6),
+	("PST", -8),
+	("PDT", -7),
+];
+
+/// Splits an RFC 2822 date and time into words, commas, and colons, skipping
+/// whitespace and comments
+struct Tokens<'a> {
+	rest: &'a str,
+}
+
+impl Tokens<'_> {
+	/// Skips whitespace and comments. Comments may be nested. If a comment
+	/// isn't closed, then the rest of the string starts with its `(`.
+	fn skip_whitespace(&mut self) {
+		loop {
+			self.rest = self.rest.trim_start();
+			if !self.rest.starts_with('(') {
+				return;
+			}
+
+			let mut depth = 0_usize;
+			let mut escaped = false;
+			let mut end = None;
+			for (i, c) in self.rest.char_indices() {
+				match c {
+					_ if escaped => escaped = false,
+					'\\' => escaped = true,
+					'(' => depth += 1,
+					')' => {
+						depth -= 1;
+						if depth == 0 {
+							end = Some(i + 1);
+							break;
+						}
+					}
+					_ => {}
+				}
+			}
+
+			let Some(end) = end else {
+				return;
+			};
+			self.rest = &self.rest[end..];
+		}
+	}
+}
+
+impl<'a> Iterator for Tokens<'a> {
+	type Item = &'a str;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		self.skip_whitespace();
+		if self.rest.is_empty() {
+			return None;
+		}
+
+		let len = match self
+			.rest
+			.find(|c: char| c.is_whitespace() || matches!(c, '(' | ',' | ':'))
+		{
+			Some(0) => 1,
+			Some(len) => len,
+			None => self.rest.len(),
+		};
+		let (token, rest) = self.rest.split_at(len);
+		self.rest = rest;
+		Some(token)
+	}
+}
+
+/// Parses an RFC 2822 date and time into its local time and offset, and
+/// whether the offset is unknown
+fn parse_rfc2822(s: &str) -> Option<(NaiveDateTime, UtcOffset, bool)> {
+	let mut tokens = Tokens { rest: s }.peekable();
+	if tokens
+		.peek()?
+		.starts_with(|c: char| c.is_ascii_alphabetic())
+	{
+		let day_name = tokens.next()?;
+		if !Weekday::iter().any(|weekday| weekday.abbreviation().eq_ignore_ascii_case(day_name))
+			|| tokens.next()? != ","
+		{
+			return None;
+		}
+	}
+
+	let day = tokens.next().filter(|day| day.len() <= 2)?;
+	let month = tokens.next()?;
+	let month = (1..=12)
+		.filter_map(Month::from_u8)
+		.find(|m| m.abbreviation().eq_ignore_ascii_case(month))?;
+	let year = tokens.next()?;
+	let year = match (year.len(), parse_number::<i16>(year)?) {
+		(2, year @ 0..=49) => year + 2000,
+		(2 | 3, year) => year + 1900,
+		(4.., year) => year,
+		_ => return None,
+	};
+	let date = Date::from_ymd(Year::from_i16(year), month, parse_number(day)?).ok()?;
+
+	let two_digits = |n: Option<&str>| n.filter(|n| n.len() == 2).and_then(parse_number);
+	let hour = two_digits(tokens.next())?;
+	if tokens.next()? != ":" {
+		return None;
+	}
+	let minute = two_digits(tokens.next())?;
+	let second = match tokens.next_if_eq(&":") {
+		Some(_) => two_digits(tokens.next())?,
+		None => 0,
+	};
+	let time = Time::from_hms(hour, minute, second).ok()?;
+
+	let (offset, unknown) = parse_zone(tokens.next()?)?;
+	if tokens.next().is_some() {
+		return None;
+	}
+
+	Some((date.at(time), offset, unknown))
+}
+
+/// Parses an RFC 2822 time zone, which is either an offset like `-0500`, or
+/// one of the obsolete zone names. This also returns whether the offset is
+/// unknown.
+fn parse_zone(zone: &str) -> Option<(UtcOffset, bool)> {
+	if matches!(zone.as_bytes(), [b'+' | b'-', _, _, _, _]) {
+		return Some((UtcOffset::parse(zone).ok()?, zone == "-0000"));
+	}
+
+	if let Some((_, hours)) = OBSOLETE_ZONES
+		.iter()
+		.find(|(name, _)| name.eq_ignore_ascii_case(zone))
+	{
+		return Some((UtcOffset::from_hours_unchecked(*hours), false));
+	}
+
+	#[cfg(feature = "abbreviations")]
+	if let Some(offset) = UtcOffset::from_abbreviation(zone) {
+		return Some((offset, false));
+	}
+
+	// RFC 822 got the military zones backwards, so RFC 2822 says to treat them
+	// all as an unknown offset
+	let mut chars = zone.chars();
+	match (chars.next(), chars.next()) {
+		(Some(letter), None) => {
+			UtcOffset::from_military_letter(letter).map(|_| (UtcOffset::UTC, true))
+		}
+		_ => None,
+	}
+}
+
+/// Parses a date made of three numbers with the given separator. The order
+/// gives the position of the day, month, and year.
+fn parse_numeric_date(s: &str, separator: char, order: [usize; 3]) -> Option<Date> {
+	let mut parts = [""; 3];
+	let mut split = s.split(separator);
+	for part in &mut parts {
+		*part = split.next()?;
+	}
+	if split.next().is_some() {
+		return None;
+	}
+
+	let [day, month, year] = order.map(|i| parts[i]);
+	if day.len() > 2 || month.len() > 2 || year.len() != 4 {
+		return None;
+	}
+
+	let month = Month::from_u8(parse_number(month)?)?;
+	Date::from_ymd(
+		Year::from_i16(parse_number(year)?),
+		month,
+		parse_number(day)?,
+	)
+	.ok()
+}
+
+#[cfg(test)]
+mod tests {
+	#[cfg(feature = "alloc")]
+	use alloc::string::ToString;
+
+	use super::*;
+
+	fn timestamp(s: &str) -> Option<i64> {
+		let parsed = parse_any(s).ok()?;
+		let date_time = parsed.with_default_offset(UtcOffset::UTC).ok()?;
+		Some(date_time.unix_timestamp().total_seconds())
+	}
+
+	#[test]
+	fn detected_formats() {
+		let format = |s| parse_any(s).map(|parsed| parsed.format());
+		assert_eq!(format("2024-03-10T09:30:00-05:00"), Ok(Format::Rfc3339));
+		assert_eq!(
+			format("Sun, 10 Mar 2024 09:30:00 -0500"),
+			Ok(Format::Rfc2822)
+		);
+		assert_eq!(format("1710081000"), Ok(Format::UnixSeconds));
+		assert_eq!(format("1710081000000"), Ok(Format::UnixMillis));
+		assert_eq!(format("03/10/2024"), Ok(Format::MonthDayYear));
+		assert_eq!(format("10.03.2024"), Ok(Format::DayMonthYear));
+		assert_eq!(format(" 2024-03-10 09:30:00.25Z\n"), Ok(Format::Rfc3339));
+	}
+
+	#[test]
+	fn same_instant() {
+		let instant = Some(1_710_081_000);
+		assert_eq!(timestamp("2024-03-10T09:30:00-05:00"), instant);
+		assert_eq!(timestamp("2024-03-10t14:30:00z"), instant);
+		assert_eq!(timestamp("Sun, 10 Mar 2024 09:30:00 -0500"), instant);
+		assert_eq!(timestamp("10 Mar 2024 14:30 +0000"), instant);
+		assert_eq!(timestamp("1710081000"), instant);
+		assert_eq!(timestamp("1710081000000"), instant);
+		assert_eq!(timestamp("-1"), Some(-1));
+		assert_eq!(timestamp("3/10/2024"), Some(1_710_028_800));
+	}
+
+	#[test]
+	fn obsolete_rfc2822() {
+		let instant = Some(1_710_081_000);
+		assert_eq!(timestamp("sun, 10 MAR 2024 09:30:00 est"), instant);
+		assert_eq!(timestamp("Sun , 10 Mar 24 14:30 GMT"), instant);
+		assert_eq!(timestamp("10 Mar 124 14:30 UT"), instant);
+		assert_eq!(timestamp("10 Mar 2024 14:30 Z"), instant);
+		assert_eq!(timestamp("10 Mar 2024 14:30 A"), instant);
+		assert_eq!(
+			timestamp("Sun, (day (of the) week) 10\r\n Mar 2024 10:30:00 (\\)) EDT"),
+			instant
+		);
+		assert_eq!(timestamp("1 Jan 50 00:00 GMT"), Some(-631_152_000));
+		assert_eq!(timestamp("1 Jan 49 00:00 GMT"), Some(2_493_072_000));
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn original_offsets() {
+		for s in [
+			"2024-03-10T09:30:00-05:00",
+			"2024-03-10T14:30:00Z",
+			"2024-03-10T14:30:00+00:00",
+			"2024-03-10 14:30:00z",
+			"2024-03-10t14:30:00.5+00:00",
+			"2024-03-10T20:00:00.123456789+05:30",
+			"2016-12-31T23:59:60-00:00",
+			"Sun, 10 Mar 2024 09:30:00 -0500",
+			"Mon, 1 Jan 2024 00:00:00 -0000",
+		] {
+			let date_time = OffsetDateTime::parse_rfc3339(s)
+				.or_else(|_| OffsetDateTime::parse_rfc2822(s))
+				.unwrap();
+			assert_eq!(date_time.to_string(), s);
+		}
+
+		let utc = OffsetDateTime::parse_rfc3339("2024-03-10T14:30:00Z").unwrap();
+		let eastern = OffsetDateTime::parse_rfc3339("2024-03-10T09:30:00-05:00").unwrap();
+		assert_ne!(utc, eastern);
+		assert_ne!(
+			utc,
+			OffsetDateTime::parse_rfc3339("2024-03-10T14:30:00+00:00").unwrap()
+		);
+		assert_eq!(utc.date_time(), eastern.date_time());
+		assert_eq!(eastern.original_offset().seconds_ahead(), -5 * 3600);
+
+		let military = OffsetDateTime::parse_rfc2822("10 Mar 2024 14:30 A").unwrap();
+		assert!(military.is_offset_unknown());
+		assert_eq!(military.to_string(), "Sun, 10 Mar 2024 14:30:00 -0000");
+		assert!(OffsetDateTime::parse_rfc3339("10 Mar 2024 14:30 Z").is_err());
+	}
+
+	#[cfg(feature = "abbreviations")]
+	#[test]
+	fn abbreviated_zones() {
+		let instant = Some(1_710_081_000);
+		assert_eq!(timestamp("Sun, 10 Mar 2024 15:30:00 CET"), instant);
+		assert_eq!(timestamp("Sun, 10 Mar 2024 23:30:00 jst"), instant);
+		assert_eq!(timestamp("Sun, 10 Mar 2024 20:00:00 IST"), instant);
+	}
+
+	#[test]
+	fn invalid() {
+		for s in [
+			"",
+			"-",
+			"2024-03-10",
+			"2024-03-10T09:30:00",
+			"2024-03-10T09:30:00+0500",
+			"2024-03-10T09:30:61Z",
+			"2024-03-10T09:30:99Z",
+			"Sunday, 10 Mar 2024 09:30:00 -0500",
+			"10 Mar 2024 09:30:00 -05:00",
+			"10 Mar 2024 9:30:00 -0500",
+			"10 Mar 2024 09:30:00 J",
+			"10 Mar 2024 09:30:00 XYZ",
+			"10 Mar 2024 09:30:00 -0500 (unclosed",
+			"10 Mar 2 09:30:00 -0500",
+			"Sun 10 Mar 2024 09:30:00 -0500",
+			"13/10/2024",
+			"10.03.24",
+			"31.02.2024",
+			"99999999999999999999",
+		] {
+			assert_eq!(parse_any(s), Err(ParseError), "{s}");
+		}
+
+		assert_eq!(rfc3339("2024-03-10T09:30:61Z"), Err(ParseError));
+		assert_eq!(rfc3339("2024-03-10T09:30:99Z"), Err(ParseError));
+		assert!(rfc3339("2016-12-31T23:59:60Z").is_ok());
+	}
+}
@@ -0,0 +1,180 @@
+//! Serde support for [`DateTime`].
+//!
+//! A `DateTime` is serialized as its local date and time, followed by its
+//! offset from UTC and the name of its time zone in brackets, as described by
+//! RFC 9557. For example, `2024-03-10T09:30:00-05:00[UTC-5]`. Deserializing
+//! parses the time zone back from its name, so the time zone is preserved.
+
+use core::fmt::{Display, Formatter};
+use core::marker::PhantomData;
+use core::str::FromStr;
+
+use ::serde::de::{self, Visitor};
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::parse::{parse_date, parse_time};
+use crate::{timezone::UtcOffset, DateTime, NaiveDateTime, TimeZone};
+
+/// Displays an offset in the RFC 3339 form, such as `-05:00`
+struct Rfc3339Offset(UtcOffset);
+
+impl Display for Rfc3339Offset {
+	fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+		let seconds = self.0.seconds_ahead();
+		let sign = if seconds.is_negative() { '-' } else { '+' };
+		let seconds = seconds.unsigned_abs();
+		write!(
+			f,
+			"{}{:02}:{:02}",
+			sign,
+			seconds / 3600,
+			(seconds % 3600) / 60
+		)?;
+
+		if !seconds.is_multiple_of(60) {
+			write!(f, ":{:02}", seconds % 60)?;
+		}
+
+		Ok(())
+	}
+}
+
+impl<Tz: TimeZone> Serialize for DateTime<Tz> {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		let local = self.to_naive_local().map_err(::serde::ser::Error::custom)?;
+		serializer.collect_str(&format_args!(
+			"{}T{}{}[{}]",
+			local.date(),
+			local.time(),
+			Rfc3339Offset(self.offset()),
+			self.timezone()
+		))
+	}
+}
+
+/// Parses an RFC 9557 date and time into its local time, offset, and zone name.
+/// If there's no zone name, the offset is used as the name.
+fn parse_rfc9557(s: &str) -> Option<(NaiveDateTime, UtcOffset, &str)> {
+	let (s, zone) = match s.strip_suffix(']') {
+		Some(s) => {
+			let (s, zone) = s.rsplit_once('[')?;
+			(s, Some(zone))
+		}
+		None => (s, None),
+	};
+
+	let separator = s.find(['T', 't', ' '])?;
+	let date = parse_date(&s[..separator])?;
+	let (time, offset_str) = parse_time(&s[separator + 1..])?;
+	let offset = UtcOffset::parse(offset_str).ok()?;
+
+	Some((
+		NaiveDateTime::new(date, time),
+		offset,
+		zone.unwrap_or(offset_str),
+	))
+}
+
+struct DateTimeVisitor<Tz>(PhantomData<Tz>);
+
+impl<'de, Tz: TimeZone + FromStr> Visitor<'de> for DateTimeVisitor<Tz> {
+	type Value = DateTime<Tz>;
+
+	fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+		formatter.write_str("an RFC 9557 date and time, such as 2024-03-10T09:30:00-05:00[UTC-5]")
+	}
+
+	fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+		let (local, offset, zone) =
+			parse_rfc9557(v).ok_or_else(|| E::invalid_value(de::Unexpected::Str(v), &self))?;
+		let timezone = Tz::from_str(zone)
+			.map_err(|_| E::custom(format_args!("unrecognized time zone: {zone}")))?;
+
+		let (utc, overflow) = local.add_offset_overflowing(-offset.seconds_ahead());
+		if overflow {
+			return Err(E::custom("the date and time is out of range"));
+		}
+
+		Ok(DateTime::from_utc(utc, timezone))
+	}
+}
+
+impl<'de, Tz: TimeZone + FromStr> Deserialize<'de> for DateTime<Tz> {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		deserializer.deserialize_str(DateTimeVisitor(PhantomData))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use alloc::string::ToString;
+
+	use super::*;
+	use crate::{Date, Month};
+
+	#[test]
+	fn rfc3339_offsets() {
+		let offset =
+			|seconds| Rfc3339Offset(UtcOffset::from_seconds_unchecked(seconds)).to_string();
+		assert_eq!(offset(0), "+00:00");
+		assert_eq!(offset(-18_000), "-05:00");
+		assert_eq!(offset(19_800), "+05:30");
+		assert_eq!(offset(-32), "-00:00:32");
+	}
+
+	#[test]
+	fn parse_with_zone_name() {
+		let (local, offset, zone) = parse_rfc9557("2024-03-10T09:30:00.25-05:00[UTC-5]").unwrap();
+
+		assert_eq!(
+			local.date(),
+			Date::from_ymd(2024.into(), Month::March, 10).unwrap()
+		);
+		assert_eq!(local.time().hour(), 9);
+		assert_eq!(local.time().minute(), 30);
+		assert_eq!(local.time().nanosecond(), 250_000_000);
+		assert_eq!(offset, UtcOffset::from_hours_unchecked(-5));
+		assert_eq!(zone, "UTC-5");
+	}
+
+	#[test]
+	fn parse_without_zone_name() {
+		let (_, offset, zone) = parse_rfc9557("-0044-03-15T12:00:00Z").unwrap();
+		assert_eq!(offset, UtcOffset::UTC);
+		assert_eq!(zone, "Z");
+	}
+
+	#[test]
+	fn parse_invalid() {
+		assert!(parse_rfc9557("2024-02-30T00:00:00Z").is_none());
+		assert!(parse_rfc9557("2024-01-01T00:00:00.Z").is_none());
+		assert!(parse_rfc9557("2024-01-01T00:00:00+05:00[UTC").is_none());
+	}
+
+	#[test]
+	fn round_trips() {
+		use crate::datetime;
+		use serde_test::{assert_tokens, Token};
+
+		let offset = datetime!(2024-03-10 09:30:00.25 -05:00);
+		assert_tokens(
+			&offset,
+			&[Token::Str("2024-03-10T09:30:00.25-05:00[UTC-5]")],
+		);
+
+		let leap_second = datetime!(2016-12-31 23:59:60.5 UTC);
+		assert_tokens(
+			&leap_second,
+			&[Token::Str("2016-12-31T23:59:60.5+00:00[UTC]")],
+		);
+
+		let half_hour = DateTime::from_utc(
+			leap_second.naive_utc(),
+			UtcOffset::from_seconds_unchecked(19_800),
+		);
+		assert_tokens(
+			&half_hour,
+			&[Token::Str("2017-01-01T05:29:60.5+05:30[UTC+05:30]")],
+		);
+	}
+}
@@ -0,0 +1,160 @@
+//! Sidereal time, which measures the Earth's rotation relative to the stars
+//! instead of the Sun.
+//!
+//! The local sidereal time is the right ascension which is crossing the
+//! meridian, so it's needed to point a telescope at a star. Sidereal time
+//! follows UT1, which is the time given by the Earth's actual rotation. UTC is
+//! kept within 0.9 seconds of UT1 by leap seconds, so UTC can be used directly
+//! when a second of error is acceptable. Otherwise, the difference, called
+//! DUT1, is published by the IERS in Bulletin A.
+
+use crate::{timezone::Utc, DateTime};
+
+/// The Unix timestamp of the J2000 epoch, 2000-01-01 12:00:00 UT
+const J2000_UNIX_SECONDS: i64 = 946_728_000;
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// The number of arcseconds in an hour of right ascension
+const ARCSECONDS_PER_HOUR: f64 = 54_000.0;
+
+/// A mean sidereal time, which is an angle from zero up to 24 hours
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct SiderealTime {
+	hours: f64,
+}
+
+impl SiderealTime {
+	/// Calculates the Greenwich mean sidereal time at the given time, using
+	/// UTC as an approximation of UT1. This may be off by up to 0.9 seconds.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use botic::{datetime, sidereal::SiderealTime};
+	///
+	/// let gmst = SiderealTime::greenwich_mean(datetime!(2000-01-01 12:00 UTC));
+	/// assert!((gmst.hours() - 18.697_374_6).abs() < 1e-6);
+	/// ```
+	#[must_use]
+	pub fn greenwich_mean(utc: DateTime<Utc>) -> Self {
+		Self::greenwich_mean_ut1(utc, 0.0)
+	}
+
+	/// Calculates the Greenwich mean sidereal time at the given time, given
+	/// DUT1, the number of seconds which UT1 is ahead of UTC.
+	///
+	/// This uses the IAU 2006 expression, which is based on the Earth
+	/// rotation angle.
+	#[must_use]
+	pub fn greenwich_mean_ut1(utc: DateTime<Utc>, dut1: f64) -> Self {
+		let timestamp = utc.unix_timestamp();
+		let seconds = timestamp.total_seconds() - J2000_UNIX_SECONDS;
+
+		// the whole days are kept separate from the fraction, since whole
+		// turns of the Earth don't affect the angle, and would use up the
+		// precision of the fraction
+		let days = seconds.div_euclid(SECONDS_PER_DAY) as f64;
+		let seconds_of_day = seconds.rem_euclid(SECONDS_PER_DAY) as f64
+			+ f64::from(timestamp.nanosecond()) * 1e-9
+			+ dut1;
+		let fraction = seconds_of_day / SECONDS_PER_DAY as f64;
+
+		let rotation = 0.779_057_273_264 + fraction + 0.002_737_811_911_354_48 * (days + fraction);
+
+		// the precession of the equinox since J2000, in arcseconds. This
+		// should be in TT, but the difference is far below a microsecond.
+		let t = (days + fraction) / 36_525.0;
+		let precession = 0.014_506
+			+ t * (4_612.156_534
+				+ t * (1.391_581_7
+					+ t * (-0.000_000_44 + t * (-0.000_029_956 + t * -0.000_000_036_8))));
+
+		Self::from_hours(rotation * 24.0 + precession / ARCSECONDS_PER_HOUR)
+	}
+
+	/// Gets the local mean sidereal time at a longitude, given in degrees
+	/// east of Greenwich
+	///
+	/// # Example
+	///
+	/// ```
+	/// use botic::{datetime, sidereal::SiderealTime};
+	///
+	/// let gmst = SiderealTime::greenwich_mean(datetime!(2000-01-01 12:00 UTC));
+	/// let new_york = gmst.at_longitude(-74.0);
+	/// assert!((new_york.hours() - 13.764_041_2).abs() < 1e-6);
+	/// ```
+	#[must_use]
+	pub fn at_longitude(self, longitude: f64) -> Self {
+		Self::from_hours(self.hours + longitude / 15.0)
+	}
+
+	/// Wraps the number of hours into the range `0.0..24.0`
+	fn from_hours(hours: f64) -> Self {
+		let hours = hours % 24.0;
+		let hours = if hours < 0.0 { hours + 24.0 } else { hours };
+
+		// adding 24 to a tiny negative number can round up to 24
+		Self {
+			hours: if hours >= 24.0 { 0.0 } else { hours },
+		}
+	}
+
+	/// Gets the sidereal time in hours, from zero up to 24
+	#[must_use]
+	pub const fn hours(self) -> f64 {
+		self.hours
+	}
+
+	/// Gets the sidereal time in degrees, from zero up to 360
+	#[must_use]
+	pub fn degrees(self) -> f64 {
+		self.hours * 15.0
+	}
+
+	/// Gets the sidereal time in radians, from zero up to 2π
+	#[must_use]
+	pub fn radians(self) -> f64 {
+		self.hours * (core::f64::consts::PI / 12.0)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn seconds(hours: f64) -> f64 {
+		hours * 3600.0
+	}
+
+	#[test]
+	fn greenwich_mean() {
+		// examples 12.a and 12.b from Astronomical Algorithms by Jean Meeus
+		let gmst = SiderealTime::greenwich_mean(crate::datetime!(1987-04-10 00:00 UTC));
+		let expected = 13.0 + 10.0 / 60.0 + 46.3668 / 3600.0;
+		assert!((seconds(gmst.hours()) - seconds(expected)).abs() < 0.01);
+
+		let gmst = SiderealTime::greenwich_mean(crate::datetime!(1987-04-10 19:21:00 UTC));
+		let expected = 8.0 + 34.0 / 60.0 + 57.0896 / 3600.0;
+		assert!((seconds(gmst.hours()) - seconds(expected)).abs() < 0.01);
+	}
+
+	#[test]
+	fn dut1() {
+		let utc = crate::datetime!(2024-03-10 12:00 UTC);
+		let later = SiderealTime::greenwich_mean_ut1(utc, 0.5);
+		let now = SiderealTime::greenwich_mean(utc);
+		let difference = seconds(later.hours() - now.hours());
+		assert!((difference - 0.5 * 1.002_737_9).abs() < 1e-4);
+	}
+
+	#[test]
+	fn wraps_around() {
+		let gmst = SiderealTime::greenwich_mean(crate::datetime!(2000-01-01 12:00 UTC));
+		let east = gmst.at_longitude(180.0);
+		assert!((east.hours() - (gmst.hours() - 12.0)).abs() < 1e-9);
+		assert!(gmst.at_longitude(-360.0).hours() >= 0.0);
+		assert!((east.degrees() - east.hours() * 15.0).abs() < 1e-9);
+	}
+}
@@ -0,0 +1,267 @@
+use core::convert::Infallible;
+use core::fmt::Display;
+use core::str::FromStr;
+
+use crate::{
+	tai::{self, GlobalLeapSeconds, LeapSecondProvider},
+	timezone::{ParseTimeZoneError, Utc, UtcOffset},
+	DateTime, NaiveDateTime, Time, TimeZone, Timestamp,
+};
+
+const NANOS_PER_SECOND: i128 = 1_000_000_000;
+
+/// The number of seconds between the start of a smear and the leap second
+const HALF_WINDOW_SECONDS: i64 = 43_200;
+
+/// A time zone which spreads each leap second across the 24 hours surrounding
+/// it, from noon to noon UTC.
+///
+/// This is the linear smear used by Google and Amazon's NTP servers. During
+/// the smear, each smeared second is slightly longer than an SI second, so the
+/// smeared clock never needs to show 23:59:60. Outside of a smear, the smeared
+/// time is equal to UTC.
+///
+/// Because a `UtcOffset` is only precise to the second, the offsets returned
+/// by this time zone are rounded to the nearest second. Use
+/// [`SmearedUtc::to_smeared`] and [`SmearedUtc::from_smeared`] for exact
+/// conversions.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct SmearedUtc;
+
+/// Converts the number of true elapsed nanoseconds since the start of a smear
+/// into the number of smeared nanoseconds
+const fn smear_nanos(elapsed: i128) -> i128 {
+	elapsed * 86_400 / 86_401
+}
+
+/// Converts the number of smeared nanoseconds since the start of a smear into
+/// the number of true elapsed nanoseconds
+const fn unsmear_nanos(smeared: i128) -> i128 {
+	smeared * 86_401 / 86_400
+}
+
+const fn timestamp_nanos(timestamp: Timestamp) -> i128 {
+	timestamp.total_seconds() as i128 * NANOS_PER_SECOND + timestamp.nanosecond() as i128
+}
+
+const fn naive_from_nanos(nanos: i128) -> NaiveDateTime {
+	let seconds = nanos.div_euclid(NANOS_PER_SECOND) as i64;
+	let nanoseconds = nanos.rem_euclid(NANOS_PER_SECOND) as u32;
+	NaiveDateTime::from_timestamp(Timestamp::new(seconds, nanoseconds))
+}
+
+impl SmearedUtc {
+	/// Converts a UTC time into the time shown by a smeared clock, using the
+	/// global leap second table
+	#[must_use]
+	pub fn to_smeared(date_time: DateTime<Utc>) -> NaiveDateTime {
+		Self::to_smeared_with(&GlobalLeapSeconds, date_time)
+	}
+
+	/// Converts a UTC time into the time shown by a smeared clock, using leap
+	/// seconds from the given provider
+	#[must_use]
+	pub fn to_smeared_with<P: LeapSecondProvider + ?Sized>(
+		provider: &P,
+		date_time: DateTime<Utc>,
+	) -> NaiveDateTime {
+		let Some(leap_second) = tai::leap_second_near(provider, date_time, HALF_WINDOW_SECONDS)
+		else {
+			return date_time.naive_utc();
+		};
+
+		let leap_nanos = timestamp_nanos(leap_second.unix_timestamp());
+		let start_nanos = leap_nanos - i128::from(HALF_WINDOW_SECONDS) * NANOS_PER_SECOND;
+		let given_nanos = timestamp_nanos(date_time.unix_timestamp());
+
+		// the leap second itself has the same timestamp as the following midnight
+		let after_leap_second = given_nanos >= leap_nanos && date_time.naive_utc().second() != 60;
+		let elapsed = given_nanos - start_nanos + NANOS_PER_SECOND * i128::from(after_leap_second);
+		if elapsed >= 86_401 * NANOS_PER_SECOND {
+			return date_time.naive_utc();
+		}
+
+		naive_from_nanos(start_nanos + smear_nanos(elapsed))
+	}
+
+	/// Converts the time shown by a smeared clock into UTC, using the global
+	/// leap second table
+	#[must_use]
+	pub fn from_smeared(smeared: NaiveDateTime) -> DateTime<Utc> {
+		Self::from_smeared_with(&GlobalLeapSeconds, smeared)
+	}
+
+	/// Converts the time shown by a smeared clock into UTC, using leap seconds
+	/// from the given provider
+	#[must_use]
+	pub fn from_smeared_with<P: LeapSecondProvider + ?Sized>(
+		provider: &P,
+		smeared: NaiveDateTime,
+	) -> DateTime<Utc> {
+		let as_utc = DateTime::from_utc(smeared, Utc);
+		let Some(leap_second) = tai::leap_second_near(provider, as_utc, HALF_WINDOW_SECONDS) else {
+			return as_utc;
+		};
+
+		let leap_nanos = timestamp_nanos(leap_second.unix_timestamp());
+		let start_nanos = leap_nanos - i128::from(HALF_WINDOW_SECONDS) * NANOS_PER_SECOND;
+		let smeared_elapsed = timestamp_nanos(smeared.timestamp()) - start_nanos;
+		if smeared_elapsed >= 86_400 * NANOS_PER_SECOND {
+			return as_utc;
+		}
+
+		let elapsed = unsmear_nanos(smeared_elapsed);
+		let leap_second_start = i128::from(HALF_WINDOW_SECONDS) * NANOS_PER_SECOND;
+		let utc = if elapsed < leap_second_start {
+			naive_from_nanos(start_nanos + elapsed)
+		} else if elapsed < leap_second_start + NANOS_PER_SECOND {
+			let day = naive_from_nanos(leap_nanos - NANOS_PER_SECOND).date();
+			let nanosecond = (elapsed - leap_second_start) as u32;
+			let time = Time::from_hms_nano_unchecked(23, 59, 60, nanosecond);
+			NaiveDateTime::new(day, time)
+		} else {
+			naive_from_nanos(start_nanos + elapsed - NANOS_PER_SECOND)
+		};
+
+		DateTime::from_utc(utc, Utc)
+	}
+}
+
+/// Rounds a number of nanoseconds to the nearest offset in seconds
+fn offset_from_nanos(nanos: i128) -> UtcOffset {
+	let seconds = (nanos + NANOS_PER_SECOND / 2).div_euclid(NANOS_PER_SECOND);
+	UtcOffset::from_seconds_unchecked(seconds as i32)
+}
+
+impl Display for SmearedUtc {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(f, "Smeared UTC")
+	}
+}
+
+impl FromStr for SmearedUtc {
+	type Err = ParseTimeZoneError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s {
+			"Smeared UTC" => Ok(SmearedUtc),
+			_ => Err(ParseTimeZoneError),
+		}
+	}
+}
+
+impl TimeZone for SmearedUtc {
+	type Err = Infallible;
+
+	fn utc_offset(&self, date_time: DateTime<Utc>) -> UtcOffset {
+		let smeared = Self::to_smeared(date_time);
+		offset_from_nanos(
+			timestamp_nanos(smeared.timestamp()) - timestamp_nanos(date_time.unix_timestamp()),
+		)
+	}
+
+	fn offset_from_local_naive(&self, date_time: NaiveDateTime) -> Result<UtcOffset, Self::Err> {
+		let utc = Self::from_smeared(date_time);
+		Ok(offset_from_nanos(
+			timestamp_nanos(date_time.timestamp()) - timestamp_nanos(utc.unix_timestamp()),
+		))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn smear_is_linear_over_the_window() {
+		assert_eq!(smear_nanos(0), 0);
+		assert_eq!(
+			smear_nanos(86_401 * NANOS_PER_SECOND),
+			86_400 * NANOS_PER_SECOND
+		);
+
+		// halfway through the window, the smeared clock is half a second behind
+		let halfway = 43_200 * NANOS_PER_SECOND + NANOS_PER_SECOND / 2;
+		assert_eq!(smear_nanos(halfway), 43_200 * NANOS_PER_SECOND);
+	}
+
+	#[test]
+	fn unsmear_inverts_smear() {
+		for elapsed in [
+			0,
+			1,
+			999_999_999,
+			43_200 * NANOS_PER_SECOND,
+			86_400 * NANOS_PER_SECOND,
+		] {
+			let smeared = smear_nanos(unsmear_nanos(elapsed));
+			assert!((smeared - elapsed).abs() <= 1);
+		}
+	}
+
+	#[test]
+	fn smear_around_leap_second() {
+		use crate::datetime;
+
+		let smear = |date_time: DateTime<Utc>| SmearedUtc::to_smeared(date_time);
+
+		// the window starts at noon before the leap second
+		let start = datetime!(2016-12-31 12:00 UTC);
+		assert_eq!(smear(start), start.naive_utc());
+		assert_eq!(
+			smear(datetime!(2016-12-31 11:59:59.999999999 UTC)),
+			datetime!(2016-12-31 11:59:59.999999999)
+		);
+
+		// the leap second is in the middle, so it's smeared by half a second
+		assert_eq!(
+			smear(datetime!(2016-12-31 23:59:60 UTC)),
+			datetime!(2016-12-31 23:59:59.500005786)
+		);
+		assert_eq!(
+			smear(datetime!(2016-12-31 23:59:60.5 UTC)),
+			datetime!(2017-01-01 00:00)
+		);
+
+		// and it ends at noon after
+		let end = datetime!(2017-01-01 12:00 UTC);
+		assert_eq!(smear(end), end.naive_utc());
+		assert_eq!(
+			smear(datetime!(2017-01-01 11:59:59 UTC)),
+			datetime!(2017-01-01 11:59:59.000011573)
+		);
+	}
+
+	#[test]
+	fn smear_round_trips() {
+		use crate::datetime;
+		use core::time::Duration;
+
+		for date_time in [
+			datetime!(2016-12-31 12:00 UTC),
+			datetime!(2016-12-31 18:00:00.123 UTC),
+			datetime!(2016-12-31 23:59:59.75 UTC),
+			datetime!(2016-12-31 23:59:60 UTC),
+			datetime!(2016-12-31 23:59:60.999999999 UTC),
+			datetime!(2017-01-01 00:00 UTC),
+			datetime!(2017-01-01 11:59:59.999999999 UTC),
+			datetime!(2017-06-01 00:00 UTC),
+		] {
+			let round_trip = SmearedUtc::from_smeared(SmearedUtc::to_smeared(date_time));
+			let error = round_trip.time_until(&date_time) + date_time.time_until(&round_trip);
+			assert!(
+				error <= Duration::from_nanos(1),
+				"{date_time} became {round_trip}"
+			);
+		}
+	}
+
+	#[test]
+	fn offsets_round_to_nearest_second() {
+		assert_eq!(offset_from_nanos(-400_000_000), UtcOffset::UTC);
+		assert_eq!(
+			offset_from_nanos(-600_000_000),
+			UtcOffset::from_seconds_unchecked(-1)
+		);
+	}
+}
@@ -0,0 +1,230 @@
+//! Platform-specific functionality
+
+/// Checks if the current process only has one thread.
+/// If this can't be determined, then this returns `false`.
+pub(crate) fn is_single_threaded() -> bool {
+	#[cfg(target_os = "linux")]
+	{
+		std::fs::read_dir("/proc/self/task")
+			.map(|tasks| tasks.count() == 1)
+			.unwrap_or(false)
+	}
+
+	#[cfg(not(target_os = "linux"))]
+	{
+		false
+	}
+}
+
+// `localtime_r` isn't required to check for changes to `TZ`
+#[cfg(unix)]
+extern "C" {
+	fn tzset();
+}
+
+/// Gets the number of seconds the system's local time is ahead of UTC at the
+/// given Unix timestamp. Returns `None` if the offset can't be determined.
+///
+/// # Safety
+///
+/// This reads the `TZ` environment variable, so no other thread may modify
+/// the environment while this is running.
+#[cfg(unix)]
+pub(crate) unsafe fn local_offset_at(unix_seconds: i64) -> Option<i32> {
+	// time_t is 32 bits on some platforms
+	#[allow(clippy::useless_conversion)]
+	let time: libc::time_t = unix_seconds.try_into().ok()?;
+	let mut tm = core::mem::MaybeUninit::<libc::tm>::uninit();
+
+	tzset();
+	if libc::localtime_r(&time, tm.as_mut_ptr()).is_null() {
+		return None;
+	}
+
+	let tm = tm.assume_init();
+	tm.tm_gmtoff.try_into().ok()
+}
+
+/// Gets the number of seconds the system's local time is ahead of UTC at the
+/// given Unix timestamp. Returns `None` if the offset can't be determined.
+///
+/// # Safety
+///
+/// This reads the `TZ` environment variable, so no other thread may modify
+/// the environment while this is running.
+#[cfg(not(unix))]
+pub(crate) unsafe fn local_offset_at(_: i64) -> Option<i32> {
+	None
+}
+
+/// Reads the given clock, returning the seconds and nanoseconds
+#[cfg(target_os = "linux")]
+fn clock_gettime(clock: libc::clockid_t) -> Option<(i64, u32)> {
+	let mut time = core::mem::MaybeUninit::<libc::timespec>::uninit();
+	if unsafe { libc::clock_gettime(clock, time.as_mut_ptr()) } != 0 {
+		return None;
+	}
+
+	let time = unsafe { time.assume_init() };
+	#[allow(clippy::useless_conversion)]
+	Some((time.tv_sec.into(), time.tv_nsec.try_into().ok()?))
+}
+
+/// Gets the current TAI time from the kernel, as the number of seconds since
+/// 1970-01-01 00:00:00 TAI. Returns `None` if the kernel doesn't know the
+/// current TAI offset.
+#[cfg(target_os = "linux")]
+pub(crate) fn tai_now() -> Option<(i64, u32)> {
+	let tai = clock_gettime(libc::CLOCK_TAI)?;
+	let utc = clock_gettime(libc::CLOCK_REALTIME)?;
+
+	// unless something like chrony sets it, the kernel's TAI offset is zero
+	if tai.0 - utc.0 < 10 {
+		return None;
+	}
+
+	Some(tai)
+}
+
+/// Gets the current TAI time from the kernel, as the number of seconds since
+/// 1970-01-01 00:00:00 TAI. Returns `None` if the kernel doesn't know the
+/// current TAI offset.
+#[cfg(not(target_os = "linux"))]
+pub(crate) fn tai_now() -> Option<(i64, u32)> {
+	None
+}
+
+/// Reads the kernel's NTP state, without changing it. Returns the number of
+/// seconds TAI is ahead of UTC (zero if unknown), and the pending leap second,
+/// as `1` for an inserted second, `-1` for a deleted second, or `0` for none.
+#[cfg(target_os = "linux")]
+pub(crate) fn kernel_leap_state() -> Option<(i32, i8)> {
+	let mut timex: libc::timex = unsafe { core::mem::zeroed() };
+	if unsafe { libc::adjtimex(&mut timex) } == -1 {
+		return None;
+	}
+
+	let pending = if timex.status & libc::STA_INS != 0 {
+		1
+	} else if timex.status & libc::STA_DEL != 0 {
+		-1
+	} else {
+		0
+	};
+
+	Some((timex.tai, pending))
+}
+
+/// Reads the kernel's NTP state, without changing it. Returns the number of
+/// seconds TAI is ahead of UTC (zero if unknown), and the pending leap second,
+/// as `1` for an inserted second, `-1` for a deleted second, or `0` for none.
+#[cfg(not(target_os = "linux"))]
+pub(crate) fn kernel_leap_state() -> Option<(i32, i8)> {
+	None
+}
+
+/// Quickly reads the wall clock, with a resolution of a few milliseconds.
+/// Returns the seconds and nanoseconds since the Unix epoch.
+#[cfg(target_os = "linux")]
+pub(crate) fn coarse_now() -> Option<(i64, u32)> {
+	clock_gettime(libc::CLOCK_REALTIME_COARSE)
+}
+
+/// Quickly reads the wall clock, with a resolution of a few milliseconds.
+/// Returns the seconds and nanoseconds since the Unix epoch.
+#[cfg(windows)]
+pub(crate) fn coarse_now() -> Option<(i64, u32)> {
+	use windows_sys::Win32::{
+		Foundation::FILETIME, System::SystemInformation::GetSystemTimeAsFileTime,
+	};
+
+	let mut file_time = FILETIME {
+		dwLowDateTime: 0,
+		dwHighDateTime: 0,
+	};
+	unsafe { GetSystemTimeAsFileTime(&mut file_time) };
+
+	Some(from_file_time(
+		file_time.dwHighDateTime,
+		file_time.dwLowDateTime,
+	))
+}
+
+/// Quickly reads the wall clock, with a resolution of a few milliseconds.
+/// Returns the seconds and nanoseconds since the Unix epoch.
+#[cfg(not(any(target_os = "linux", windows)))]
+pub(crate) fn coarse_now() -> Option<(i64, u32)> {
+	None
+}
+
+/// Converts a Windows `FILETIME`, which counts 100 nanosecond intervals since
+/// 1601, into the seconds and nanoseconds since the Unix epoch
+#[cfg(windows)]
+fn from_file_time(high: u32, low: u32) -> (i64, u32) {
+	const UNIX_EPOCH_INTERVALS: i64 = 116_444_736_000_000_000;
+
+	let intervals = ((u64::from(high) << 32) | u64::from(low)) as i64 - UNIX_EPOCH_INTERVALS;
+	let seconds = intervals.div_euclid(10_000_000);
+	let nanoseconds = intervals.rem_euclid(10_000_000) as u32 * 100;
+	(seconds, nanoseconds)
+}
+
+/// Gets the resolution of the given clock
+#[cfg(target_os = "linux")]
+fn clock_getres(clock: libc::clockid_t) -> Option<core::time::Duration> {
+	let mut resolution = core::mem::MaybeUninit::<libc::timespec>::uninit();
+	if unsafe { libc::clock_getres(clock, resolution.as_mut_ptr()) } != 0 {
+		return None;
+	}
+
+	let resolution = unsafe { resolution.assume_init() };
+	Some(core::time::Duration::new(
+		resolution.tv_sec.try_into().ok()?,
+		resolution.tv_nsec.try_into().ok()?,
+	))
+}
+
+/// Gets the resolution of the system's wall clock, if it's known
+#[cfg(target_os = "linux")]
+pub(crate) fn realtime_resolution() -> Option<core::time::Duration> {
+	clock_getres(libc::CLOCK_REALTIME)
+}
+
+/// Gets the resolution of the system's wall clock, if it's known
+#[cfg(windows)]
+pub(crate) fn realtime_resolution() -> Option<core::time::Duration> {
+	// the standard library uses GetSystemTimePreciseAsFileTime
+	Some(core::time::Duration::from_nanos(100))
+}
+
+/// Gets the resolution of the system's wall clock, if it's known
+#[cfg(not(any(target_os = "linux", windows)))]
+pub(crate) fn realtime_resolution() -> Option<core::time::Duration> {
+	None
+}
+
+/// Gets the resolution of the clock used by [`coarse_now`], if it's known
+#[cfg(target_os = "linux")]
+pub(crate) fn coarse_resolution() -> Option<core::time::Duration> {
+	clock_getres(libc::CLOCK_REALTIME_COARSE)
+}
+
+/// Gets the resolution of the clock used by [`coarse_now`], if it's known
+#[cfg(windows)]
+pub(crate) fn coarse_resolution() -> Option<core::time::Duration> {
+	use windows_sys::Win32::System::SystemInformation::GetSystemTimeAdjustment;
+
+	let (mut adjustment, mut increment, mut disabled) = (0, 0, 0);
+	if unsafe { GetSystemTimeAdjustment(&mut adjustment, &mut increment, &mut disabled) } == 0 {
+		return None;
+	}
+
+	// the increment is in 100 nanosecond intervals
+	Some(core::time::Duration::from_nanos(u64::from(increment) * 100))
+}
+
+/// Gets the resolution of the clock used by [`coarse_now`], if it's known
+#[cfg(not(any(target_os = "linux", windows)))]
+pub(crate) fn coarse_resolution() -> Option<core::time::Duration> {
+	None
+}
@@ -1,62 +1,1480 @@
-use core::cmp::Ordering;
-use core::fmt::Display;
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, string::String, sync::Arc, vec::Vec};
+use core::fmt::{Display, Write};
+use core::str::FromStr;
+#[cfg(feature = "std")]
+use std::path::{Path, PathBuf};
+#[cfg(feature = "std")]
+use std::sync::OnceLock;
 
-use parking_lot::{const_rwlock, RwLock};
+#[cfg(feature = "std")]
+use arc_swap::ArcSwap;
 use thiserror::Error;
 
+#[cfg(feature = "std")]
+use crate::sys;
 use crate::{
-	timezone::{Utc, UtcOffset},
-	Date, DateTime, NaiveDateTime, Time, TimeZone,
+	smear::SmearedUtc,
+	timezone::{ParseTimeZoneError, Utc, UtcOffset},
+	Date, DateTime, Month, NaiveDateTime, OutOfRange, Time, TimeZone, Timestamp, Year,
 };
 
-static GLOBAL_LEAP_SECONDS: RwLock<LeapSeconds> = const_rwlock(LeapSeconds::empty());
+#[cfg(feature = "std")]
+static GLOBAL_LEAP_SECONDS: OnceLock<ArcSwap<LeapSecondTable>> = OnceLock::new();
 
-#[derive(Debug)]
-struct LeapSeconds(Vec<DateTime<Utc>>);
+/// Gets the global leap second table. If [`init`] wasn't called, then the
+/// embedded table is used.
+///
+/// The table is never modified in place. Instead, changes replace it with a
+/// new snapshot, so readers don't need to take a lock.
+#[cfg(feature = "std")]
+fn global_leap_seconds() -> &'static ArcSwap<LeapSecondTable> {
+	GLOBAL_LEAP_SECONDS.get_or_init(|| ArcSwap::from_pointee(LeapSecondTable::embedded()))
+}
+
+/// Reads the current snapshot of the global leap second table
+#[cfg(feature = "std")]
+fn with_global_leap_seconds<R>(f: impl FnOnce(LeapSecondSlice<'_>) -> R) -> R {
+	f(global_leap_seconds().load().as_slice())
+}
+
+/// Reads the global leap second table. Without `std`, there's nowhere to
+/// keep a table which can be modified, so this is always the embedded table.
+#[cfg(not(feature = "std"))]
+fn with_global_leap_seconds<R>(f: impl FnOnce(LeapSecondSlice<'_>) -> R) -> R {
+	f(LeapSecondSlice::embedded())
+}
+
+/// The month following each leap second that was announced when this version
+/// of the library was published. Each leap second happened at the end of the
+/// day before the first of the month.
+const EMBEDDED_LEAP_SECONDS: [(i16, Month); 27] = [
+	(1972, Month::July),
+	(1973, Month::January),
+	(1974, Month::January),
+	(1975, Month::January),
+	(1976, Month::January),
+	(1977, Month::January),
+	(1978, Month::January),
+	(1979, Month::January),
+	(1980, Month::January),
+	(1981, Month::July),
+	(1982, Month::July),
+	(1983, Month::July),
+	(1985, Month::July),
+	(1988, Month::January),
+	(1990, Month::January),
+	(1991, Month::January),
+	(1992, Month::July),
+	(1993, Month::July),
+	(1994, Month::July),
+	(1996, Month::January),
+	(1997, Month::July),
+	(1999, Month::January),
+	(2006, Month::January),
+	(2009, Month::January),
+	(2012, Month::July),
+	(2015, Month::July),
+	(2017, Month::January),
+];
+
+/// The expiration date of the embedded leap second table, from the
+/// `leap-seconds.list` it was copied from. This is the release which followed
+/// IERS Bulletin C 72, which announced that there won't be a leap second at
+/// the end of December 2026.
+const EMBEDDED_EXPIRY: (i16, Month, u8) = (2027, Month::June, 28);
+
+/// Where the global leap second table is loaded from
+#[cfg(feature = "std")]
+#[derive(Clone, Debug, Default)]
+pub enum LeapSecondSource {
+	/// Start without any leap seconds. TAI will be exactly ten seconds ahead
+	/// of UTC, unless leap seconds are added later.
+	Empty,
+	/// Use the leap seconds which were known when this library was published
+	#[default]
+	Embedded,
+	/// Load a `leap-seconds.list` or TZif file.
+	/// See [`load_leap_seconds_from_file`].
+	File(PathBuf),
+	/// Use the given table
+	Table(LeapSecondTable),
+}
+
+/// Configures how the global leap second table is initialized
+#[cfg(feature = "std")]
+#[derive(Clone, Debug, Default)]
+pub struct LeapSecondConfig {
+	/// Where the initial leap seconds come from
+	pub source: LeapSecondSource,
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Error)]
+pub enum InitLeapSecondsError {
+	#[error("The global leap second table was already initialized")]
+	AlreadyInitialized,
+	#[error(transparent)]
+	Load(#[from] LoadLeapSecondsError),
+}
+
+/// Initializes the global leap second table. This should be called at startup,
+/// before anything uses the table. If this isn't called, the embedded table
+/// is used. After initialization, the table can only be changed by functions
+/// like [`add_leap_second`] and [`set_leap_seconds`].
+///
+/// # Errors
+///
+/// Returns an error if the table was already initialized, or if the leap
+/// second file couldn't be loaded
+#[cfg(feature = "std")]
+pub fn init(config: LeapSecondConfig) -> Result<(), InitLeapSecondsError> {
+	let table = match config.source {
+		LeapSecondSource::Empty => LeapSecondTable::empty(),
+		LeapSecondSource::Embedded => LeapSecondTable::embedded(),
+		LeapSecondSource::File(path) => LeapSecondTable::from_file(path)?,
+		LeapSecondSource::Table(table) => table,
+	};
+
+	GLOBAL_LEAP_SECONDS
+		.set(ArcSwap::from_pointee(table))
+		.map_err(|_| InitLeapSecondsError::AlreadyInitialized)
+}
+
+/// The difference between TAI and UTC before the first leap second, in 1972
+const INITIAL_TAI_OFFSET: i32 = 10;
+
+/// The start of 1972, when UTC switched from fractional adjustments to whole
+/// leap seconds
+const LEAP_SECONDS_START: NaiveDateTime = NaiveDateTime::new(
+	Date::from_ymd_unchecked(Year::from_i16(1972), Month::January, 1),
+	Time::MIDNIGHT,
+);
+
+/// A source of leap seconds, which is used to convert between UTC and TAI
+pub trait LeapSecondProvider {
+	/// Gets the number of seconds TAI is ahead of UTC at the given time.
+	/// A leap second is counted if the given time is the midnight immediately
+	/// following it.
+	fn tai_offset_at_utc(&self, date_time: DateTime<Utc>) -> i32;
+
+	/// Gets the number of seconds TAI is ahead of UTC, given a time in TAI.
+	/// During a leap second, this is the offset from before it. Use
+	/// [`Tai::to_utc`] to convert a time during a leap second.
+	fn tai_offset_at_tai(&self, date_time: NaiveDateTime) -> i32;
+
+	/// Gets the time after which the leap seconds from this provider are no
+	/// longer known, if there is one
+	fn expiry(&self) -> Option<DateTime<Utc>> {
+		None
+	}
+}
+
+impl<P: LeapSecondProvider + ?Sized> LeapSecondProvider for &P {
+	fn tai_offset_at_utc(&self, date_time: DateTime<Utc>) -> i32 {
+		(**self).tai_offset_at_utc(date_time)
+	}
+
+	fn tai_offset_at_tai(&self, date_time: NaiveDateTime) -> i32 {
+		(**self).tai_offset_at_tai(date_time)
+	}
+
+	fn expiry(&self) -> Option<DateTime<Utc>> {
+		(**self).expiry()
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<P: LeapSecondProvider + ?Sized> LeapSecondProvider for Arc<P> {
+	fn tai_offset_at_utc(&self, date_time: DateTime<Utc>) -> i32 {
+		(**self).tai_offset_at_utc(date_time)
+	}
+
+	fn tai_offset_at_tai(&self, date_time: NaiveDateTime) -> i32 {
+		(**self).tai_offset_at_tai(date_time)
+	}
+
+	fn expiry(&self) -> Option<DateTime<Utc>> {
+		(**self).expiry()
+	}
+}
+
+/// The default [`LeapSecondProvider`], which uses a table shared by the
+/// entire process. The table can be modified using [`add_leap_second`] and
+/// [`load_leap_seconds_from_file`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct GlobalLeapSeconds;
+
+impl LeapSecondProvider for GlobalLeapSeconds {
+	fn tai_offset_at_utc(&self, date_time: DateTime<Utc>) -> i32 {
+		with_global_leap_seconds(|table| table.tai_offset_at_utc(date_time))
+	}
+
+	fn tai_offset_at_tai(&self, date_time: NaiveDateTime) -> i32 {
+		with_global_leap_seconds(|table| table.tai_offset_at_tai(date_time))
+	}
+
+	fn expiry(&self) -> Option<DateTime<Utc>> {
+		with_global_leap_seconds(|table| table.expiry())
+	}
+}
+
+/// The midnight following each leap second in [`EMBEDDED_LEAP_SECONDS`]
+const EMBEDDED_MIDNIGHTS: [DateTime<Utc>; EMBEDDED_LEAP_SECONDS.len()] = {
+	let mut midnights = [DateTime::from_utc(LEAP_SECONDS_START, Utc); EMBEDDED_LEAP_SECONDS.len()];
+	let mut i = 0;
+	while i < EMBEDDED_LEAP_SECONDS.len() {
+		let (year, month) = EMBEDDED_LEAP_SECONDS[i];
+		let day = Date::from_ymd_unchecked(Year::from_i16(year), month, 1);
+		midnights[i] = DateTime::from_utc(NaiveDateTime::new(day, Time::MIDNIGHT), Utc);
+		i += 1;
+	}
+
+	midnights
+};
+
+/// A list of leap seconds borrowed from a slice, which can be used as a
+/// [`LeapSecondProvider`] without an allocator.
+///
+/// Each leap second is represented by the midnight immediately following it.
+/// The total difference between TAI and UTC after each leap second is its
+/// position in the list, so lookups are a binary search.
+///
+/// # Example
+///
+/// ```
+/// use botic::tai::{LeapSecondSlice, Tai};
+/// use botic::timezone::Utc;
+/// use botic::{Date, DateTime, Month, NaiveDateTime, Time, TimeZone};
+///
+/// const fn midnight(year: i16, month: Month) -> DateTime<Utc> {
+///     let date = Date::from_ymd_unchecked(botic::Year::from_i16(year), month, 1);
+///     DateTime::from_utc(NaiveDateTime::new(date, Time::MIDNIGHT), Utc)
+/// }
+///
+/// static LEAP_SECONDS: [DateTime<Utc>; 2] =
+///     [midnight(2015, Month::July), midnight(2017, Month::January)];
+///
+/// let tai = Tai::with_provider(LeapSecondSlice::new(&LEAP_SECONDS, None));
+/// assert_eq!(tai.utc_offset(midnight(2016, Month::January)).seconds_ahead(), 11);
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct LeapSecondSlice<'a> {
+	leap_seconds: &'a [DateTime<Utc>],
+	expiry: Option<DateTime<Utc>>,
+}
+
+impl LeapSecondSlice<'static> {
+	/// The leap seconds which were known when this version of the library was
+	/// published
+	#[must_use]
+	pub const fn embedded() -> Self {
+		let (year, month, day) = EMBEDDED_EXPIRY;
+		let expiry = Date::from_ymd_unchecked(Year::from_i16(year), month, day);
+		let expiry = DateTime::from_utc(NaiveDateTime::new(expiry, Time::MIDNIGHT), Utc);
+
+		Self {
+			leap_seconds: &EMBEDDED_MIDNIGHTS,
+			expiry: Some(expiry),
+		}
+	}
+}
+
+impl<'a> LeapSecondSlice<'a> {
+	/// Creates a list from the midnight immediately following each leap second,
+	/// and the time after which the list can no longer be trusted
+	///
+	/// # Panics
+	///
+	/// Panics if the leap seconds aren't sorted, or if one is repeated
+	#[must_use]
+	pub const fn new(leap_seconds: &'a [DateTime<Utc>], expiry: Option<DateTime<Utc>>) -> Self {
+		let mut i = 1;
+		while i < leap_seconds.len() {
+			let previous = leap_seconds[i - 1].unix_timestamp().total_seconds();
+			let current = leap_seconds[i].unix_timestamp().total_seconds();
+			assert!(previous < current, "the leap seconds must be sorted");
+			i += 1;
+		}
+
+		Self {
+			leap_seconds,
+			expiry,
+		}
+	}
+
+	/// Gets the leap seconds in this list. Each leap second is represented by
+	/// the midnight immediately following it.
+	#[must_use]
+	pub const fn as_slice(&self) -> &'a [DateTime<Utc>] {
+		self.leap_seconds
+	}
+
+	/// Gets the time after which this list can no longer be trusted, because a
+	/// new leap second may have been announced
+	#[must_use]
+	pub const fn expiry(&self) -> Option<DateTime<Utc>> {
+		self.expiry
+	}
+
+	/// Gets the difference between TAI and UTC after the given number of leap
+	/// seconds
+	const fn offset_after(count: usize) -> i32 {
+		INITIAL_TAI_OFFSET + count as i32
+	}
+
+	/// Gets the first leap second in this list which ends after the given
+	/// time. See [`LeapSecondTable::next_leap_second`].
+	#[must_use]
+	pub fn next_leap_second(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+		let i = self.leap_seconds_before_inclusive(after);
+		self.leap_seconds.get(i).copied()
+	}
+
+	/// Gets the last leap second in this list which ended before the given
+	/// time. See [`LeapSecondTable::previous_leap_second`].
+	#[must_use]
+	pub fn previous_leap_second(&self, before: DateTime<Utc>) -> Option<DateTime<Utc>> {
+		let i = self
+			.leap_seconds
+			.partition_point(|leap_second| *leap_second < before);
+		i.checked_sub(1).map(|i| self.leap_seconds[i])
+	}
+
+	/// Checks if the given UTC time is during a leap second in this list
+	#[must_use]
+	pub fn is_leap_second(&self, date_time: NaiveDateTime) -> bool {
+		if date_time.second() != 60 {
+			return false;
+		}
+
+		let time = date_time.time();
+		if time.hour() != 23 || time.minute() != 59 {
+			return false;
+		}
+
+		self.leap_seconds
+			.iter()
+			.any(|leap_second| is_day_before(date_time.date(), leap_second.naive_utc().date()))
+	}
+
+	/// Gets the number of leap seconds which were inserted before the given
+	/// time. A leap second is counted if the given time is the midnight
+	/// immediately following it.
+	#[must_use]
+	pub fn leap_seconds_before_inclusive(&self, date_time: DateTime<Utc>) -> usize {
+		self.leap_seconds
+			.partition_point(|leap_second| *leap_second <= date_time)
+	}
+
+	/// Counts the leap seconds in this list between two times, in either
+	/// order. See [`leap_seconds_between`].
+	#[must_use]
+	pub fn leap_seconds_between(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> u32 {
+		self.leap_seconds_between_signed(start, end).unsigned_abs()
+	}
+
+	/// Counts the leap seconds in this list from `start` to `end`, which is
+	/// negative if `end` is before `start`. See
+	/// [`leap_seconds_between_signed`].
+	#[must_use]
+	pub fn leap_seconds_between_signed(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> i32 {
+		// the list is much shorter than i32::MAX
+		self.leap_seconds_before_inclusive(end) as i32
+			- self.leap_seconds_before_inclusive(start) as i32
+	}
+
+	/// Writes this list in the `leap-seconds.list` format. The hash used by
+	/// the official lists isn't included.
+	///
+	/// # Errors
+	///
+	/// Returns an error if the writer fails
+	pub fn write_leap_seconds_list(&self, writer: &mut impl Write) -> core::fmt::Result {
+		if let Some(expiry) = self.expiry {
+			let ntp_seconds = expiry.unix_timestamp().total_seconds() + NTP_UNIX_OFFSET;
+			writeln!(writer, "#@\t{ntp_seconds}")?;
+		}
+
+		writeln!(writer, "{LEAP_SECONDS_START_NTP}\t{INITIAL_TAI_OFFSET}")?;
+		for (i, leap_second) in self.leap_seconds.iter().enumerate() {
+			let ntp_seconds = leap_second.unix_timestamp().total_seconds() + NTP_UNIX_OFFSET;
+			writeln!(writer, "{ntp_seconds}\t{}", Self::offset_after(i + 1))?;
+		}
+
+		Ok(())
+	}
+}
+
+impl LeapSecondProvider for LeapSecondSlice<'_> {
+	fn tai_offset_at_utc(&self, date_time: DateTime<Utc>) -> i32 {
+		Self::offset_after(self.leap_seconds_before_inclusive(date_time))
+	}
+
+	fn tai_offset_at_tai(&self, date_time: NaiveDateTime) -> i32 {
+		// each leap second is at midnight UTC, which is a few seconds after
+		// midnight in TAI
+		let is_before = |i: usize| {
+			// the offset is always less than an hour, so this is a valid time
+			let offset = Self::offset_after(i + 1) as u8;
+			let tai_time = Time::from_hms_unchecked(0, offset / 60, offset % 60);
+			let leap_second = self.leap_seconds[i].naive_utc();
+			NaiveDateTime::new(leap_second.date(), tai_time) <= date_time
+		};
+
+		let (mut count, mut end) = (0, self.leap_seconds.len());
+		while count < end {
+			let middle = count + (end - count) / 2;
+			if is_before(middle) {
+				count = middle + 1;
+			} else {
+				end = middle;
+			}
+		}
+
+		Self::offset_after(count)
+	}
+
+	fn expiry(&self) -> Option<DateTime<Utc>> {
+		self.expiry
+	}
+}
+
+/// A list of leap seconds, which can be used as a [`LeapSecondProvider`].
+///
+/// This owns its leap seconds, so they can be added to. The table is kept
+/// sorted, and can be borrowed as a [`LeapSecondSlice`].
+#[cfg(feature = "alloc")]
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct LeapSecondTable {
+	leap_seconds: Vec<DateTime<Utc>>,
+	expiry: Option<DateTime<Utc>>,
+}
+
+#[cfg(feature = "alloc")]
+impl LeapSecondTable {
+	/// Creates a table without any leap seconds
+	#[must_use]
+	pub const fn empty() -> Self {
+		Self {
+			leap_seconds: Vec::new(),
+			expiry: None,
+		}
+	}
+
+	/// Creates a table with the leap seconds which were known when this
+	/// version of the library was published
+	#[must_use]
+	pub fn embedded() -> Self {
+		LeapSecondSlice::embedded().into()
+	}
+
+	/// Loads a table from a `leap-seconds.list` or TZif file.
+	/// See [`load_leap_seconds_from_file`] for details.
+	///
+	/// # Errors
+	///
+	/// Returns an error if the file can't be read, if it isn't in a supported
+	/// format, or if it doesn't contain any leap seconds.
+	#[cfg(feature = "std")]
+	pub fn from_file(path: impl AsRef<Path>) -> Result<Self, LoadLeapSecondsError> {
+		let data = std::fs::read(path)?;
+		if data.starts_with(b"TZif") {
+			Self::from_midnights(parse_tzif_leap_seconds(&data)?)
+		} else {
+			let list = String::from_utf8(data).map_err(|_| LoadLeapSecondsError::InvalidLine(1))?;
+			Self::from_leap_seconds_list(&list)
+		}
+	}
 
-impl LeapSeconds {
-	// TODO docs
+	/// Creates a table from the contents of a `leap-seconds.list` file,
+	/// including its expiration date
+	///
+	/// # Errors
+	///
+	/// Returns an error if the list is invalid, or if it doesn't contain any
+	/// leap seconds
+	pub fn from_leap_seconds_list(list: &str) -> Result<Self, LoadLeapSecondsError> {
+		let mut table = Self::from_midnights(parse_leap_seconds_list(list)?)?;
+		table.expiry = parse_leap_seconds_list_expiry(list).map(utc_from_unix);
+		Ok(table)
+	}
+
+	/// Creates a table, given the Unix timestamp of the midnight following
+	/// each leap second
+	fn from_midnights(midnights: Vec<i64>) -> Result<Self, LoadLeapSecondsError> {
+		if midnights.is_empty() {
+			return Err(LoadLeapSecondsError::NoLeapSeconds);
+		}
+
+		let mut leap_seconds: Vec<DateTime<Utc>> =
+			midnights.into_iter().map(utc_from_unix).collect();
+		leap_seconds.sort();
+		leap_seconds.dedup();
 
-	const fn empty() -> Self {
-		Self(Vec::new())
+		Ok(Self {
+			leap_seconds,
+			expiry: None,
+		})
 	}
 
-	fn leap_seconds_before_inclusive(&self, date_time: DateTime<Utc>) -> usize {
-		let mut seconds = 0;
-		for leap_second in &self.0 {
-			if leap_second > &date_time {
-				break;
+	/// Writes this table in the `leap-seconds.list` format. The hash used by
+	/// the official lists isn't included.
+	#[must_use]
+	pub fn to_leap_seconds_list(&self) -> String {
+		let mut list = String::new();
+		self.as_slice()
+			.write_leap_seconds_list(&mut list)
+			.expect("writing to a String never fails");
+		list
+	}
+
+	/// Writes this table in a compact binary form, which can be read using
+	/// [`LeapSecondTable::from_bytes`]
+	#[must_use]
+	pub fn to_bytes(&self) -> Vec<u8> {
+		let mut bytes = Vec::with_capacity(17 + 8 * self.leap_seconds.len());
+		bytes.extend(BINARY_MAGIC);
+		match self.expiry {
+			Some(expiry) => {
+				bytes.push(1);
+				bytes.extend(expiry.unix_timestamp().total_seconds().to_be_bytes());
 			}
-			seconds += 1;
+			None => bytes.extend([0; 9]),
+		}
+
+		bytes.extend((self.leap_seconds.len() as u32).to_be_bytes());
+		for leap_second in &self.leap_seconds {
+			bytes.extend(leap_second.unix_timestamp().total_seconds().to_be_bytes());
+		}
+
+		bytes
+	}
+
+	/// Reads a table that was written by [`LeapSecondTable::to_bytes`]
+	///
+	/// # Errors
+	///
+	/// Returns an error if the bytes aren't a valid table
+	pub fn from_bytes(bytes: &[u8]) -> Result<Self, LoadLeapSecondsError> {
+		use LoadLeapSecondsError::InvalidBinary;
+
+		let read_i64 = |at: usize| -> Result<i64, LoadLeapSecondsError> {
+			let bytes = bytes.get(at..at + 8).ok_or(InvalidBinary)?;
+			Ok(i64::from_be_bytes(
+				bytes.try_into().map_err(|_| InvalidBinary)?,
+			))
+		};
+
+		if bytes.get(..4) != Some(&BINARY_MAGIC) {
+			return Err(InvalidBinary);
 		}
 
-		seconds
+		let expiry = match bytes.get(4) {
+			Some(0) => None,
+			Some(1) => Some(utc_from_unix(read_i64(5)?)),
+			_ => return Err(InvalidBinary),
+		};
+
+		let count = bytes.get(13..17).ok_or(InvalidBinary)?;
+		let count = u32::from_be_bytes(count.try_into().map_err(|_| InvalidBinary)?) as usize;
+		if bytes.len() != 17 + 8 * count {
+			return Err(InvalidBinary);
+		}
+
+		let midnights = (0..count)
+			.map(|i| read_i64(17 + 8 * i))
+			.collect::<Result<Vec<_>, _>>()?;
+		let mut table = if midnights.is_empty() {
+			Self::empty()
+		} else {
+			Self::from_midnights(midnights)?
+		};
+		table.expiry = expiry;
+
+		Ok(table)
+	}
+
+	/// Borrows the leap seconds in this table
+	#[must_use]
+	pub fn as_slice(&self) -> LeapSecondSlice<'_> {
+		LeapSecondSlice {
+			leap_seconds: &self.leap_seconds,
+			expiry: self.expiry,
+		}
+	}
+
+	/// Gets the leap seconds in this table. Each leap second is represented
+	/// by the midnight immediately following it.
+	pub fn leap_seconds(&self) -> impl Iterator<Item = DateTime<Utc>> + '_ {
+		self.leap_seconds.iter().copied()
+	}
+
+	/// Gets the time after which this table can no longer be trusted, because
+	/// a new leap second may have been announced. Conversions after this time
+	/// are speculative.
+	#[must_use]
+	pub const fn expiry(&self) -> Option<DateTime<Utc>> {
+		self.expiry
+	}
+
+	/// Sets the time after which this table can no longer be trusted
+	pub fn set_expiry(&mut self, expiry: Option<DateTime<Utc>>) {
+		self.expiry = expiry;
+	}
+
+	/// Gets the first leap second in this table which ends after the given
+	/// time. Like the rest of the table, the leap second is represented by the
+	/// midnight immediately following it.
+	#[must_use]
+	pub fn next_leap_second(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+		self.as_slice().next_leap_second(after)
+	}
+
+	/// Gets the last leap second in this table which ended before the given
+	/// time. Like the rest of the table, the leap second is represented by the
+	/// midnight immediately following it.
+	#[must_use]
+	pub fn previous_leap_second(&self, before: DateTime<Utc>) -> Option<DateTime<Utc>> {
+		self.as_slice().previous_leap_second(before)
+	}
+
+	/// Checks if the given UTC time is during a leap second in this table
+	#[must_use]
+	pub fn is_leap_second(&self, date_time: NaiveDateTime) -> bool {
+		self.as_slice().is_leap_second(date_time)
+	}
+
+	/// Gets the number of leap seconds which were inserted before the given
+	/// time. A leap second is counted if the given time is the midnight
+	/// immediately following it.
+	#[must_use]
+	pub fn leap_seconds_before_inclusive(&self, date_time: DateTime<Utc>) -> usize {
+		self.as_slice().leap_seconds_before_inclusive(date_time)
+	}
+
+	/// Counts the leap seconds in this table between two times, in either
+	/// order. See [`leap_seconds_between`].
+	#[must_use]
+	pub fn leap_seconds_between(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> u32 {
+		self.as_slice().leap_seconds_between(start, end)
+	}
+
+	/// Counts the leap seconds in this table from `start` to `end`, which is
+	/// negative if `end` is before `start`. See
+	/// [`leap_seconds_between_signed`].
+	#[must_use]
+	pub fn leap_seconds_between_signed(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> i32 {
+		self.as_slice().leap_seconds_between_signed(start, end)
 	}
 
-	fn add_leap_second(&mut self, day: Date) {
+	/// Adds a leap second to the table, which ends as the given day begins.
+	/// If the leap second is already in the table, then nothing happens.
+	pub fn add_leap_second(&mut self, day: Date) {
 		let utc_datetime = NaiveDateTime::new(day, Time::MIDNIGHT);
 		let exact_time = DateTime::from_utc(utc_datetime, Utc);
 
-		let mut i = 0;
-		while i < self.0.len() {
-			match self.0[i].cmp(&exact_time) {
-				Ordering::Greater => break, // insert the new leap second here
-				Ordering::Equal => return,  // it's already here, so don't add it again
-				Ordering::Less => i += 1,   // check the next leap second
-			}
+		// if it's already here, then don't add it again
+		if let Err(i) = self.leap_seconds.binary_search(&exact_time) {
+			self.leap_seconds.insert(i, exact_time);
+		}
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl From<LeapSecondSlice<'_>> for LeapSecondTable {
+	fn from(slice: LeapSecondSlice<'_>) -> Self {
+		Self {
+			leap_seconds: slice.leap_seconds.to_vec(),
+			expiry: slice.expiry,
 		}
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl LeapSecondProvider for LeapSecondTable {
+	fn tai_offset_at_utc(&self, date_time: DateTime<Utc>) -> i32 {
+		self.as_slice().tai_offset_at_utc(date_time)
+	}
+
+	fn tai_offset_at_tai(&self, date_time: NaiveDateTime) -> i32 {
+		self.as_slice().tai_offset_at_tai(date_time)
+	}
+
+	fn expiry(&self) -> Option<DateTime<Utc>> {
+		self.expiry
+	}
+}
+
+/// Checks if `earlier` is the day immediately before `later`
+fn is_day_before(earlier: Date, later: Date) -> bool {
+	day_after(earlier) == Some(later)
+}
 
-		self.0.insert(i, exact_time);
+/// Gets the day after the given date, if it can be represented
+fn day_after(date: Date) -> Option<Date> {
+	if date.day() < date.month().days(date.is_leap_year()) {
+		return Some(Date::from_ymd_unchecked(
+			date.year(),
+			date.month(),
+			date.day() + 1,
+		));
 	}
+
+	let month = date.month().next();
+	let year = if month == Month::January {
+		date.year().checked_add(1)?
+	} else {
+		date.year()
+	};
+
+	Some(Date::from_ymd_unchecked(year, month, 1))
+}
+
+/// Checks if the given UTC time is during a leap second in the global table
+pub(crate) fn is_leap_second(date_time: NaiveDateTime) -> bool {
+	with_global_leap_seconds(|table| table.is_leap_second(date_time))
+}
+
+/// Gets the first leap second in the global table which ends after the given
+/// time. See [`LeapSecondTable::next_leap_second`].
+#[must_use]
+pub fn next_leap_second(after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+	with_global_leap_seconds(|table| table.next_leap_second(after))
+}
+
+/// Gets the last leap second in the global table which ended before the given
+/// time. See [`LeapSecondTable::previous_leap_second`].
+#[must_use]
+pub fn previous_leap_second(before: DateTime<Utc>) -> Option<DateTime<Utc>> {
+	with_global_leap_seconds(|table| table.previous_leap_second(before))
+}
+
+/// Counts the leap seconds in the global table between two times, in either
+/// order. This is useful for noting that an interval is a few seconds longer
+/// than the difference of its Unix timestamps.
+///
+/// A leap second is counted if it ends after the earlier time, and at or
+/// before the later time. So, a leap second is counted if the later time is
+/// the midnight immediately following it.
+///
+/// # Example
+///
+/// ```
+/// use botic::datetime;
+/// use botic::tai;
+///
+/// let start = datetime!(2016-12-31 23:00 UTC);
+/// let end = datetime!(2017-01-01 01:00 UTC);
+/// assert_eq!(tai::leap_seconds_between(start, end), 1);
+/// assert_eq!(tai::leap_seconds_between(end, start), 1);
+///
+/// let y2k = datetime!(2000-01-01 00:00 UTC);
+/// assert_eq!(tai::leap_seconds_between(y2k, end), 5);
+/// ```
+#[must_use]
+pub fn leap_seconds_between(start: DateTime<Utc>, end: DateTime<Utc>) -> u32 {
+	with_global_leap_seconds(|table| table.leap_seconds_between(start, end))
+}
+
+/// Counts the leap seconds in the global table from `start` to `end`, which
+/// is negative if `end` is before `start`. See [`leap_seconds_between`].
+///
+/// # Example
+///
+/// ```
+/// use botic::datetime;
+/// use botic::tai;
+///
+/// let start = datetime!(2016-12-31 23:00 UTC);
+/// let end = datetime!(2017-01-01 01:00 UTC);
+/// assert_eq!(tai::leap_seconds_between_signed(start, end), 1);
+/// assert_eq!(tai::leap_seconds_between_signed(end, start), -1);
+/// ```
+#[must_use]
+pub fn leap_seconds_between_signed(start: DateTime<Utc>, end: DateTime<Utc>) -> i32 {
+	with_global_leap_seconds(|table| table.leap_seconds_between_signed(start, end))
+}
+
+/// Finds a leap second from the provider which is within the given number of
+/// seconds of the given time, which must be less than a day. The returned time
+/// is the midnight following the leap second.
+pub(crate) fn leap_second_near<P: LeapSecondProvider + ?Sized>(
+	provider: &P,
+	date_time: DateTime<Utc>,
+	seconds: i64,
+) -> Option<DateTime<Utc>> {
+	let date = date_time.naive_utc().date();
+	let timestamp = date_time.unix_timestamp().total_seconds();
+
+	// leap seconds are always at the end of a UTC day
+	[date, date.add_days_overflowing(1).0]
+		.into_iter()
+		.map(|day| DateTime::from_utc(NaiveDateTime::new(day, Time::MIDNIGHT), Utc))
+		.filter(|midnight| (midnight.unix_timestamp().total_seconds() - timestamp).abs() <= seconds)
+		.find(|midnight| {
+			let before = midnight.naive_utc().add_seconds_overflowing(-1).0;
+			let before = DateTime::from_utc(before, Utc);
+			provider.tai_offset_at_utc(*midnight) != provider.tai_offset_at_utc(before)
+		})
 }
 
+/// Adds a leap second to the global table, which ends as the given day begins
+#[cfg(feature = "std")]
 pub fn add_leap_second(day: Date) {
-	let mut leap_seconds = GLOBAL_LEAP_SECONDS.write();
-	leap_seconds.add_leap_second(day);
+	global_leap_seconds().rcu(|table| {
+		let mut table = LeapSecondTable::clone(table);
+		table.add_leap_second(day);
+		table
+	});
+}
+
+/// Gets the time after which the global leap second table can no longer be
+/// trusted. See [`LeapSecondTable::expiry`].
+#[must_use]
+pub fn table_expiry() -> Option<DateTime<Utc>> {
+	with_global_leap_seconds(|table| table.expiry())
 }
 
+/// A leap second which the kernel has been told about
+#[cfg(feature = "std")]
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
-pub struct Tai;
+pub enum PendingLeapSecond {
+	/// A second will be inserted at the end of the current UTC day
+	Insert,
+	/// A second will be removed at the end of the current UTC day
+	Delete,
+}
+
+/// The leap second state of the system clock, as reported by the kernel
+#[cfg(feature = "std")]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct KernelLeapStatus {
+	tai_offset: Option<i32>,
+	pending: Option<PendingLeapSecond>,
+}
+
+#[cfg(feature = "std")]
+impl KernelLeapStatus {
+	/// The number of seconds TAI is ahead of UTC, if the kernel knows it.
+	/// This is usually set by an NTP daemon, such as chrony.
+	#[must_use]
+	pub const fn tai_offset(&self) -> Option<i32> {
+		self.tai_offset
+	}
+
+	/// The leap second at the end of the current day, if there is one
+	#[must_use]
+	pub const fn pending(&self) -> Option<PendingLeapSecond> {
+		self.pending
+	}
+}
+
+/// Reads the leap second state of the system clock. This is only supported
+/// on Linux, and returns `None` on other platforms.
+#[cfg(feature = "std")]
+#[must_use]
+pub fn kernel_leap_status() -> Option<KernelLeapStatus> {
+	let (tai_offset, pending) = sys::kernel_leap_state()?;
+	Some(KernelLeapStatus {
+		tai_offset: (tai_offset != 0).then_some(tai_offset),
+		pending: match pending {
+			1 => Some(PendingLeapSecond::Insert),
+			-1 => Some(PendingLeapSecond::Delete),
+			_ => None,
+		},
+	})
+}
+
+/// Adds the leap second announced to the kernel, if there is one, to the
+/// global leap second table. This lets programs on a system synchronized by
+/// NTP stay correct without a newer leap second list. Call this periodically,
+/// since the kernel is only told about a leap second shortly before it
+/// happens. Deleted leap seconds aren't supported, so they're ignored.
+///
+/// Returns the kernel's status, or `None` if it couldn't be read.
+#[cfg(feature = "std")]
+pub fn update_from_kernel() -> Option<KernelLeapStatus> {
+	let status = kernel_leap_status()?;
+	if status.pending == Some(PendingLeapSecond::Insert) {
+		let today = DateTime::<Utc>::now().naive_utc().date();
+		if let Some(tomorrow) = day_after(today) {
+			add_leap_second(tomorrow);
+		}
+	}
+
+	Some(status)
+}
+
+/// Gets a copy of the global leap second table
+#[cfg(feature = "alloc")]
+#[must_use]
+pub fn leap_second_table() -> LeapSecondTable {
+	with_global_leap_seconds(|table| table.into())
+}
+
+/// Replaces the global leap second table
+#[cfg(feature = "std")]
+pub fn set_leap_seconds(table: LeapSecondTable) {
+	global_leap_seconds().store(Arc::new(table));
+}
+
+/// The number of seconds between the NTP epoch (1900) and the Unix epoch (1970)
+const NTP_UNIX_OFFSET: i64 = 2_208_988_800;
+
+/// The start of 1972, as an NTP timestamp
+const LEAP_SECONDS_START_NTP: i64 = 2_272_060_800;
+
+/// The first bytes of a binary leap second table
+#[cfg(feature = "alloc")]
+const BINARY_MAGIC: [u8; 4] = *b"BLST";
+
+/// Converts a Unix timestamp to a UTC date and time
+#[cfg(feature = "alloc")]
+fn utc_from_unix(seconds: i64) -> DateTime<Utc> {
+	let naive = NaiveDateTime::from_timestamp(Timestamp::new(seconds, 0));
+	DateTime::from_utc(naive, Utc)
+}
+
+#[cfg(feature = "alloc")]
+#[derive(Debug, Error)]
+pub enum LoadLeapSecondsError {
+	#[cfg(feature = "std")]
+	#[error("Failed to read the leap second file: {0}")]
+	Io(#[from] std::io::Error),
+	#[error("Line {0} of the leap second list is invalid")]
+	InvalidLine(usize),
+	#[error("The leap second list must start with TAI-UTC = 10 in 1972")]
+	InvalidStart,
+	#[error("The TZif file is invalid")]
+	InvalidTzif,
+	#[error("Negative leap seconds are not supported")]
+	NegativeLeapSecond,
+	#[error("The file does not contain any leap seconds")]
+	NoLeapSeconds,
+	#[error("The binary leap second table is invalid")]
+	InvalidBinary,
+}
+
+/// Parses a `leap-seconds.list` file, as published by the IERS and NIST.
+/// Returns the Unix timestamp of the midnight following each leap second.
+#[cfg(feature = "alloc")]
+fn parse_leap_seconds_list(list: &str) -> Result<Vec<i64>, LoadLeapSecondsError> {
+	let mut midnights = Vec::new();
+	let mut previous_offset = None;
+
+	for (i, line) in list.lines().enumerate() {
+		let data = line.split('#').next().unwrap_or_default();
+		let mut fields = data.split_whitespace();
+		let Some(ntp_seconds) = fields.next() else {
+			continue;
+		};
+
+		let line_number = i + 1;
+		let ntp_seconds: i64 = ntp_seconds
+			.parse()
+			.map_err(|_| LoadLeapSecondsError::InvalidLine(line_number))?;
+		let tai_offset: i64 = fields
+			.next()
+			.and_then(|offset| offset.parse().ok())
+			.ok_or(LoadLeapSecondsError::InvalidLine(line_number))?;
+		if fields.next().is_some() {
+			return Err(LoadLeapSecondsError::InvalidLine(line_number));
+		}
+
+		match previous_offset {
+			None if tai_offset != 10 => return Err(LoadLeapSecondsError::InvalidStart),
+			None => (),
+			Some(previous) if tai_offset == previous + 1 => {
+				midnights.push(ntp_seconds - NTP_UNIX_OFFSET);
+			}
+			Some(previous) if tai_offset < previous => {
+				return Err(LoadLeapSecondsError::NegativeLeapSecond)
+			}
+			Some(_) => return Err(LoadLeapSecondsError::InvalidLine(line_number)),
+		}
+
+		previous_offset = Some(tai_offset);
+	}
+
+	Ok(midnights)
+}
+
+/// Finds the expiration date of a `leap-seconds.list` file, as a Unix timestamp
+#[cfg(feature = "alloc")]
+fn parse_leap_seconds_list_expiry(list: &str) -> Option<i64> {
+	list.lines()
+		.find_map(|line| line.strip_prefix("#@"))
+		.and_then(|expiry| expiry.trim().parse::<i64>().ok())
+		.map(|ntp_seconds| ntp_seconds - NTP_UNIX_OFFSET)
+}
+
+/// Parses the leap second records of a TZif file, such as the ones in the
+/// `right/` zoneinfo directory. Returns the Unix timestamp of the midnight
+/// following each leap second.
+#[cfg(feature = "std")]
+fn parse_tzif_leap_seconds(data: &[u8]) -> Result<Vec<i64>, LoadLeapSecondsError> {
+	fn read_u32(data: &[u8], at: usize) -> Option<u32> {
+		Some(u32::from_be_bytes(data.get(at..at + 4)?.try_into().ok()?))
+	}
+
+	fn read_i64(data: &[u8], at: usize) -> Option<i64> {
+		Some(i64::from_be_bytes(data.get(at..at + 8)?.try_into().ok()?))
+	}
+
+	/// Reads the header, returning the length of the data block which follows it,
+	/// and the position and number of the leap second records
+	fn read_header(data: &[u8], time_size: usize) -> Option<(usize, usize, usize)> {
+		if data.get(..4)? != b"TZif" {
+			return None;
+		}
+
+		let count = |i: usize| read_u32(data, 20 + 4 * i).map(|count| count as usize);
+		let (isut, isstd, leap, time, kind, chars) = (
+			count(0)?,
+			count(1)?,
+			count(2)?,
+			count(3)?,
+			count(4)?,
+			count(5)?,
+		);
+		let leap_start = 44 + time * (time_size + 1) + kind * 6 + chars;
+		let length = leap_start + leap * (time_size + 4) + isstd + isut;
+
+		Some((length, leap_start, leap))
+	}
+
+	use LoadLeapSecondsError::InvalidTzif;
+
+	let (v1_length, mut leap_start, mut leap_count) = read_header(data, 4).ok_or(InvalidTzif)?;
+	let mut data = data;
+	let mut time_size = 4;
+
+	// version 2 and later files repeat the data with 64-bit times
+	if *data.get(4).ok_or(InvalidTzif)? >= b'2' {
+		data = &data[v1_length..];
+		time_size = 8;
+		(_, leap_start, leap_count) = read_header(data, time_size).ok_or(InvalidTzif)?;
+	}
+
+	let mut midnights = Vec::with_capacity(leap_count);
+	let mut previous_correction = 0;
+	for i in 0..leap_count {
+		let at = leap_start + i * (time_size + 4);
+		let occurrence = if time_size == 8 {
+			read_i64(data, at)
+		} else {
+			read_u32(data, at).map(|time| i64::from(time as i32))
+		}
+		.ok_or(InvalidTzif)?;
+		let correction = read_u32(data, at + time_size).ok_or(InvalidTzif)? as i32;
+
+		// the occurrence includes all of the previous leap seconds
+		match correction - previous_correction {
+			1 => midnights.push(occurrence - i64::from(previous_correction)),
+			// newer files may end with a record which only marks the expiration
+			0 => (),
+			_ => return Err(LoadLeapSecondsError::NegativeLeapSecond),
+		}
+
+		previous_correction = correction;
+	}
+
+	Ok(midnights)
+}
+
+/// Replaces the global leap second table with the leap seconds in the given file.
+///
+/// The file may either be a `leap-seconds.list` file, as published by the
+/// IERS and NIST, or a TZif file from the `right/` zoneinfo directory. On many
+/// Unix systems, these can be found at `/usr/share/zoneinfo/leap-seconds.list`
+/// and `/usr/share/zoneinfo/right/UTC`.
+///
+/// # Errors
+///
+/// Returns an error if the file can't be read, if it isn't in one of the above
+/// formats, or if it doesn't contain any leap seconds. If this fails, then the
+/// leap second table is left unchanged.
+#[cfg(feature = "std")]
+pub fn load_leap_seconds_from_file(path: impl AsRef<Path>) -> Result<(), LoadLeapSecondsError> {
+	set_leap_seconds(LeapSecondTable::from_file(path)?);
+	Ok(())
+}
+
+/// Where [`fetch_leap_seconds`] downloads the leap second list from
+#[cfg(feature = "fetch")]
+pub const LEAP_SECONDS_URL: &str = "https://data.iana.org/time-zones/tzdb/leap-seconds.list";
+
+#[cfg(feature = "fetch")]
+#[derive(Debug, Error)]
+pub enum FetchLeapSecondsError {
+	#[error("Failed to download the leap second list: {0}")]
+	Http(Box<ureq::Error>),
+	#[error("Failed to read the leap second list: {0}")]
+	Io(#[from] std::io::Error),
+	#[error("The leap second list does not have a hash")]
+	MissingHash,
+	#[error("The hash of the leap second list does not match its contents")]
+	HashMismatch,
+	#[error("The leap second list does not have an expiration date")]
+	MissingExpiration,
+	#[error("The leap second list has expired")]
+	Expired,
+	#[error("{0}")]
+	Invalid(#[from] LoadLeapSecondsError),
+}
+
+/// Checks the SHA-1 hash and the expiration date of a `leap-seconds.list` file
+#[cfg(feature = "fetch")]
+fn verify_leap_seconds_list(list: &str, unix_now: i64) -> Result<(), FetchLeapSecondsError> {
+	let mut updated = "";
+	let mut expires = "";
+	let mut expected_hash = None;
+	let mut hasher = sha1_smol::Sha1::new();
+	let mut data = String::new();
+
+	for line in list.lines() {
+		if let Some(value) = line.strip_prefix("#$") {
+			updated = value.trim();
+		} else if let Some(value) = line.strip_prefix("#@") {
+			expires = value.trim();
+		} else if let Some(hash) = line.strip_prefix("#h") {
+			// leading zeros are sometimes left out of each word
+			let words: Option<Vec<u32>> = hash
+				.split_whitespace()
+				.map(|word| u32::from_str_radix(word, 16).ok())
+				.collect();
+			expected_hash = words.filter(|words| words.len() == 5);
+		} else if !line.starts_with('#') {
+			let fields = line.split('#').next().unwrap_or_default();
+			for field in fields.split_whitespace().take(2) {
+				data.push_str(field);
+			}
+		}
+	}
+
+	// the hash covers the update time, then the expiration, then the data
+	hasher.update(updated.as_bytes());
+	hasher.update(expires.as_bytes());
+	hasher.update(data.as_bytes());
+
+	let expected_hash = expected_hash.ok_or(FetchLeapSecondsError::MissingHash)?;
+	let hash = hasher.digest().bytes();
+	let matches = hash.chunks(4).zip(expected_hash).all(|(word, expected)| {
+		u32::from_be_bytes([word[0], word[1], word[2], word[3]]) == expected
+	});
+	if !matches {
+		return Err(FetchLeapSecondsError::HashMismatch);
+	}
+
+	let expiration: i64 = expires
+		.parse()
+		.map_err(|_| FetchLeapSecondsError::MissingExpiration)?;
+	if expiration - NTP_UNIX_OFFSET <= unix_now {
+		return Err(FetchLeapSecondsError::Expired);
+	}
+
+	Ok(())
+}
+
+/// Downloads the leap second list from [`LEAP_SECONDS_URL`], and replaces the
+/// global leap second table with it. See [`fetch_leap_seconds_from`] for details.
+///
+/// # Errors
+///
+/// Returns an error if the list can't be downloaded or verified
+#[cfg(feature = "fetch")]
+pub fn fetch_leap_seconds() -> Result<(), FetchLeapSecondsError> {
+	fetch_leap_seconds_from(LEAP_SECONDS_URL)
+}
+
+/// Downloads a `leap-seconds.list` file from the given URL, and replaces the
+/// global leap second table with it.
+///
+/// The file is only used if its SHA-1 hash matches the hash written inside of
+/// it, and if it hasn't expired yet. This blocks until the download finishes.
+///
+/// # Errors
+///
+/// Returns an error if the list can't be downloaded, if its hash doesn't
+/// match, if it has expired, or if it's invalid. If this fails, then the leap
+/// second table is left unchanged.
+#[cfg(feature = "fetch")]
+pub fn fetch_leap_seconds_from(url: &str) -> Result<(), FetchLeapSecondsError> {
+	let list = ureq::get(url)
+		.call()
+		.map_err(|e| FetchLeapSecondsError::Http(Box::new(e)))?
+		.into_string()?;
+
+	let unix_now = DateTime::<Utc>::now().unix_timestamp().total_seconds();
+	verify_leap_seconds_list(&list, unix_now)?;
+	set_leap_seconds(LeapSecondTable::from_leap_seconds_list(&list)?);
+	Ok(())
+}
+
+/// Downloads the leap second list from [`LEAP_SECONDS_URL`] without blocking
+/// the async executor. See [`fetch_leap_seconds_from`] for details.
+///
+/// # Errors
+///
+/// Returns an error if the list can't be downloaded or verified
+#[cfg(all(
+	feature = "fetch",
+	any(feature = "tokio", feature = "async-std", feature = "smol")
+))]
+pub async fn fetch_leap_seconds_async() -> Result<(), FetchLeapSecondsError> {
+	fetch_leap_seconds_from_async(LEAP_SECONDS_URL).await
+}
+
+/// Downloads a `leap-seconds.list` file from the given URL without blocking
+/// the async executor, and replaces the global leap second table with it.
+/// See [`fetch_leap_seconds_from`] for details.
+///
+/// The download runs on its own thread, so this works with any executor.
+///
+/// # Errors
+///
+/// Returns an error if the list can't be downloaded, if its hash doesn't
+/// match, if it has expired, or if it's invalid. If this fails, then the leap
+/// second table is left unchanged.
+#[cfg(all(
+	feature = "fetch",
+	any(feature = "tokio", feature = "async-std", feature = "smol")
+))]
+pub async fn fetch_leap_seconds_from_async(url: &str) -> Result<(), FetchLeapSecondsError> {
+	let url = url.to_owned();
+	BlockingTask::spawn(move || fetch_leap_seconds_from(&url)).await
+}
+
+/// A future which runs a blocking function on a new thread
+#[cfg(all(
+	feature = "fetch",
+	any(feature = "tokio", feature = "async-std", feature = "smol")
+))]
+struct BlockingTask<T> {
+	state: Arc<parking_lot::Mutex<BlockingTaskState<T>>>,
+}
+
+#[cfg(all(
+	feature = "fetch",
+	any(feature = "tokio", feature = "async-std", feature = "smol")
+))]
+struct BlockingTaskState<T> {
+	result: Option<std::thread::Result<T>>,
+	waker: Option<core::task::Waker>,
+}
+
+#[cfg(all(
+	feature = "fetch",
+	any(feature = "tokio", feature = "async-std", feature = "smol")
+))]
+impl<T: Send + 'static> BlockingTask<T> {
+	fn spawn(f: impl FnOnce() -> T + Send + 'static) -> Self {
+		let state = Arc::new(parking_lot::Mutex::new(BlockingTaskState {
+			result: None,
+			waker: None,
+		}));
+
+		let thread_state = Arc::clone(&state);
+		std::thread::spawn(move || {
+			// a panic is sent to the task, so that it isn't left waiting
+			let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(f));
+			let mut state = thread_state.lock();
+			state.result = Some(result);
+			if let Some(waker) = state.waker.take() {
+				waker.wake();
+			}
+		});
+
+		Self { state }
+	}
+}
+
+#[cfg(all(
+	feature = "fetch",
+	any(feature = "tokio", feature = "async-std", feature = "smol")
+))]
+impl<T> core::future::Future for BlockingTask<T> {
+	type Output = T;
+
+	fn poll(
+		self: core::pin::Pin<&mut Self>,
+		cx: &mut core::task::Context<'_>,
+	) -> core::task::Poll<T> {
+		let mut state = self.state.lock();
+		match state.result.take() {
+			Some(Ok(value)) => core::task::Poll::Ready(value),
+			Some(Err(panic)) => std::panic::resume_unwind(panic),
+			None => {
+				state.waker = Some(cx.waker().clone());
+				core::task::Poll::Pending
+			}
+		}
+	}
+}
+
+/// International Atomic Time, which doesn't have leap seconds.
+///
+/// The offset from UTC is determined by the leap seconds from a
+/// [`LeapSecondProvider`]. By default, the global leap second table is used.
+///
+/// # Before 1972
+///
+/// Before 1972, TAI-UTC drifted by fractions of a second, which can't be
+/// represented by a [`UtcOffset`]. The [`TimeZone`] implementation doesn't
+/// report an error for those times. Instead, they're treated as if TAI-UTC was
+/// ten seconds, as it was at the start of 1972, so conversions before 1972
+/// are off by up to ten seconds. Use [`Tai::checked_utc_offset`] to reject
+/// them instead.
+///
+/// ```
+/// use botic::tai::{Tai, UnknownTaiOffsetError};
+/// use botic::{datetime, TimeZone};
+///
+/// let tai = Tai::new();
+/// let moon_landing = datetime!(1969-07-20 20:17 UTC);
+/// assert_eq!(tai.utc_offset(moon_landing).seconds_ahead(), 10);
+/// assert_eq!(
+///     tai.checked_utc_offset(moon_landing),
+///     Err(UnknownTaiOffsetError::BeforeLeapSeconds)
+/// );
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct Tai<P: LeapSecondProvider = GlobalLeapSeconds> {
+	provider: P,
+}
+
+impl Tai {
+	/// TAI, using the global leap second table
+	#[must_use]
+	pub const fn new() -> Self {
+		Self {
+			provider: GlobalLeapSeconds,
+		}
+	}
+}
+
+impl<P: LeapSecondProvider> Tai<P> {
+	/// TAI, using leap seconds from the given provider
+	#[must_use]
+	pub const fn with_provider(provider: P) -> Self {
+		Self { provider }
+	}
+
+	/// Gets the source of leap seconds used by this time zone
+	#[must_use]
+	pub const fn provider(&self) -> &P {
+		&self.provider
+	}
+
+	/// Converts a date and time in TAI to UTC.
+	///
+	/// Unlike [`DateTime::from_local`], this can give a leap second. During a
+	/// leap second, TAI keeps counting while UTC is at 23:59:60, so no single
+	/// offset from UTC gives the right time.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use botic::tai::Tai;
+	/// use botic::datetime;
+	///
+	/// let tai = Tai::new();
+	/// let utc = tai.to_utc(datetime!(2017-01-01 00:00:36.5)).unwrap();
+	/// assert_eq!(utc.naive_utc(), datetime!(2016-12-31 23:59:60.5));
+	/// ```
+	///
+	/// # Errors
+	///
+	/// Returns an error if the date and time in UTC is out of range
+	pub fn to_utc(&self, tai: NaiveDateTime) -> Result<DateTime<Utc>, OutOfRange> {
+		let offset = self.provider.tai_offset_at_tai(tai);
+		let (utc, overflow) = tai.add_seconds_overflowing(-i64::from(offset));
+		if overflow {
+			return Err(OutOfRange);
+		}
+
+		// the offset changes when the leap second ends, so a change within
+		// the next second means that this is during the leap second
+		let next_second = tai.add_seconds_overflowing(1).0;
+		if self.provider.tai_offset_at_tai(next_second) > offset {
+			let before = utc.add_seconds_overflowing(-1).0;
+			let time = Time::from_hms_nano_unchecked(23, 59, 60, before.nanosecond());
+			return Ok(DateTime::from_utc(
+				NaiveDateTime::new(before.date(), time),
+				Utc,
+			));
+		}
+
+		Ok(DateTime::from_utc(utc, Utc))
+	}
+
+	/// Creates a date and time from a local date and time in TAI. TAI doesn't
+	/// have leap seconds, so the policy decides what to do with a time at
+	/// 23:59:60, such as one which was copied from a UTC clock.
+	///
+	/// # Errors
+	///
+	/// Returns an error if the second is 60, and the policy is
+	/// [`LeapSecondPolicy::Error`]
+	pub fn from_local_with_policy(
+		&self,
+		local: NaiveDateTime,
+		policy: LeapSecondPolicy,
+	) -> Result<DateTime<Self>, UnexpectedLeapSecond>
+	where
+		P: Clone + Eq,
+	{
+		let local = if local.second() == 60 {
+			policy.resolve_with(&self.provider, DateTime::from_utc(local, Utc))?
+		} else {
+			local
+		};
+
+		DateTime::from_local(local, self.clone())
+	}
+
+	/// Gets the offset from UTC at the given time, as long as the leap seconds
+	/// at that time are known. Use this instead of [`TimeZone::utc_offset`]
+	/// when speculative results aren't acceptable.
+	///
+	/// # Errors
+	///
+	/// Returns an error if the time is before 1972, or after the provider's
+	/// expiration date
+	pub fn checked_utc_offset(
+		&self,
+		date_time: DateTime<Utc>,
+	) -> Result<UtcOffset, UnknownTaiOffsetError> {
+		if date_time.naive_utc() < LEAP_SECONDS_START {
+			return Err(UnknownTaiOffsetError::BeforeLeapSeconds);
+		}
+
+		if let Some(expiry) = self.provider.expiry() {
+			if date_time > expiry {
+				return Err(UnknownTaiOffsetError::Expired(expiry));
+			}
+		}
+
+		Ok(UtcOffset::from_seconds_unchecked(
+			self.provider.tai_offset_at_utc(date_time),
+		))
+	}
+}
+
+/// The difference between TAI and UTC isn't known for a time
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Error)]
+pub enum UnknownTaiOffsetError {
+	/// Before 1972, UTC seconds were slightly different in length from TAI
+	/// seconds, and UTC was adjusted by fractions of a second. This isn't
+	/// supported.
+	#[error("TAI-UTC is not supported before 1972")]
+	BeforeLeapSeconds,
+	/// The time is after the expiration date of the leap second table, so a
+	/// leap second may have been announced since it was published
+	#[error("the leap second table expired at {0}")]
+	Expired(DateTime<Utc>),
+}
 
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Error)]
 #[error(
@@ -67,22 +1485,124 @@ pub struct UnexpectedLeapSecond {
 	given_dt: NaiveDateTime,
 }
 
-impl Display for Tai {
+/// A time was at 23:59:60, but there wasn't a leap second at the end of that
+/// day, according to the leap second table
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Error)]
+#[error("There was no leap second at {date_time}")]
+pub struct InvalidLeapSecond {
+	date_time: NaiveDateTime,
+}
+
+impl InvalidLeapSecond {
+	pub(crate) const fn new(date_time: NaiveDateTime) -> Self {
+		Self { date_time }
+	}
+
+	/// The date and time which was given
+	#[must_use]
+	pub const fn date_time(&self) -> NaiveDateTime {
+		self.date_time
+	}
+}
+
+/// What to do with a leap second when converting a UTC time into a type which
+/// can't represent leap seconds, such as a Unix timestamp
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub enum LeapSecondPolicy {
+	/// Return an [`UnexpectedLeapSecond`] error
+	#[default]
+	Error,
+	/// Use the last nanosecond before the leap second, 23:59:59.999999999
+	ClampToEndOfMinute,
+	/// Spread the leap second across the surrounding 24 hours, as
+	/// [`SmearedUtc`] does. This changes every time
+	/// within twelve hours of a leap second, not just the leap second itself.
+	Smear,
+	/// Count the leap second like any other second, so 23:59:60.5 becomes
+	/// 00:00:00.5 on the next day. The second after the leap second is then
+	/// repeated, which is how Unix timestamps behave.
+	CountInTai,
+}
+
+impl LeapSecondPolicy {
+	/// Converts a UTC time into a date and time without leap seconds, using
+	/// the global leap second table for [`LeapSecondPolicy::Smear`]
+	///
+	/// # Errors
+	///
+	/// Returns an error if the time is a leap second, and the policy is
+	/// [`LeapSecondPolicy::Error`]
+	pub fn resolve(self, date_time: DateTime<Utc>) -> Result<NaiveDateTime, UnexpectedLeapSecond> {
+		self.resolve_with(&GlobalLeapSeconds, date_time)
+	}
+
+	/// Converts a UTC time into a date and time without leap seconds, using
+	/// leap seconds from the given provider for [`LeapSecondPolicy::Smear`]
+	///
+	/// # Errors
+	///
+	/// Returns an error if the time is a leap second, and the policy is
+	/// [`LeapSecondPolicy::Error`]
+	pub fn resolve_with<P: LeapSecondProvider + ?Sized>(
+		self,
+		provider: &P,
+		date_time: DateTime<Utc>,
+	) -> Result<NaiveDateTime, UnexpectedLeapSecond> {
+		let naive = date_time.naive_utc();
+		match self {
+			// the smear also changes the times around the leap second
+			Self::Smear => Ok(SmearedUtc::to_smeared_with(provider, date_time)),
+			_ if naive.second() != 60 => Ok(naive),
+			Self::Error => Err(UnexpectedLeapSecond { given_dt: naive }),
+			Self::ClampToEndOfMinute => {
+				let time =
+					Time::from_hms_nano_unchecked(naive.hour(), naive.minute(), 59, 999_999_999);
+				Ok(NaiveDateTime::new(naive.date(), time))
+			}
+			Self::CountInTai => {
+				let timestamp = naive.timestamp();
+				Ok(NaiveDateTime::from_timestamp(Timestamp::new(
+					timestamp.total_seconds() + 1,
+					timestamp.nanosecond() - 1_000_000_000,
+				)))
+			}
+		}
+	}
+}
+
+impl<P: LeapSecondProvider> Display for Tai<P> {
 	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
 		write!(f, "TAI")
 	}
 }
 
-impl TimeZone for Tai {
+impl<P: LeapSecondProvider + Default> FromStr for Tai<P> {
+	type Err = ParseTimeZoneError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s {
+			"TAI" => Ok(Self::default()),
+			_ => Err(ParseTimeZoneError),
+		}
+	}
+}
+
+impl<P: LeapSecondProvider + Eq> TimeZone for Tai<P> {
 	type Err = UnexpectedLeapSecond;
 
+	/// Gets TAI-UTC at the given time. Before 1972, this is always ten
+	/// seconds, which isn't accurate. See [`Tai::checked_utc_offset`].
 	fn utc_offset(&self, date_time: DateTime<Utc>) -> UtcOffset {
-		let leap_seconds = GLOBAL_LEAP_SECONDS.read();
-		let past_leap_seconds = leap_seconds.leap_seconds_before_inclusive(date_time);
-		UtcOffset::from_seconds(-(past_leap_seconds as i32 + 10))
+		UtcOffset::from_seconds_unchecked(self.provider.tai_offset_at_utc(date_time))
 	}
 
-	// TODO optimize
+	/// Gets TAI-UTC at the given time in TAI. Before 1972, this is always ten
+	/// seconds, which isn't accurate. See [`Tai::checked_utc_offset`].
+	///
+	/// # Errors
+	///
+	/// Returns an error if the second is 60, since TAI doesn't have leap
+	/// seconds
 	fn offset_from_local_naive(&self, date_time: NaiveDateTime) -> Result<UtcOffset, Self::Err> {
 		// TAI times cannot have leap seconds
 		if date_time.second() == 60 {
@@ -91,23 +1611,91 @@ impl TimeZone for Tai {
 			});
 		}
 
-		// calculate the number of seconds that have passed since date_time in UTC
-		let leap_seconds = GLOBAL_LEAP_SECONDS.read();
-		let utc_dt = DateTime::from_utc(date_time, Utc);
-		let mut past_leap_seconds = dbg!(leap_seconds.leap_seconds_before_inclusive(utc_dt));
-		let mut prev_pls = 0; // use this to see if the number of leap seconds has been updated
+		Ok(UtcOffset::from_seconds_unchecked(
+			self.provider.tai_offset_at_tai(date_time),
+		))
+	}
+
+	#[cfg(feature = "alloc")]
+	fn name(&self) -> Cow<'_, str> {
+		Cow::Borrowed("TAI")
+	}
+}
+
+/// The number of nanoseconds that Terrestrial Time is ahead of TAI
+const TT_TAI_OFFSET_NANOS: i64 = 32_184_000_000;
 
-		// check if any leap seconds were found because of this calculation
-		// keep checking until there is no longer a change in the total leap seconds
-		while past_leap_seconds != prev_pls {
-			prev_pls = past_leap_seconds;
-			// TODO think about this discard
-			let (ndt, _) = dbg!(date_time.add_seconds_overflowing(past_leap_seconds as i64));
-			let utc_dt = DateTime::from_utc(ndt, Utc);
-			past_leap_seconds = dbg!(leap_seconds.leap_seconds_before_inclusive(utc_dt));
+/// Adds nanoseconds to a timestamp, carrying into the seconds
+fn add_nanoseconds(timestamp: Timestamp, nanoseconds: i64) -> Timestamp {
+	let total_nanos = i64::from(timestamp.nanosecond()) + nanoseconds;
+	Timestamp::new(
+		timestamp.total_seconds() + total_nanos.div_euclid(1_000_000_000),
+		total_nanos.rem_euclid(1_000_000_000) as u32,
+	)
+}
+
+/// Terrestrial Time, which is used for ephemeris calculations. TT is always
+/// exactly 32.184 seconds ahead of TAI.
+///
+/// Because that isn't a whole number of seconds, TT can't be a [`TimeZone`].
+/// Instead, times are converted to and from the number of seconds since
+/// 1970-01-01 00:00:00 TT.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct Tt<P: LeapSecondProvider = GlobalLeapSeconds> {
+	tai: Tai<P>,
+}
+
+impl Tt {
+	/// TT, using the global leap second table
+	#[must_use]
+	pub const fn new() -> Self {
+		Self { tai: Tai::new() }
+	}
+}
+
+impl<P: LeapSecondProvider> Tt<P> {
+	/// TT, using leap seconds from the given provider
+	#[must_use]
+	pub const fn with_provider(provider: P) -> Self {
+		Self {
+			tai: Tai::with_provider(provider),
 		}
+	}
+
+	/// Gets the source of leap seconds used to convert from UTC
+	#[must_use]
+	pub const fn provider(&self) -> &P {
+		self.tai.provider()
+	}
+
+	/// Gets the number of seconds since 1970-01-01 00:00:00 TT at the given time
+	#[must_use]
+	pub fn timestamp<Tz: TimeZone>(&self, date_time: &DateTime<Tz>) -> Timestamp {
+		let tai_offset = self.provider().tai_offset_at_utc(date_time.as_utc());
+		let unix_timestamp = date_time.unix_timestamp();
+		let tai_timestamp = Timestamp::new(
+			unix_timestamp.total_seconds() + i64::from(tai_offset),
+			unix_timestamp.nanosecond(),
+		);
+
+		add_nanoseconds(tai_timestamp, TT_TAI_OFFSET_NANOS)
+	}
+
+	/// Converts a number of seconds since 1970-01-01 00:00:00 TT to UTC. This
+	/// gives 23:59:60 during a leap second.
+	///
+	/// # Errors
+	///
+	/// Returns an error if the date and time in UTC is out of range
+	pub fn from_timestamp(&self, timestamp: Timestamp) -> Result<DateTime<Utc>, OutOfRange> {
+		let tai_timestamp = add_nanoseconds(timestamp, -TT_TAI_OFFSET_NANOS);
+		self.tai.to_utc(NaiveDateTime::try_from(tai_timestamp)?)
+	}
+}
 
-		Ok(UtcOffset::from_seconds(-(past_leap_seconds as i32 + 10)))
+impl<P: LeapSecondProvider> Display for Tt<P> {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(f, "TT")
 	}
 }
 
@@ -117,30 +1705,624 @@ mod tests {
 
 	use super::*;
 
+	#[cfg(feature = "alloc")]
 	#[test]
 	fn test_conversion_no_leap_seconds() {
-		let offset = unsafe {
-			Tai.offset_from_local_naive(NaiveDateTime::new(
+		let table = LeapSecondTable::empty();
+		let offset = Tai::with_provider(&table)
+			.offset_from_local_naive(NaiveDateTime::new(
 				Date::from_ymd_unchecked(2000.into(), Month::January, 1),
 				Time::from_hms_unchecked(0, 0, 0),
 			))
-			.unwrap()
-		};
+			.unwrap();
 
-		assert_eq!(offset, UtcOffset::from_seconds(-10));
+		assert_eq!(offset, UtcOffset::from_seconds_unchecked(10));
 	}
 
+	#[cfg(feature = "alloc")]
 	#[test]
 	fn test_conversion_one_leap_second() {
-		add_leap_second(unsafe { Date::from_ymd_unchecked(2000.into(), Month::January, 1) });
-		let offset = unsafe {
-			Tai.offset_from_local_naive(NaiveDateTime::new(
+		let mut table = LeapSecondTable::empty();
+		table.add_leap_second(Date::from_ymd_unchecked(2000.into(), Month::January, 1));
+		let offset = Tai::with_provider(&table)
+			.offset_from_local_naive(NaiveDateTime::new(
 				Date::from_ymd_unchecked(2000.into(), Month::January, 2),
 				Time::from_hms_unchecked(0, 0, 0),
 			))
-			.unwrap()
+			.unwrap();
+
+		assert_eq!(offset, UtcOffset::from_seconds_unchecked(11));
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn test_offset_at_tai_around_leap_second() {
+		let day = Date::from_ymd(2000.into(), Month::January, 1).unwrap();
+		let mut table = LeapSecondTable::empty();
+		table.add_leap_second(day);
+
+		let at = |second| NaiveDateTime::new(day, Time::from_hms(0, 0, second).unwrap());
+		assert_eq!(table.tai_offset_at_tai(at(10)), 10);
+		assert_eq!(table.tai_offset_at_tai(at(11)), 11);
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn test_to_utc_around_leap_second() {
+		let midnight = Date::from_ymd(2017.into(), Month::January, 1).unwrap();
+		let mut table = LeapSecondTable::empty();
+		table.add_leap_second(midnight);
+		let tai = Tai::with_provider(&table);
+
+		let to_utc = |second, nanosecond| {
+			let time = Time::from_hms_nano(0, 0, second, nanosecond).unwrap();
+			tai.to_utc(NaiveDateTime::new(midnight, time))
+				.unwrap()
+				.naive_utc()
+		};
+		let last_day = midnight.add_days_overflowing(-1).0;
+		let utc = |second, nanosecond| {
+			let time = Time::from_hms_nano(23, 59, second, nanosecond).unwrap();
+			NaiveDateTime::new(last_day, time)
+		};
+
+		// the table has one leap second, so TAI is eleven seconds ahead
+		// afterwards, and ten seconds ahead before
+		assert_eq!(to_utc(9, 500_000_000), utc(59, 500_000_000));
+		assert_eq!(to_utc(10, 0), utc(60, 0));
+		assert_eq!(to_utc(10, 999_999_999), utc(60, 999_999_999));
+		assert_eq!(to_utc(11, 0), NaiveDateTime::new(midnight, Time::MIDNIGHT));
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn test_is_leap_second() {
+		let mut table = LeapSecondTable::empty();
+		table.add_leap_second(Date::from_ymd(2017.into(), Month::January, 1).unwrap());
+		table.add_leap_second(Date::from_ymd(2012.into(), Month::July, 1).unwrap());
+
+		let at = |year: i16, month, day, second| {
+			let date = Date::from_ymd(year.into(), month, day).unwrap();
+			NaiveDateTime::new(date, Time::from_hms(23, 59, second).unwrap())
+		};
+		assert!(table.is_leap_second(at(2016, Month::December, 31, 60)));
+		assert!(table.is_leap_second(at(2012, Month::June, 30, 60)));
+		assert!(!table.is_leap_second(at(2016, Month::December, 31, 59)));
+		assert!(!table.is_leap_second(at(2015, Month::December, 31, 60)));
+		assert!(!table.is_leap_second(at(2012, Month::June, 29, 60)));
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn test_next_and_previous_leap_second() {
+		let midnight = |year: i16, month| {
+			let day = Date::from_ymd(year.into(), month, 1).unwrap();
+			DateTime::from_utc(NaiveDateTime::new(day, Time::MIDNIGHT), Utc)
+		};
+		let mut table = LeapSecondTable::empty();
+		table.add_leap_second(midnight(2012, Month::July).naive_utc().date());
+		table.add_leap_second(midnight(2015, Month::July).naive_utc().date());
+
+		let between = midnight(2014, Month::January);
+		assert_eq!(
+			table.next_leap_second(between),
+			Some(midnight(2015, Month::July))
+		);
+		assert_eq!(
+			table.previous_leap_second(between),
+			Some(midnight(2012, Month::July))
+		);
+
+		let at = midnight(2015, Month::July);
+		assert_eq!(table.next_leap_second(at), None);
+		assert_eq!(
+			table.previous_leap_second(at),
+			Some(midnight(2012, Month::July))
+		);
+		assert_eq!(
+			table.previous_leap_second(midnight(2012, Month::July)),
+			None
+		);
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn test_leap_seconds_between() {
+		let midnight = |year: i16, month| {
+			let day = Date::from_ymd(year.into(), month, 1).unwrap();
+			DateTime::from_utc(NaiveDateTime::new(day, Time::MIDNIGHT), Utc)
+		};
+		let mut table = LeapSecondTable::empty();
+		table.add_leap_second(midnight(2012, Month::July).naive_utc().date());
+		table.add_leap_second(midnight(2015, Month::July).naive_utc().date());
+
+		let (start, end) = (
+			midnight(2012, Month::January),
+			midnight(2016, Month::January),
+		);
+		assert_eq!(table.leap_seconds_between(start, end), 2);
+		assert_eq!(table.leap_seconds_between(end, start), 2);
+		assert_eq!(table.leap_seconds_between_signed(end, start), -2);
+		assert_eq!(table.leap_seconds_between(start, start), 0);
+
+		// the midnight after a leap second is the end of it
+		let leap_second = midnight(2015, Month::July);
+		assert_eq!(table.leap_seconds_between(start, leap_second), 2);
+		assert_eq!(table.leap_seconds_between(leap_second, end), 0);
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn test_embedded_table() {
+		let table = LeapSecondTable::embedded();
+		let midnight = |year: i16, month| {
+			let day = Date::from_ymd(year.into(), month, 1).unwrap();
+			DateTime::from_utc(NaiveDateTime::new(day, Time::MIDNIGHT), Utc)
+		};
+
+		assert_eq!(table.leap_seconds().count(), 27);
+		assert_eq!(table.tai_offset_at_utc(midnight(1972, Month::January)), 10);
+		assert_eq!(table.tai_offset_at_utc(midnight(2017, Month::January)), 37);
+		assert!(table.expiry().is_some());
+	}
+
+	/// Fails when the embedded table is about to expire, as a reminder to
+	/// update it from the latest `leap-seconds.list`
+	#[cfg(feature = "std")]
+	#[test]
+	fn test_embedded_table_is_current() {
+		let expiry = LeapSecondSlice::embedded().expiry().unwrap();
+		let remaining = expiry.time_until(&DateTime::<Utc>::now());
+		assert!(
+			remaining > core::time::Duration::from_secs(60 * 86_400),
+			"the embedded leap second table expires at {expiry}"
+		);
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn test_embedded_slice() {
+		let slice = LeapSecondSlice::embedded();
+		let table = LeapSecondTable::embedded();
+		assert_eq!(slice, table.as_slice());
+
+		let day = Date::from_ymd(2017.into(), Month::January, 1).unwrap();
+		for second in 30..40 {
+			let at = NaiveDateTime::new(day, Time::from_hms(0, 0, second).unwrap());
+			assert_eq!(slice.tai_offset_at_tai(at), table.tai_offset_at_tai(at));
+		}
+	}
+
+	#[test]
+	#[should_panic]
+	fn test_unsorted_slice() {
+		let midnight = |year: i16| {
+			let day = Date::from_ymd(year.into(), Month::January, 1).unwrap();
+			DateTime::from_utc(NaiveDateTime::new(day, Time::MIDNIGHT), Utc)
+		};
+
+		let _ = LeapSecondSlice::new(&[midnight(1973), midnight(1972)], None);
+	}
+
+	#[test]
+	fn test_day_after() {
+		let date = |year: i16, month, day| Date::from_ymd(year.into(), month, day).unwrap();
+		assert_eq!(
+			day_after(date(2016, Month::December, 31)),
+			Some(date(2017, Month::January, 1))
+		);
+		assert_eq!(
+			day_after(date(2016, Month::February, 28)),
+			Some(date(2016, Month::February, 29))
+		);
+		assert_eq!(
+			day_after(date(2015, Month::February, 28)),
+			Some(date(2015, Month::March, 1))
+		);
+		assert_eq!(day_after(Date::MAX), None);
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn test_table_stays_sorted() {
+		let day = |month| Date::from_ymd(1972.into(), month, 1).unwrap();
+		let mut table = LeapSecondTable::empty();
+		table.add_leap_second(day(Month::July));
+		table.add_leap_second(day(Month::January));
+		table.add_leap_second(day(Month::July));
+
+		let midnight =
+			|month| DateTime::from_utc(NaiveDateTime::new(day(month), Time::MIDNIGHT), Utc);
+		assert!(table
+			.leap_seconds()
+			.eq([midnight(Month::January), midnight(Month::July)]));
+		assert_eq!(table.tai_offset_at_utc(midnight(Month::July)), 12);
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn test_tt_timestamp() {
+		let day = Date::from_ymd(2000.into(), Month::January, 1).unwrap();
+		let date_time = DateTime::from_utc(NaiveDateTime::new(day, Time::MIDNIGHT), Utc);
+
+		let unix_seconds = date_time.unix_timestamp().total_seconds();
+
+		let mut table = LeapSecondTable::empty();
+		let tt = Tt::with_provider(&table);
+		assert_eq!(
+			tt.timestamp(&date_time),
+			Timestamp::new(unix_seconds + 42, 184_000_000)
+		);
+
+		table.add_leap_second(day);
+		let tt = Tt::with_provider(&table);
+		assert_eq!(
+			tt.timestamp(&date_time),
+			Timestamp::new(unix_seconds + 43, 184_000_000)
+		);
+	}
+
+	#[test]
+	fn test_tt_from_timestamp() {
+		let tt = Tt::with_provider(LeapSecondSlice::embedded());
+
+		// TT - UTC was 69.184 seconds at the start of 2017
+		let date_time = crate::datetime!(2017-01-01 00:00 UTC);
+		let timestamp = Timestamp::new(1_483_228_800 + 69, 184_000_000);
+		assert_eq!(tt.timestamp(&date_time), timestamp);
+		assert_eq!(tt.from_timestamp(timestamp).unwrap(), date_time);
+
+		// and 68.184 seconds before the leap second
+		let leap_second = crate::datetime!(2016-12-31 23:59:60.5 UTC);
+		let before = Timestamp::new(1_483_228_799 + 68, 684_000_000);
+		assert_eq!(
+			tt.from_timestamp(before).unwrap(),
+			crate::datetime!(2016-12-31 23:59:59.5 UTC)
+		);
+		assert_eq!(
+			tt.from_timestamp(add_nanoseconds(before, 1_000_000_000))
+				.unwrap(),
+			leap_second
+		);
+		assert_eq!(
+			tt.from_timestamp(add_nanoseconds(before, 2_000_000_000))
+				.unwrap(),
+			crate::datetime!(2017-01-01 00:00:00.5 UTC)
+		);
+
+		assert!(tt.from_timestamp(Timestamp::MAX).is_err());
+	}
+
+	#[test]
+	fn test_leap_second_policy() {
+		let day = Date::from_ymd(2016.into(), Month::December, 31).unwrap();
+		let at = |second, nanosecond| {
+			let time = Time::from_hms_nano_unchecked(23, 59, second, nanosecond);
+			DateTime::from_utc(NaiveDateTime::new(day, time), Utc)
+		};
+
+		assert!(LeapSecondPolicy::Error.resolve(at(60, 5)).is_err());
+		assert_eq!(
+			LeapSecondPolicy::Error.resolve(at(59, 5)).unwrap(),
+			at(59, 5).naive_utc()
+		);
+		assert_eq!(
+			LeapSecondPolicy::ClampToEndOfMinute
+				.resolve(at(60, 5))
+				.unwrap()
+				.time(),
+			Time::from_hms_nano_unchecked(23, 59, 59, 999_999_999)
+		);
+
+		let next_day = |second, nanosecond| {
+			let time = Time::from_hms_nano_unchecked(0, 0, second, nanosecond);
+			NaiveDateTime::new(day.add_days_overflowing(1).0, time)
+		};
+		let count = |date_time| LeapSecondPolicy::CountInTai.resolve(date_time).unwrap();
+		assert_eq!(count(at(60, 500_000_000)), next_day(0, 500_000_000));
+		assert_eq!(count(at(59, 5)), at(59, 5).naive_utc());
+		assert_eq!(
+			count(DateTime::from_utc(next_day(0, 5), Utc)),
+			next_day(0, 5)
+		);
+
+		// the smear spreads the leap second from noon to noon, so even
+		// 23:59:59 is changed, and the leap second is halfway through
+		let smear = |date_time| LeapSecondPolicy::Smear.resolve(date_time).unwrap();
+		assert_eq!(smear(at(60, 0)), at(59, 500_005_786).naive_utc());
+		assert!(smear(at(59, 0)) < at(59, 0).naive_utc());
+		assert_eq!(smear(at(60, 999_999_999)), next_day(0, 499_994_212));
+		let noon = NaiveDateTime::new(day, Time::from_hms_unchecked(12, 0, 0));
+		assert_eq!(smear(DateTime::from_utc(noon, Utc)), noon);
+	}
+
+	#[test]
+	fn test_tai_with_leap_second_policy() {
+		let tai = Tai::new();
+		let local = |second| {
+			let time = Time::from_hms_milli_unchecked(23, 59, second, 500);
+			NaiveDateTime::new(crate::date!(2016 - 12 - 31), time)
+		};
+
+		assert!(tai
+			.from_local_with_policy(local(60), LeapSecondPolicy::Error)
+			.is_err());
+		assert_eq!(
+			tai.from_local_with_policy(local(59), LeapSecondPolicy::Error),
+			DateTime::from_local(local(59), tai)
+		);
+		let clamped = tai
+			.from_local_with_policy(local(60), LeapSecondPolicy::ClampToEndOfMinute)
+			.unwrap();
+		assert_eq!(
+			clamped.to_naive_local().unwrap().time(),
+			Time::from_hms_nano_unchecked(23, 59, 59, 999_999_999)
+		);
+		let counted = tai
+			.from_local_with_policy(local(60), LeapSecondPolicy::CountInTai)
+			.unwrap();
+		assert_eq!(
+			counted.to_naive_local().unwrap(),
+			crate::datetime!(2017-01-01 00:00:00.5)
+		);
+	}
+
+	#[test]
+	fn test_smear_policy_uses_the_provider() {
+		let date_time = crate::datetime!(2016-12-31 23:59:59.5 UTC);
+		let smear = LeapSecondPolicy::Smear;
+		assert_eq!(
+			smear.resolve_with(&LeapSecondSlice::new(&[], None), date_time),
+			Ok(date_time.naive_utc())
+		);
+		assert_eq!(
+			smear.resolve_with(&LeapSecondSlice::embedded(), date_time),
+			Ok(SmearedUtc::to_smeared(date_time))
+		);
+		assert_ne!(SmearedUtc::to_smeared(date_time), date_time.naive_utc());
+
+		// a leap second which is only in the provider is smeared
+		let leap_seconds = [crate::datetime!(2000-01-01 00:00 UTC)];
+		let provider = LeapSecondSlice::new(&leap_seconds, None);
+		let date_time = crate::datetime!(1999-12-31 23:59:59.5 UTC);
+		let smeared = smear.resolve_with(&provider, date_time).unwrap();
+		assert_eq!(smeared, SmearedUtc::to_smeared_with(&provider, date_time));
+		assert!(smeared < date_time.naive_utc());
+		let round_trip = SmearedUtc::from_smeared_with(&provider, smeared);
+		let error = round_trip.time_until(&date_time) + date_time.time_until(&round_trip);
+		assert!(error <= core::time::Duration::from_nanos(1));
+	}
+
+	#[test]
+	fn test_add_nanoseconds_carries() {
+		let timestamp = Timestamp::new(100, 900_000_000);
+		assert_eq!(
+			add_nanoseconds(timestamp, 32_184_000_000),
+			Timestamp::new(133, 84_000_000)
+		);
+		assert_eq!(
+			add_nanoseconds(timestamp, -32_184_000_000),
+			Timestamp::new(68, 716_000_000)
+		);
+	}
+
+	#[cfg(feature = "alloc")]
+	const LEAP_SECONDS_LIST: &str = "\
+#	This is a comment
+#@	3991593600
+2272060800	10	# 1 Jan 1972
+2287785600	11	# 1 Jul 1972
+
+2303683200	12	# 1 Jan 1973
+";
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn test_parse_leap_seconds_list() {
+		let midnights = parse_leap_seconds_list(LEAP_SECONDS_LIST).unwrap();
+		assert_eq!(midnights, [78_796_800, 94_694_400]);
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn test_parse_leap_seconds_list_expiry() {
+		let expiry = parse_leap_seconds_list_expiry(LEAP_SECONDS_LIST);
+		assert_eq!(expiry, Some(3_991_593_600 - NTP_UNIX_OFFSET));
+		assert_eq!(parse_leap_seconds_list_expiry("2272060800 10"), None);
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn test_checked_offset_after_expiry() {
+		let day = |day| Date::from_ymd(2000.into(), Month::January, day).unwrap();
+		let midnight = |day| DateTime::from_utc(NaiveDateTime::new(day, Time::MIDNIGHT), Utc);
+		let mut table = LeapSecondTable::empty();
+		table.set_expiry(Some(midnight(day(2))));
+
+		let tai = Tai::with_provider(&table);
+		assert!(tai.checked_utc_offset(midnight(day(1))).is_ok());
+		assert_eq!(
+			tai.checked_utc_offset(midnight(day(3))),
+			Err(UnknownTaiOffsetError::Expired(midnight(day(2))))
+		);
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn test_checked_offset_before_1972() {
+		let midnight = |year: i16| {
+			let day = Date::from_ymd(year.into(), Month::January, 1).unwrap();
+			DateTime::from_utc(NaiveDateTime::new(day, Time::MIDNIGHT), Utc)
 		};
 
-		assert_eq!(offset, UtcOffset::from_seconds(-11));
+		let table = LeapSecondTable::empty();
+		let tai = Tai::with_provider(&table);
+		assert_eq!(
+			tai.checked_utc_offset(midnight(1971)),
+			Err(UnknownTaiOffsetError::BeforeLeapSeconds)
+		);
+		assert!(tai.checked_utc_offset(midnight(1972)).is_ok());
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn test_export_leap_seconds_list() {
+		let mut table = LeapSecondTable::empty();
+		table.add_leap_second(Date::from_ymd(1972.into(), Month::July, 1).unwrap());
+		table.add_leap_second(Date::from_ymd(1973.into(), Month::January, 1).unwrap());
+		let midnights: Vec<i64> = table
+			.leap_seconds()
+			.map(|leap_second| leap_second.unix_timestamp().total_seconds())
+			.collect();
+
+		let list = table.to_leap_seconds_list();
+		assert_eq!(parse_leap_seconds_list(&list).unwrap(), midnights);
+		assert_eq!(parse_leap_seconds_list_expiry(&list), None);
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn test_binary_table_round_trip() {
+		let table = LeapSecondTable::embedded();
+		assert!(table.expiry().is_some());
+
+		let bytes = table.to_bytes();
+		assert_eq!(bytes.len(), 17 + 8 * table.leap_seconds().count());
+		assert_eq!(LeapSecondTable::from_bytes(&bytes).unwrap(), table);
+
+		let list = "2272060800\t10\n2287785600\t11\n#@\t3960835200\n";
+		let table = LeapSecondTable::from_leap_seconds_list(list).unwrap();
+		let bytes = table.to_bytes();
+		let read = LeapSecondTable::from_bytes(&bytes).unwrap();
+		assert_eq!(
+			read.expiry(),
+			Some(utc_from_unix(3_960_835_200 - NTP_UNIX_OFFSET))
+		);
+		assert_eq!(read, table);
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn test_invalid_binary_table() {
+		let bytes = LeapSecondTable::empty().to_bytes();
+		assert_eq!(
+			LeapSecondTable::from_bytes(&bytes).unwrap(),
+			LeapSecondTable::empty()
+		);
+
+		assert!(matches!(
+			LeapSecondTable::from_bytes(&bytes[..16]),
+			Err(LoadLeapSecondsError::InvalidBinary)
+		));
+		assert!(matches!(
+			LeapSecondTable::from_bytes(b"TZif"),
+			Err(LoadLeapSecondsError::InvalidBinary)
+		));
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn test_parse_invalid_leap_seconds_list() {
+		assert!(matches!(
+			parse_leap_seconds_list("2272060800 11"),
+			Err(LoadLeapSecondsError::InvalidStart)
+		));
+		assert!(matches!(
+			parse_leap_seconds_list("2272060800 10\n2287785600 9"),
+			Err(LoadLeapSecondsError::NegativeLeapSecond)
+		));
+		assert!(matches!(
+			parse_leap_seconds_list("2272060800 10\nabc 11"),
+			Err(LoadLeapSecondsError::InvalidLine(2))
+		));
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn test_parse_tzif_leap_seconds() {
+		let mut tzif = b"TZif".to_vec();
+		tzif.extend([0; 16]);
+		// isut, isstd, leap, time, type, and char counts
+		for count in [0_u32, 0, 2, 0, 1, 4] {
+			tzif.extend(count.to_be_bytes());
+		}
+		tzif.extend([0, 0, 0, 0, 0, 0]);
+		tzif.extend(b"UTC\0");
+		for (occurrence, correction) in [(78_796_800_u32, 1_u32), (94_694_401, 2)] {
+			tzif.extend(occurrence.to_be_bytes());
+			tzif.extend(correction.to_be_bytes());
+		}
+
+		let midnights = parse_tzif_leap_seconds(&tzif).unwrap();
+		assert_eq!(midnights, [78_796_800, 94_694_400]);
+	}
+
+	#[cfg(feature = "fetch")]
+	const SIGNED_LEAP_SECONDS_LIST: &str = "\
+#$	3960835200
+2272060800	10	# 1 Jan 1972
+2287785600	11	# 1 Jul 1972
+#@	3991593600
+#h	55b48a18 32dfc6f3 dd78be6a b4b574de 64744ce7
+";
+
+	#[cfg(feature = "fetch")]
+	#[test]
+	fn test_verify_leap_seconds_list() {
+		assert!(verify_leap_seconds_list(SIGNED_LEAP_SECONDS_LIST, 0).is_ok());
+		assert!(matches!(
+			verify_leap_seconds_list(SIGNED_LEAP_SECONDS_LIST, 3_991_593_600 - NTP_UNIX_OFFSET),
+			Err(FetchLeapSecondsError::Expired)
+		));
+
+		let tampered = SIGNED_LEAP_SECONDS_LIST.replace("11\t#", "12\t#");
+		assert!(matches!(
+			verify_leap_seconds_list(&tampered, 0),
+			Err(FetchLeapSecondsError::HashMismatch)
+		));
+	}
+
+	/// Runs a future on the current thread, for testing without an executor
+	#[cfg(all(
+		feature = "fetch",
+		any(feature = "tokio", feature = "async-std", feature = "smol")
+	))]
+	fn block_on<F: core::future::Future>(future: F) -> F::Output {
+		struct ThreadWaker(std::thread::Thread);
+
+		impl std::task::Wake for ThreadWaker {
+			fn wake(self: Arc<Self>) {
+				self.0.unpark();
+			}
+		}
+
+		let waker = Arc::new(ThreadWaker(std::thread::current())).into();
+		let mut cx = core::task::Context::from_waker(&waker);
+		let mut future = core::pin::pin!(future);
+		loop {
+			if let core::task::Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+				return output;
+			}
+
+			std::thread::park();
+		}
+	}
+
+	#[cfg(all(
+		feature = "fetch",
+		any(feature = "tokio", feature = "async-std", feature = "smol")
+	))]
+	#[test]
+	fn test_blocking_task() {
+		assert_eq!(block_on(BlockingTask::spawn(|| 2 + 2)), 4);
+
+		let panicked = std::panic::catch_unwind(|| {
+			block_on(BlockingTask::spawn(|| panic!("the task panicked")))
+		});
+		assert!(panicked.is_err());
+
+		// nothing listens on port 9, so this fails without leaving the machine
+		let result = block_on(fetch_leap_seconds_from_async("http://127.0.0.1:9/"));
+		assert!(matches!(result, Err(FetchLeapSecondsError::Http(_))));
 	}
 }
@@ -1,92 +1,65 @@
 use core::cmp::Ordering;
 use core::fmt::Display;
-use core::panic;
+use core::time::Duration;
 
-use thiserror::Error;
+use crate::error::{check_range, ComponentRange};
 
+#[cfg(feature = "std")]
+use crate::TimeZone;
+use crate::{Date, NaiveDateTime};
+
+/// A time of day.
+///
+/// This is stored as the number of nanoseconds since midnight. During a leap
+/// second, the nanoseconds are the same as they were one second earlier, and
+/// the highest bit is set.
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
 pub struct Time {
-	hour: u8,
-	minute: u8,
-	second: u8,
-	nanosecond: u32,
-}
-
-#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug, Error)]
-pub struct InvalidTimeError {
-	hour: u8,
-	minute: u8,
-	second: u8,
-	nanosecond: u32,
-}
-
-impl Display for InvalidTimeError {
-	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-		write!(
-			f,
-			"Tried to construct invalid time {}:{}:{}.{}",
-			self.hour, self.minute, self.second, self.nanosecond
-		)
-	}
+	nanoseconds: u64,
 }
 
-impl InvalidTimeError {
-	const unsafe fn new_unchecked(hour: u8, minute: u8, second: u8, nanosecond: u32) -> Self {
-		Self {
-			hour,
-			minute,
-			second,
-			nanosecond,
-		}
-	}
-}
+const LEAP_SECOND_FLAG: u64 = 1 << 63;
+const NANOS_PER_SECOND: u64 = 1_000_000_000;
+const NANOS_PER_DAY: u64 = 86_400 * NANOS_PER_SECOND;
 
 impl Time {
 	/// A `Time` that is exactly midnight
-	pub const MIDNIGHT: Self = unsafe { Self::from_hms_unchecked(0, 0, 0) };
+	pub const MIDNIGHT: Self = Self::from_hms_unchecked(0, 0, 0);
+
+	/// Gets the current time of day in the given time zone
+	#[cfg(feature = "std")]
+	#[must_use]
+	pub fn now(timezone: &impl TimeZone) -> Self {
+		NaiveDateTime::now_in(timezone).time()
+	}
 
-	// TODO validated versions of the following:
 	// TODO examples
 
-	/// Create a `Time` from an hour, minute, and second
+	/// Create a `Time` from an hour, minute, and second, without checking that
+	/// they're in range.
 	///
-	/// # Safety
-	///
-	/// Creating a time where the hour is greater than 23, minute is greater than 59, or second is
-	/// greater than 60 results in undefined behavior
+	/// Components which are too large carry into the next one, and a time
+	/// past the end of the day wraps around midnight. A second of 60 is a leap
+	/// second at the end of the given minute.
 	#[must_use]
-	pub const unsafe fn from_hms_unchecked(hour: u8, minute: u8, second: u8) -> Self {
-		Self {
-			hour,
-			minute,
-			second,
-			nanosecond: 0,
-		}
+	pub const fn from_hms_unchecked(hour: u8, minute: u8, second: u8) -> Self {
+		Self::from_hms_nano_unchecked(hour, minute, second, 0)
 	}
 
-	pub const fn from_hms(hour: u8, minute: u8, second: u8) -> Result<Self, InvalidTimeError> {
+	pub const fn from_hms(hour: u8, minute: u8, second: u8) -> Result<Self, ComponentRange> {
 		Self::from_hms_nano(hour, minute, second, 0)
 	}
 
-	/// Create a `Time` from an hour, minute, second, and millisecond
-	///
-	/// # Safety
-	///
-	/// Creating a time where the hour is greater than 23, minute is greater than 59, second is
-	/// greater than 60, or millisecond is greater than 999 results in undefined behavior
+	/// Create a `Time` from an hour, minute, second, and millisecond, without
+	/// checking that they're in range. See [`Time::from_hms_unchecked`].
 	#[must_use]
-	pub const unsafe fn from_hms_milli_unchecked(
+	pub const fn from_hms_milli_unchecked(
 		hour: u8,
 		minute: u8,
 		second: u8,
 		millisecond: u16,
 	) -> Self {
-		Self {
-			hour,
-			minute,
-			second,
-			nanosecond: millisecond as u32 * 1_000_000,
-		}
+		Self::from_hms_nanos_wrapping(hour, minute, second, millisecond as u64 * 1_000_000)
 	}
 
 	pub const fn from_hms_milli(
@@ -94,29 +67,21 @@ impl Time {
 		minute: u8,
 		second: u8,
 		millisecond: u16,
-	) -> Result<Self, InvalidTimeError> {
-		Self::from_hms_nano(hour, minute, second, millisecond as u32 * 1000)
+	) -> Result<Self, ComponentRange> {
+		check_range!("millisecond", millisecond, 0, 999);
+		Self::from_hms_nano(hour, minute, second, millisecond as u32 * 1_000_000)
 	}
 
-	/// Create a `Time` from an hour, minute, second, and microsecond
-	///
-	/// # Safety
-	///
-	/// Creating a time where the hour is greater than 23, minute is greater than 59, second is
-	/// greater than 60, or microsecond is greater than 999,999 results in undefined behavior
+	/// Create a `Time` from an hour, minute, second, and microsecond, without
+	/// checking that they're in range. See [`Time::from_hms_unchecked`].
 	#[must_use]
-	pub const unsafe fn from_hms_micro_unchecked(
+	pub const fn from_hms_micro_unchecked(
 		hour: u8,
 		minute: u8,
 		second: u8,
 		microsecond: u32,
 	) -> Self {
-		Self {
-			hour,
-			minute,
-			second,
-			nanosecond: microsecond * 1_000,
-		}
+		Self::from_hms_nanos_wrapping(hour, minute, second, microsecond as u64 * 1_000)
 	}
 
 	pub const fn from_hms_micro(
@@ -124,28 +89,128 @@ impl Time {
 		minute: u8,
 		second: u8,
 		microsecond: u32,
-	) -> Result<Self, InvalidTimeError> {
-		Self::from_hms_nano(hour, minute, second, microsecond * 1_000_000)
+	) -> Result<Self, ComponentRange> {
+		check_range!("microsecond", microsecond, 0, 999_999);
+		Self::from_hms_nano(hour, minute, second, microsecond * 1_000)
 	}
 
-	/// Create a `Time` from an hour, minute, second, and nanosecond
-	///
-	/// # Safety
-	///
-	/// Creating a time where the hour is greater than 23, minute is greater than 59, second is
-	/// greater than 60, or nanosecond is greater than 999,999,999 results in undefined behavior
+	/// Create a `Time` from an hour, minute, second, and nanosecond, without
+	/// checking that they're in range. See [`Time::from_hms_unchecked`].
 	#[must_use]
-	pub const unsafe fn from_hms_nano_unchecked(
+	pub const fn from_hms_nano_unchecked(
 		hour: u8,
 		minute: u8,
 		second: u8,
 		nanosecond: u32,
 	) -> Self {
+		Self::from_hms_nanos_wrapping(hour, minute, second, nanosecond as u64)
+	}
+
+	/// Adds up the components, wrapping around midnight. None of these can
+	/// overflow a `u64`.
+	///
+	/// A second of 60 shares its nanoseconds with second 59, so nanoseconds
+	/// which carry out of the leap second end up in the following second.
+	const fn from_hms_nanos_wrapping(hour: u8, minute: u8, second: u8, nanoseconds: u64) -> Self {
+		let is_sixty = second == 60;
+		let seconds = hour as u64 * 3600 + minute as u64 * 60 + (second - is_sixty as u8) as u64;
+		let leap_second = is_sixty && nanoseconds < NANOS_PER_SECOND;
+		let nanoseconds = (seconds * NANOS_PER_SECOND + nanoseconds) % NANOS_PER_DAY;
+		Self::from_parts(nanoseconds, leap_second)
+	}
+
+	/// Creates a time from the number of nanoseconds since midnight, not
+	/// counting a leap second, and whether or not it's during a leap second
+	pub(crate) const fn from_parts(nanoseconds: u64, leap_second: bool) -> Self {
 		Self {
-			hour,
-			minute,
-			second,
-			nanosecond,
+			nanoseconds: nanoseconds | (leap_second as u64 * LEAP_SECOND_FLAG),
+		}
+	}
+
+	/// The number of nanoseconds since midnight, not counting a leap second
+	pub(crate) const fn nanoseconds_without_leap_second(self) -> u64 {
+		self.nanoseconds & !LEAP_SECOND_FLAG
+	}
+
+	/// Checks if this time is during a leap second
+	pub(crate) const fn in_leap_second(self) -> bool {
+		self.nanoseconds & LEAP_SECOND_FLAG != 0
+	}
+
+	/// Adds nanoseconds to the number of nanoseconds since midnight, without
+	/// wrapping. A leap second is counted as one extra second, so the boolean
+	/// is true if the result is still during the same leap second.
+	pub(crate) const fn add_to_nanoseconds_overflowing(self, nanoseconds: i128) -> (i128, bool) {
+		let second = NANOS_PER_SECOND as i128;
+		let current = self.nanoseconds_without_leap_second() as i128;
+		if !self.in_leap_second() {
+			return (current + nanoseconds, false);
+		}
+
+		// the leap second shares its nanoseconds with the second before it
+		let start = current - current % second;
+		let into_leap_second = current % second + nanoseconds;
+		if into_leap_second < 0 {
+			(start + second + into_leap_second, false)
+		} else if into_leap_second < second {
+			(start + into_leap_second, true)
+		} else {
+			(start + into_leap_second, false)
+		}
+	}
+
+	/// A number which sorts in the same order as the times. A leap second
+	/// comes after the second that it shares nanoseconds with, so each second
+	/// gets two billion values, and the leap second uses the second billion.
+	pub(crate) const fn sort_key(self) -> u64 {
+		let nanoseconds = self.nanoseconds_without_leap_second();
+		let seconds = nanoseconds / NANOS_PER_SECOND;
+		let leap_second = self.in_leap_second() as u64;
+		(seconds * 2 + leap_second) * NANOS_PER_SECOND + nanoseconds % NANOS_PER_SECOND
+	}
+
+	/// Checks if this time is before the other one
+	const fn is_before(self, other: Self) -> bool {
+		self.sort_key() < other.sort_key()
+	}
+
+	/// Gets the earlier of two times. Unlike [`Ord::min`], this can be
+	/// used in a `const`.
+	#[must_use]
+	pub const fn min(self, other: Self) -> Self {
+		if other.is_before(self) {
+			other
+		} else {
+			self
+		}
+	}
+
+	/// Gets the later of two times. Unlike [`Ord::max`], this can be used
+	/// in a `const`.
+	#[must_use]
+	pub const fn max(self, other: Self) -> Self {
+		if other.is_before(self) {
+			self
+		} else {
+			other
+		}
+	}
+
+	/// Restricts the time to be from `min` to `max`. Unlike [`Ord::clamp`],
+	/// this can be used in a `const`.
+	///
+	/// # Panics
+	///
+	/// Panics if `min` is after `max`
+	#[must_use]
+	pub const fn clamp(self, min: Self, max: Self) -> Self {
+		assert!(!max.is_before(min), "the minimum is after the maximum");
+		if self.is_before(min) {
+			min
+		} else if max.is_before(self) {
+			max
+		} else {
+			self
 		}
 	}
 
@@ -154,87 +219,126 @@ impl Time {
 		minute: u8,
 		second: u8,
 		nanosecond: u32,
-	) -> Result<Self, InvalidTimeError> {
-		if hour >= 24 {
-			return unsafe {
-				Err(InvalidTimeError::new_unchecked(
-					hour, minute, second, nanosecond,
-				))
-			};
-		}
-
-		if minute >= 60 {
-			return unsafe {
-				Err(InvalidTimeError::new_unchecked(
-					hour, minute, second, nanosecond,
-				))
-			};
+	) -> Result<Self, ComponentRange> {
+		check_range!("hour", hour, 0, 23);
+		check_range!("minute", minute, 0, 59);
+		check_range!("nanosecond", nanosecond, 0, 999_999_999);
+
+		// a leap second can only be at the end of the day
+		if hour == 23 && minute == 59 {
+			check_range!("second", second, 0, 60);
+		} else {
+			check_range!("second", second, 0, 59);
 		}
 
-		if second > 60 {
-			return unsafe {
-				Err(InvalidTimeError::new_unchecked(
-					hour, minute, second, nanosecond,
-				))
-			};
-		}
+		Ok(Self::from_hms_nano_unchecked(
+			hour, minute, second, nanosecond,
+		))
+	}
 
-		if nanosecond >= 1_000_000_000 {
-			return unsafe {
-				Err(InvalidTimeError::new_unchecked(
-					hour, minute, second, nanosecond,
-				))
-			};
-		}
+	/// Creates a time from an hour, minute, and second, panicking if any of
+	/// them are out of range. This is meant for constants, where an invalid
+	/// time becomes a compile error.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use botic::Time;
+	///
+	/// const NOON: Time = Time::from_hms_or_panic(12, 0, 0);
+	/// assert_eq!(NOON.hour(), 12);
+	/// ```
+	///
+	/// ```compile_fail
+	/// const MIDNIGHT: botic::Time = botic::Time::from_hms_or_panic(24, 0, 0);
+	/// ```
+	///
+	/// # Panics
+	///
+	/// Panics if a component is out of range, or if the second is 60 at any
+	/// time other than 23:59
+	#[must_use]
+	#[track_caller]
+	pub const fn from_hms_or_panic(hour: u8, minute: u8, second: u8) -> Self {
+		Self::from_hms_nano_or_panic(hour, minute, second, 0)
+	}
 
-		if second == 60 && (minute != 59 || hour != 23) {
-			return unsafe {
-				Err(InvalidTimeError::new_unchecked(
-					hour, minute, second, nanosecond,
-				))
-			};
+	/// Creates a time from an hour, minute, second, and nanosecond, panicking
+	/// if any of them are out of range. See [`Time::from_hms_or_panic`].
+	///
+	/// # Panics
+	///
+	/// Panics if a component is out of range, or if the second is 60 at any
+	/// time other than 23:59
+	#[must_use]
+	#[track_caller]
+	pub const fn from_hms_nano_or_panic(hour: u8, minute: u8, second: u8, nanosecond: u32) -> Self {
+		match Self::from_hms_nano(hour, minute, second, nanosecond) {
+			Ok(time) => time,
+			Err(error) => error.panic(),
 		}
+	}
 
-		unsafe { Ok(Self::from_hms_unchecked(hour, minute, second)) }
+	/// Combines the time of day with a date. This is the same as
+	/// [`Date::at`].
+	#[must_use]
+	pub const fn on(self, date: Date) -> NaiveDateTime {
+		NaiveDateTime::new(date, self)
 	}
 
 	/// Get the clock hour. The returned value will always be in the range `0..24`
 	#[must_use]
 	pub const fn hour(self) -> u8 {
-		self.hour
+		(self.nanoseconds_without_leap_second() / (3600 * NANOS_PER_SECOND)) as u8
 	}
 
 	/// Get the minute within the hour. The returned value will always be in the range `0..60`
 	#[must_use]
 	pub const fn minute(self) -> u8 {
-		self.minute
+		(self.nanoseconds_without_leap_second() / (60 * NANOS_PER_SECOND) % 60) as u8
 	}
 
 	// Get the second within the minute. The returned value will always be in the range `0..=60`
 	#[must_use]
 	pub const fn second(self) -> u8 {
-		self.second
+		let second = self.nanoseconds_without_leap_second() / NANOS_PER_SECOND % 60;
+		second as u8 + self.in_leap_second() as u8
 	}
 
 	// Get the millisecond within the second.
 	// The returned value will always be in the range `0..1_000`
 	#[must_use]
 	pub const fn millisecond(self) -> u16 {
-		(self.nanosecond / 1_000_000) as u16
+		(self.nanosecond() / 1_000_000) as u16
 	}
 
 	// Get the microsecond within the second.
 	// The returned value will always be in the range `0..1_000_000`
 	#[must_use]
 	pub const fn microsecond(self) -> u32 {
-		(self.nanosecond / 1_000) as u32
+		self.nanosecond() / 1_000
 	}
 
 	// Get the nanosecond within the second.
 	// The returned value will always be in the range `0..1_000_000`
 	#[must_use]
 	pub const fn nanosecond(self) -> u32 {
-		self.nanosecond
+		(self.nanoseconds_without_leap_second() % NANOS_PER_SECOND) as u32
+	}
+
+	/// Adds nanoseconds to the time, wrapping around at midnight. A leap second
+	/// is counted as one extra second, and the result is only a leap second if
+	/// it's still during the same one.
+	const fn add_overflowing(self, nanoseconds: i128) -> (Self, bool) {
+		let (total, leap_second) = self.add_to_nanoseconds_overflowing(nanoseconds);
+		if leap_second {
+			return (Self::from_parts(total as u64, true), false);
+		}
+
+		let overflow = total < 0 || total >= NANOS_PER_DAY as i128;
+		let total = total.rem_euclid(NANOS_PER_DAY as i128);
+
+		(Self::from_parts(total as u64, false), overflow)
 	}
 
 	/// Adds the specified number of hours to the time.
@@ -242,18 +346,7 @@ impl Time {
 	/// if overflow happened.
 	#[must_use]
 	pub const fn add_hours_overflowing(self, hours: isize) -> (Self, bool) {
-		let total_hours = self.hour as isize + hours;
-		let overflow = 0 > total_hours || total_hours >= 24;
-		let total_hours = total_hours % 24 + (24 * total_hours.is_negative() as isize);
-
-		let time = Self {
-			hour: total_hours as u8,
-			minute: self.minute,
-			second: self.second,
-			nanosecond: self.nanosecond,
-		};
-
-		(time, overflow)
+		self.add_overflowing(hours as i128 * 3600 * NANOS_PER_SECOND as i128)
 	}
 
 	/// Adds the specified number of minutes to the time.
@@ -261,21 +354,7 @@ impl Time {
 	/// if overflow happened.
 	#[must_use]
 	pub const fn add_minutes_overflowing(self, minutes: isize) -> (Self, bool) {
-		let total_minutes = (self.minute as isize + minutes) % 60;
-		let total_minutes = total_minutes + (60 * total_minutes.is_negative() as isize);
-		let added_hours = (self.hour as isize + minutes) / 60;
-		let total_hours = self.hour as isize + added_hours;
-		let overflow = 0 > total_hours || total_hours >= 24;
-		let total_hours = total_hours % 24 + (24 * total_hours.is_negative() as isize);
-
-		let time = Self {
-			hour: total_hours as u8,
-			minute: total_minutes as u8,
-			second: self.second,
-			nanosecond: self.nanosecond,
-		};
-
-		(time, overflow)
+		self.add_overflowing(minutes as i128 * 60 * NANOS_PER_SECOND as i128)
 	}
 
 	/// Adds the specified number of seconds to the time.
@@ -284,24 +363,7 @@ impl Time {
 	/// Leap seconds are not included in this calculation.
 	#[must_use]
 	pub const fn add_seconds_overflowing(self, seconds: isize) -> (Self, bool) {
-		let total_seconds = (self.second as isize + seconds) % 60;
-		let total_seconds = total_seconds + (60 * total_seconds.is_negative() as isize);
-		let added_minutes = (self.second as isize + seconds) / 60;
-		let total_minutes = (self.minute as isize + added_minutes) % 60;
-		let total_minutes = total_minutes + (60 * total_minutes.is_negative() as isize);
-		let added_hours = (self.hour as isize + added_minutes) / 60;
-		let total_hours = self.hour as isize + added_hours;
-		let overflow = 0 > total_hours || total_hours >= 24;
-		let total_hours = total_hours % 24 + (24 * total_hours.is_negative() as isize);
-
-		let time = Self {
-			hour: total_hours as u8,
-			minute: total_minutes as u8,
-			second: total_seconds as u8,
-			nanosecond: self.nanosecond,
-		};
-
-		(time, overflow)
+		self.add_overflowing(seconds as i128 * NANOS_PER_SECOND as i128)
 	}
 
 	/// Adds the specified number of nanoseconds to the time.
@@ -310,27 +372,7 @@ impl Time {
 	/// Leap seconds are not included in this calculation.
 	#[must_use]
 	pub const fn add_nanoseconds_overflowing(self, nanoseconds: isize) -> (Self, bool) {
-		let total_nanos = (self.nanosecond as isize + nanoseconds) % 1_000_000_000;
-		let total_nanos = total_nanos + (1_000_000_000 * total_nanos.is_negative() as isize);
-		let added_seconds = (self.nanosecond as isize + nanoseconds) / 1_000_000_000;
-		let total_seconds = (self.second as isize + added_seconds) % 60;
-		let total_seconds = total_seconds + (60 * total_seconds.is_negative() as isize);
-		let added_minutes = (self.second as isize + added_seconds) / 60;
-		let total_minutes = (self.minute as isize + added_minutes) % 60;
-		let total_minutes = total_minutes + (60 * total_minutes.is_negative() as isize);
-		let added_hours = (self.minute as isize + added_minutes) / 60;
-		let total_hours = self.hour as isize + added_hours;
-		let overflow = 0 > total_hours || total_hours >= 24;
-		let total_hours = total_hours % 24 + (24 * total_hours.is_negative() as isize);
-
-		let time = Self {
-			hour: total_hours as u8,
-			minute: total_minutes as u8,
-			second: total_seconds as u8,
-			nanosecond: total_nanos as u32,
-		};
-
-		(time, overflow)
+		self.add_overflowing(nanoseconds as i128)
 	}
 
 	/// Adds the specified number of hours to the time.
@@ -465,101 +507,319 @@ impl Time {
 			.unwrap_or_else(|| panic!("Overflow when adding {nanoseconds} nanoseconds to {self}"))
 	}
 
-	/// Gets the number of seconds since midnight
+	/// Gets the number of whole seconds since midnight. A leap second is
+	/// counted, so 23:59:60 is 86,400 seconds after midnight.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use botic::time;
+	///
+	/// assert_eq!(45_296, time!(12:34:56.7).seconds_from_midnight());
+	/// assert_eq!(86_400, time!(23:59:60).seconds_from_midnight());
+	/// ```
 	#[must_use]
 	pub const fn seconds_from_midnight(self) -> u32 {
-		self.hour as u32 * 3_600_000_000
-			+ self.minute as u32 * 60_000_000
-			+ self.second as u32 * 1_000_000
+		self.hour() as u32 * 3_600 + self.minute() as u32 * 60 + self.second() as u32
+	}
+
+	/// Gets the amount of time since midnight. A leap second is counted, so
+	/// 23:59:60.5 is 86,400.5 seconds after midnight.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use core::time::Duration;
+	/// use botic::time;
+	///
+	/// assert_eq!(Duration::from_millis(45_296_700), time!(12:34:56.7).duration_since_midnight());
+	/// ```
+	#[must_use]
+	pub const fn duration_since_midnight(self) -> Duration {
+		Duration::from_nanos(self.nanoseconds_from_midnight())
 	}
 
 	/// Gets the number of nanoseconds since midnight
 	#[must_use]
-	pub fn nanoseconds_from_midnight(self) -> u64 {
-		u64::from(self.hour) * 3_600_000_000_000
-			+ u64::from(self.minute) * 60_000_000_000
-			+ u64::from(self.second) * 1_000_000_000
-			+ u64::from(self.nanosecond)
+	pub const fn nanoseconds_from_midnight(self) -> u64 {
+		self.nanoseconds_without_leap_second() + self.in_leap_second() as u64 * NANOS_PER_SECOND
 	}
 }
 
 impl PartialOrd for Time {
 	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-		let hour_ordering = self.hour.cmp(&other.hour);
-		let minute_ordering = self.minute.cmp(&other.minute);
-		let second_ordering = self.second.cmp(&other.second);
-		let nano_ordering = self.nanosecond.cmp(&other.nanosecond);
-
-		if hour_ordering != Ordering::Equal {
-			Some(hour_ordering)
-		} else if minute_ordering != Ordering::Equal {
-			Some(minute_ordering)
-		} else if second_ordering != Ordering::Equal {
-			Some(second_ordering)
-		} else if nano_ordering != Ordering::Equal {
-			Some(nano_ordering)
-		} else {
-			Some(Ordering::Equal)
-		}
+		Some(self.cmp(other))
 	}
 }
 
 impl Ord for Time {
 	fn cmp(&self, other: &Self) -> Ordering {
-		let hour_ordering = self.hour.cmp(&other.hour);
-		let minute_ordering = self.minute.cmp(&other.minute);
-		let second_ordering = self.second.cmp(&other.second);
-		let nano_ordering = self.nanosecond.cmp(&other.nanosecond);
-
-		if hour_ordering != Ordering::Equal {
-			hour_ordering
-		} else if minute_ordering != Ordering::Equal {
-			minute_ordering
-		} else if second_ordering != Ordering::Equal {
-			second_ordering
-		} else if nano_ordering != Ordering::Equal {
-			nano_ordering
-		} else {
-			Ordering::Equal
-		}
+		self.sort_key().cmp(&other.sort_key())
 	}
 }
 
 impl Display for Time {
 	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-		let seconds = f64::from(self.second) + (f64::from(self.nanosecond) / 1_000_000_000.0);
-		if self.nanosecond() == 0 {
-			write!(f, "{:02}:{:02}:{:02}", self.hour, self.minute, self.second)
-		} else if self.second < 10 {
-			write!(f, "{:02}:{:02}:0{}", self.hour, self.minute, seconds)
-		} else {
-			write!(f, "{:02}:{:02}:{}", self.hour, self.minute, seconds)
+		let (hour, minute, second) = (self.hour(), self.minute(), self.second());
+		write!(f, "{hour:02}:{minute:02}:{second:02}")?;
+
+		let nanosecond = self.nanosecond();
+		if nanosecond == 0 {
+			return Ok(());
+		}
+
+		// leave out the trailing zeros
+		let (mut fraction, mut digits) = (nanosecond, 9);
+		while fraction % 10 == 0 {
+			fraction /= 10;
+			digits -= 1;
 		}
+
+		write!(f, ".{fraction:0digits$}")
 	}
 }
 
 #[cfg(test)]
 mod tests {
+	#[cfg(feature = "alloc")]
+	use alloc::{format, string::ToString};
+
 	use super::*;
 
+	#[cfg(feature = "std")]
+	#[test]
+	fn now() {
+		use crate::{
+			timezone::{Utc, UtcOffset},
+			DateTime,
+		};
+
+		let behind = UtcOffset::from_hours_unchecked(-5);
+		let before = DateTime::<Utc>::now().into_timezone(behind);
+		let now = Time::now(&behind);
+		let after = DateTime::<Utc>::now().into_timezone(behind);
+
+		// the day may have ended in between
+		let (before, after) = (
+			before.to_naive_local_saturating().time(),
+			after.to_naive_local_saturating().time(),
+		);
+		assert!(
+			(before <= now && now <= after) || (after < before && (now >= before || now <= after))
+		);
+	}
+	#[test]
+	fn since_midnight() {
+		let last = Time::from_hms_nano_unchecked(23, 59, 59, 999_999_999);
+		assert_eq!(last.seconds_from_midnight(), 86_399);
+		assert_eq!(
+			last.duration_since_midnight(),
+			Duration::new(86_399, 999_999_999)
+		);
+
+		let leap_second = Time::from_hms_nano_unchecked(23, 59, 60, 500_000_000);
+		assert_eq!(leap_second.seconds_from_midnight(), 86_400);
+		assert_eq!(
+			leap_second.duration_since_midnight(),
+			Duration::new(86_400, 500_000_000)
+		);
+		assert_eq!(Time::MIDNIGHT.duration_since_midnight(), Duration::ZERO);
+	}
+
+	#[cfg(feature = "alloc")]
 	#[test]
 	fn display_without_nanos() {
-		let time = unsafe { Time::from_hms_nano_unchecked(0, 0, 1, 0) };
+		let time = Time::from_hms_nano_unchecked(0, 0, 1, 0);
 		let time_str = format!("{time}");
 		assert_eq!(time_str, "00:00:01");
 	}
 
+	#[cfg(feature = "alloc")]
 	#[test]
 	fn display_with_nanos_lt_10() {
-		let time = unsafe { Time::from_hms_nano_unchecked(0, 0, 1, 1_000_000) };
+		let time = Time::from_hms_nano_unchecked(0, 0, 1, 1_000_000);
 		let time_str = format!("{time}");
 		assert_eq!(time_str, "00:00:01.001");
 	}
 
+	#[cfg(feature = "alloc")]
 	#[test]
 	fn display_with_nanos_gt_10() {
-		let time = unsafe { Time::from_hms_nano_unchecked(0, 0, 10, 1_000_000) };
+		let time = Time::from_hms_nano_unchecked(0, 0, 10, 1_000_000);
 		let time_str = format!("{time}");
 		assert_eq!(time_str, "00:00:10.001");
 	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn display_fractions() {
+		let display =
+			|nanosecond| Time::from_hms_nano_unchecked(12, 34, 56, nanosecond).to_string();
+
+		assert_eq!(display(1), "12:34:56.000000001");
+		assert_eq!(display(10), "12:34:56.00000001");
+		assert_eq!(display(100_000), "12:34:56.0001");
+		assert_eq!(display(123_456_789), "12:34:56.123456789");
+		assert_eq!(display(100_000_000), "12:34:56.1");
+		assert_eq!(display(999_999_999), "12:34:56.999999999");
+		assert_eq!(display(500_000_001), "12:34:56.500000001");
+		assert_eq!(display(20_000_000), "12:34:56.02");
+
+		for nanosecond in (1..1_000_000_000).step_by(999_983) {
+			let expected = format!("{nanosecond:09}");
+			let expected = format!("12:34:56.{}", expected.trim_end_matches('0'));
+			assert_eq!(display(nanosecond), expected);
+		}
+	}
+
+	#[test]
+	fn out_of_range_components() {
+		let name = |result: Result<Time, ComponentRange>| result.unwrap_err().name();
+		assert_eq!(name(Time::from_hms(24, 0, 0)), "hour");
+		assert_eq!(name(Time::from_hms(0, 60, 0)), "minute");
+		assert_eq!(name(Time::from_hms(12, 0, 60)), "second");
+		assert_eq!(name(Time::from_hms_milli(0, 0, 0, 1000)), "millisecond");
+		assert_eq!(
+			name(Time::from_hms_nano(0, 0, 0, 1_000_000_000)),
+			"nanosecond"
+		);
+
+		let error = Time::from_hms(0, 0, 61).unwrap_err();
+		assert_eq!(
+			(error.value(), error.minimum(), error.maximum()),
+			(61, 0, 59)
+		);
+		assert!(Time::from_hms(23, 59, 60).is_ok());
+		assert_eq!(
+			Time::from_hms_milli(1, 2, 3, 4).unwrap().nanosecond(),
+			4_000_000
+		);
+	}
+
+	#[test]
+	fn const_constructors() {
+		const LEAP_SECOND: Time = Time::from_hms_nano_or_panic(23, 59, 60, 5);
+		assert_eq!(LEAP_SECOND, Time::from_hms_nano(23, 59, 60, 5).unwrap());
+	}
+
+	#[test]
+	#[should_panic(expected = "The minute must be in the range 0..=59, but it was 60")]
+	fn const_constructor_panics() {
+		let _ = Time::from_hms_or_panic(0, 60, 0);
+	}
+
+	#[test]
+	fn unchecked_wraps() {
+		assert_eq!(
+			Time::from_hms_unchecked(10, 60, 0),
+			Time::from_hms(11, 0, 0).unwrap()
+		);
+		assert_eq!(
+			Time::from_hms_unchecked(24, 0, 1),
+			Time::from_hms(0, 0, 1).unwrap()
+		);
+		assert_eq!(
+			Time::from_hms_milli_unchecked(0, 0, 0, u16::MAX),
+			Time::from_hms_milli(0, 1, 5, 535).unwrap()
+		);
+		assert_eq!(
+			Time::from_hms_nano_unchecked(u8::MAX, u8::MAX, u8::MAX, u32::MAX),
+			Time::from_hms_nano(19, 19, 19, 294_967_295).unwrap()
+		);
+	}
+
+	#[test]
+	fn combine_with_date() {
+		let date = Date::from_ymd(2000.into(), crate::Month::March, 1).unwrap();
+		let time = Time::from_hms(23, 59, 60).unwrap();
+		assert_eq!(time.on(date), date.at(time));
+		assert_eq!(time.on(date).time(), time);
+		assert_eq!(date.at_midnight(), Time::MIDNIGHT.on(date));
+	}
+
+	#[test]
+	fn const_min_max() {
+		const LEAP_SECOND: Time = Time::from_hms_nano_or_panic(23, 59, 60, 0);
+		const BEFORE: Time = Time::from_hms_nano_or_panic(23, 59, 59, 999_999_999);
+		const LATEST: Time = BEFORE.max(LEAP_SECOND);
+		assert_eq!(LATEST, LEAP_SECOND);
+		assert_eq!(LEAP_SECOND.min(BEFORE), BEFORE);
+		assert_eq!(Time::MIDNIGHT.clamp(BEFORE, LEAP_SECOND), BEFORE);
+
+		for (a, b) in [(Time::MIDNIGHT, BEFORE), (BEFORE, LEAP_SECOND)] {
+			assert_eq!(a.min(b), Ord::min(a, b));
+			assert_eq!(b.max(a), Ord::max(b, a));
+		}
+	}
+
+	#[test]
+	#[should_panic(expected = "the minimum is after the maximum")]
+	fn clamp_panics() {
+		let _ = Time::MIDNIGHT.clamp(Time::from_hms_or_panic(1, 0, 0), Time::MIDNIGHT);
+	}
+
+	#[test]
+	fn add_carries() {
+		let time = Time::from_hms_nano_unchecked(10, 59, 59, 999_999_999);
+		let (time, overflow) = time.add_nanoseconds_overflowing(1);
+		assert!(!overflow);
+		assert_eq!(time, Time::from_hms_unchecked(11, 0, 0));
+
+		let (time, overflow) = time.add_seconds_overflowing(-1);
+		assert!(!overflow);
+		assert_eq!(time, Time::from_hms_unchecked(10, 59, 59));
+
+		let (time, overflow) = time.add_minutes_overflowing(13 * 60 + 1);
+		assert!(overflow);
+		assert_eq!(time, Time::from_hms_unchecked(0, 0, 59));
+	}
+
+	#[test]
+	fn leap_second() {
+		let leap_second = Time::from_hms_milli_unchecked(23, 59, 60, 500);
+		assert_eq!(leap_second.second(), 60);
+		assert_eq!(leap_second.millisecond(), 500);
+		#[cfg(feature = "alloc")]
+		assert_eq!(leap_second.to_string(), "23:59:60.5");
+
+		assert!(Time::from_hms_milli_unchecked(23, 59, 59, 999) < leap_second);
+		assert_eq!(
+			leap_second.add_nanoseconds_overflowing(100_000_000),
+			(Time::from_hms_milli_unchecked(23, 59, 60, 600), false)
+		);
+		assert_eq!(
+			leap_second.add_seconds_overflowing(1),
+			(Time::from_hms_milli_unchecked(0, 0, 0, 500), true)
+		);
+		assert_eq!(
+			leap_second.add_nanoseconds_overflowing(500_000_000),
+			(Time::MIDNIGHT, true)
+		);
+		assert_eq!(
+			leap_second.add_seconds_overflowing(-1),
+			(Time::from_hms_milli_unchecked(23, 59, 59, 500), false)
+		);
+		assert_eq!(
+			leap_second.add_nanoseconds_overflowing(-500_000_001),
+			(
+				Time::from_hms_nano_unchecked(23, 59, 59, 999_999_999),
+				false
+			)
+		);
+	}
+
+	#[test]
+	fn carry_out_of_leap_second() {
+		let carried = Time::from_hms_nano_unchecked(23, 59, 60, 1_500_000_000);
+		assert_eq!(carried, Time::from_hms_milli_unchecked(0, 0, 0, 500));
+		assert_eq!(carried.second(), 0);
+		assert_eq!(
+			Time::from_hms_micro_unchecked(23, 59, 60, 999_999).second(),
+			60
+		);
+		assert_eq!(
+			Time::from_hms_milli_unchecked(23, 59, 60, 1_000),
+			Time::MIDNIGHT
+		);
+	}
 }
@@ -0,0 +1,56 @@
+//! Timers which follow the wall clock, for async-std, smol, or any other
+//! executor.
+//!
+//! Timers usually use a monotonic clock, so a timer set for a particular
+//! [`DateTime`] would fire at the wrong time if the system clock is adjusted
+//! while it's waiting. These timers wake up periodically to re-derive their
+//! deadline from the current time.
+
+use std::time::Duration;
+
+use crate::{
+	wall_clock::{duration_until, WallSchedule, MAX_SLEEP},
+	DateTime, TimeZone,
+};
+
+/// Waits until the given date and time.
+///
+/// If the system clock is changed while waiting, this still finishes at the
+/// given time, within about a second.
+pub async fn sleep_until<Tz: TimeZone>(deadline: DateTime<Tz>) {
+	while let Some(remaining) = duration_until(&deadline) {
+		futures_timer::Delay::new(remaining.min(MAX_SLEEP)).await;
+	}
+}
+
+/// Creates an interval which ticks at `anchor`, and then every `period` after
+/// it, according to the wall clock. For example, an anchor of midnight and a
+/// period of one hour ticks every hour on the hour.
+///
+/// # Panics
+///
+/// Panics if the period is zero
+pub fn interval_at_wall<Tz: TimeZone + Clone>(
+	period: Duration,
+	anchor: DateTime<Tz>,
+) -> WallInterval<Tz> {
+	WallInterval(WallSchedule::new(period, &anchor))
+}
+
+/// An interval which ticks at wall-clock-aligned times.
+/// See [`interval_at_wall`].
+#[derive(Clone, Debug)]
+pub struct WallInterval<Tz: TimeZone>(WallSchedule<Tz>);
+
+impl<Tz: TimeZone + Clone> WallInterval<Tz> {
+	/// Waits until the next tick, and returns the time it was scheduled for.
+	///
+	/// If ticks were missed, such as when the system clock jumps forward, they
+	/// are skipped. If the system clock jumps backward, then ticks aren't
+	/// repeated.
+	pub async fn tick(&mut self) -> DateTime<Tz> {
+		let deadline = self.0.next_deadline();
+		sleep_until(deadline.clone()).await;
+		deadline
+	}
+}
@@ -1,12 +1,34 @@
-use crate::{Date, NaiveDateTime};
+use core::time::Duration;
 
+use crate::NaiveDateTime;
+
+const NANOS_PER_SECOND: i128 = 1_000_000_000;
+
+/// The number of seconds and nanoseconds since the Unix epoch, not counting
+/// leap seconds.
+///
+/// A leap second is represented by a nanosecond value of at least one billion,
+/// added to the second before it. So, 23:59:60.5 has the same number of
+/// seconds as 23:59:59, and 1.5 billion nanoseconds. This means that a leap
+/// second can be converted to a timestamp and back without losing anything.
 #[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct Timestamp {
 	seconds: i64,
 	nanoseconds: u32,
 }
 
 impl Timestamp {
+	/// The earliest timestamp which can be represented
+	pub const MIN: Self = Self::new(i64::MIN, 0);
+
+	/// The latest timestamp which can be represented. This is during a leap
+	/// second, since those sort after the rest of their second.
+	pub const MAX: Self = Self::new(i64::MAX, 1_999_999_999);
+
+	/// The Unix epoch, which is midnight UTC on January 1, 1970
+	pub const EPOCH: Self = Self::new(0, 0);
+
 	#[must_use]
 	pub const fn new(seconds: i64, nanoseconds: u32) -> Self {
 		Self {
@@ -15,6 +37,31 @@ impl Timestamp {
 		}
 	}
 
+	/// Gets the current time, according to the system clock.
+	///
+	/// This uses the most precise wall clock available. On Windows, this is
+	/// `GetSystemTimePreciseAsFileTime`, which has a resolution of 100
+	/// nanoseconds, rather than the 15 millisecond resolution of
+	/// `GetSystemTimeAsFileTime`. Use [`CoarseClock`](crate::clock::CoarseClock)
+	/// if precision isn't needed.
+	#[cfg(feature = "std")]
+	#[must_use]
+	pub fn now() -> Self {
+		use std::time::SystemTime;
+
+		match SystemTime::now().duration_since(SystemTime::UNIX_EPOCH) {
+			Ok(duration) => Self::new(duration.as_secs() as i64, duration.subsec_nanos()),
+			Err(error) => {
+				// the nanoseconds are always after the seconds
+				let duration = error.duration();
+				match duration.subsec_nanos() {
+					0 => Self::new(-(duration.as_secs() as i64), 0),
+					nanos => Self::new(-(duration.as_secs() as i64) - 1, 1_000_000_000 - nanos),
+				}
+			}
+		}
+	}
+
 	#[must_use]
 	pub const fn total_seconds(self) -> i64 {
 		self.seconds
@@ -25,71 +72,314 @@ impl Timestamp {
 		self.nanoseconds
 	}
 
+	/// Checks if this timestamp is during a leap second
 	#[must_use]
-	pub const fn add_days_overflowing(self, days: i64) -> (Self, bool) {
-		let (seconds, overflowing) = self.seconds.overflowing_add(days as i64 * 3600 * 24);
+	pub const fn is_leap_second(self) -> bool {
+		self.nanoseconds >= 1_000_000_000
+	}
 
-		let timestamp = Self::new(seconds, self.nanoseconds);
-		(timestamp, overflowing)
+	/// Gets the earlier of two timestamps. Unlike [`Ord::min`], this can be
+	/// used in a `const`.
+	#[must_use]
+	pub const fn min(self, other: Self) -> Self {
+		if other.is_before(self) {
+			other
+		} else {
+			self
+		}
 	}
 
+	/// Gets the later of two timestamps. Unlike [`Ord::max`], this can be used
+	/// in a `const`.
 	#[must_use]
-	pub const fn add_hours_overflowing(self, hours: i64) -> (Self, bool) {
-		let (seconds, overflowing) = self.seconds.overflowing_add(hours as i64 * 3600);
+	pub const fn max(self, other: Self) -> Self {
+		if other.is_before(self) {
+			self
+		} else {
+			other
+		}
+	}
 
-		let timestamp = Self::new(seconds, self.nanoseconds);
-		(timestamp, overflowing)
+	/// Restricts the timestamp to be from `min` to `max`. Unlike [`Ord::clamp`],
+	/// this can be used in a `const`.
+	///
+	/// # Panics
+	///
+	/// Panics if `min` is after `max`
+	#[must_use]
+	pub const fn clamp(self, min: Self, max: Self) -> Self {
+		assert!(!max.is_before(min), "the minimum is after the maximum");
+		if self.is_before(min) {
+			min
+		} else if max.is_before(self) {
+			max
+		} else {
+			self
+		}
 	}
 
+	/// Checks if this timestamp is before the other one
+	const fn is_before(self, other: Self) -> bool {
+		self.seconds < other.seconds
+			|| (self.seconds == other.seconds && self.nanoseconds < other.nanoseconds)
+	}
+
+	/// Creates a timestamp from a number of milliseconds since the Unix epoch,
+	/// which is how JavaScript and many databases store times
 	#[must_use]
-	pub const fn add_minutes_overflowing(self, minutes: i64) -> (Self, bool) {
-		let (seconds, overflowing) = self.seconds.overflowing_add(minutes as i64 * 60);
+	pub const fn from_millis(milliseconds: i64) -> Self {
+		let nanoseconds = milliseconds.rem_euclid(1_000) as u32 * 1_000_000;
+		Self::new(milliseconds.div_euclid(1_000), nanoseconds)
+	}
 
-		let timestamp = Self::new(seconds, self.nanoseconds);
-		(timestamp, overflowing)
+	/// Creates a timestamp from a number of microseconds since the Unix epoch
+	#[must_use]
+	pub const fn from_micros(microseconds: i64) -> Self {
+		let nanoseconds = microseconds.rem_euclid(1_000_000) as u32 * 1_000;
+		Self::new(microseconds.div_euclid(1_000_000), nanoseconds)
 	}
 
+	/// Creates a timestamp from a number of nanoseconds since the Unix epoch,
+	/// or returns `None` if the number of seconds doesn't fit in an `i64`
 	#[must_use]
-	pub const fn add_seconds_overflowing(self, seconds: i64) -> (Self, bool) {
-		// TODO overflowing goes first
-		let (seconds, overflowing) = self.seconds.overflowing_add(seconds as i64);
+	pub const fn from_nanos(nanoseconds: i128) -> Option<Self> {
+		let seconds = nanoseconds.div_euclid(NANOS_PER_SECOND);
+		if seconds < i64::MIN as i128 || seconds > i64::MAX as i128 {
+			return None;
+		}
+
+		let nanoseconds = nanoseconds.rem_euclid(NANOS_PER_SECOND);
+		Some(Self::new(seconds as i64, nanoseconds as u32))
+	}
+
+	/// Gets the number of milliseconds since the Unix epoch, rounded down. A
+	/// leap second is counted as part of the second after it.
+	#[must_use]
+	pub const fn as_millis(self) -> i128 {
+		self.as_nanos().div_euclid(1_000_000)
+	}
+
+	/// Gets the number of microseconds since the Unix epoch, rounded down. A
+	/// leap second is counted as part of the second after it.
+	#[must_use]
+	pub const fn as_micros(self) -> i128 {
+		self.as_nanos().div_euclid(1_000)
+	}
+
+	/// Gets the number of nanoseconds since the Unix epoch. A leap second is
+	/// counted as part of the second after it.
+	#[must_use]
+	pub const fn as_nanos(self) -> i128 {
+		self.seconds as i128 * NANOS_PER_SECOND + self.nanoseconds as i128
+	}
 
-		let timestamp = Self::new(seconds, self.nanoseconds);
-		(timestamp, overflowing)
+	/// Creates a timestamp from a fractional number of seconds since the Unix
+	/// epoch, such as the value of Python's `time.time()`. Returns `None` if
+	/// the number isn't finite, or if it's too large for a timestamp.
+	///
+	/// An `f64` has 53 bits of precision, so times near the present are only
+	/// precise to within a few hundred nanoseconds. The result is rounded to
+	/// the nearest nanosecond.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use botic::Timestamp;
+	///
+	/// let timestamp = Timestamp::from_secs_f64(-1.25).unwrap();
+	/// assert_eq!(timestamp, Timestamp::new(-2, 750_000_000));
+	/// assert_eq!(Timestamp::from_secs_f64(f64::NAN), None);
+	/// ```
+	#[must_use]
+	pub const fn from_secs_f64(seconds: f64) -> Option<Self> {
+		// this is false for NaN
+		if !(seconds >= i64::MIN as f64 && seconds < i64::MAX as f64) {
+			return None;
+		}
+
+		// round down, since casting rounds towards zero
+		let mut whole_seconds = seconds as i64;
+		if (whole_seconds as f64) > seconds {
+			whole_seconds -= 1;
+		}
+
+		let fraction = seconds - whole_seconds as f64;
+		let nanoseconds = (fraction * NANOS_PER_SECOND as f64 + 0.5) as u32;
+		if nanoseconds < NANOS_PER_SECOND as u32 {
+			Some(Self::new(whole_seconds, nanoseconds))
+		} else if whole_seconds == i64::MAX {
+			None
+		} else {
+			Some(Self::new(whole_seconds + 1, 0))
+		}
+	}
+
+	/// Gets the number of seconds since the Unix epoch as an `f64`. A leap
+	/// second is counted as part of the second after it.
+	///
+	/// An `f64` has 53 bits of precision, so times near the present lose some
+	/// of their nanoseconds.
+	#[must_use]
+	pub const fn as_secs_f64(self) -> f64 {
+		self.seconds as f64 + self.nanoseconds as f64 / NANOS_PER_SECOND as f64
+	}
+
+	/// Gets the number of whole periods which have passed from `t0` until
+	/// this timestamp. This is the time step which is used by TOTP (RFC 6238),
+	/// and by rate limiters with fixed windows. A time before `t0` is in the
+	/// first step, which is zero.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use core::time::Duration;
+	/// use botic::Timestamp;
+	///
+	/// let period = Duration::from_secs(30);
+	/// let now = Timestamp::new(1_234_567_890, 0);
+	/// assert_eq!(now.time_step(period, Timestamp::EPOCH), 41_152_263);
+	/// ```
+	///
+	/// # Panics
+	///
+	/// Panics if the period is zero
+	#[must_use]
+	pub const fn time_step(self, period: Duration, t0: Self) -> u64 {
+		let step = self.elapsed_steps(period, t0);
+		if step > u64::MAX as i128 {
+			u64::MAX
+		} else {
+			step as u64
+		}
+	}
+
+	/// Gets the start of the time step which contains this timestamp. See
+	/// [`Timestamp::time_step`].
+	///
+	/// # Panics
+	///
+	/// Panics if the period is zero
+	#[must_use]
+	pub const fn time_step_start(self, period: Duration, t0: Self) -> Self {
+		let start = t0.as_nanos() + self.elapsed_steps(period, t0) * period.as_nanos() as i128;
+		match Self::from_nanos(start) {
+			Some(start) => start,
+			None => unreachable!(),
+		}
+	}
+
+	/// Gets the end of the time step which contains this timestamp, which is
+	/// the start of the next step. Returns `None` if the end can't be
+	/// represented. See [`Timestamp::time_step`].
+	///
+	/// # Example
+	///
+	/// ```
+	/// use core::time::Duration;
+	/// use botic::Timestamp;
+	///
+	/// let period = Duration::from_secs(30);
+	/// let now = Timestamp::new(1_234_567_890, 0);
+	/// assert_eq!(now.time_step_start(period, Timestamp::EPOCH), Timestamp::new(1_234_567_890, 0));
+	/// assert_eq!(now.time_step_end(period, Timestamp::EPOCH), Some(Timestamp::new(1_234_567_920, 0)));
+	/// ```
+	///
+	/// # Panics
+	///
+	/// Panics if the period is zero
+	#[must_use]
+	pub const fn time_step_end(self, period: Duration, t0: Self) -> Option<Self> {
+		let start = self.time_step_start(period, t0);
+		Self::from_nanos(start.as_nanos() + period.as_nanos() as i128)
+	}
+
+	/// Counts the whole periods from `t0` until this timestamp, or zero if
+	/// this timestamp is before `t0`
+	const fn elapsed_steps(self, period: Duration, t0: Self) -> i128 {
+		assert!(!period.is_zero(), "the period must not be zero");
+
+		let elapsed = self.as_nanos() - t0.as_nanos();
+		if elapsed <= 0 {
+			return 0;
+		}
+
+		elapsed / period.as_nanos() as i128
+	}
+
+	/// Adds a number of days. This returns a tuple of the result and a
+	/// boolean indicating if it was out of range, in which case it wraps
+	/// around.
+	#[must_use]
+	pub const fn add_days_overflowing(self, days: i64) -> (Self, bool) {
+		self.add_total_nanoseconds_overflowing(days as i128 * 86_400 * NANOS_PER_SECOND)
+	}
+
+	/// Adds a number of hours. See [`Timestamp::add_days_overflowing`].
+	#[must_use]
+	pub const fn add_hours_overflowing(self, hours: i64) -> (Self, bool) {
+		self.add_total_nanoseconds_overflowing(hours as i128 * 3600 * NANOS_PER_SECOND)
+	}
+
+	/// Adds a number of minutes. See [`Timestamp::add_days_overflowing`].
+	#[must_use]
+	pub const fn add_minutes_overflowing(self, minutes: i64) -> (Self, bool) {
+		self.add_total_nanoseconds_overflowing(minutes as i128 * 60 * NANOS_PER_SECOND)
 	}
 
+	/// Adds a number of seconds. See [`Timestamp::add_days_overflowing`].
+	#[must_use]
+	pub const fn add_seconds_overflowing(self, seconds: i64) -> (Self, bool) {
+		self.add_total_nanoseconds_overflowing(seconds as i128 * NANOS_PER_SECOND)
+	}
+
+	/// Adds a number of nanoseconds. See [`Timestamp::add_days_overflowing`].
 	#[must_use]
 	pub const fn add_nanoseconds_overflowing(self, nanoseconds: i64) -> (Self, bool) {
-		let total_nanos = (self.nanoseconds as i64 + nanoseconds) % 1_000_000_000;
-		let total_nanos = total_nanos + (1_000_000_000 * total_nanos.is_negative() as i64);
-		let added_seconds = (self.nanoseconds as i64 + nanoseconds) / 1_000_000_000;
-		let total_seconds = (self.seconds as i64 + added_seconds) % 60;
-		let overflow = 0 > total_seconds;
-		let total_seconds = total_seconds + (60 * total_seconds.is_negative() as i64);
+		self.add_total_nanoseconds_overflowing(nanoseconds as i128)
+	}
 
-		let timestamp = Self::new(total_seconds, total_nanos as u32);
-		(timestamp, overflow)
+	/// Adds a number of nanoseconds, wrapping around if the result is out of
+	/// range.
+	///
+	/// A leap second lasts for one second, so the result stays in the leap
+	/// second until the rest of it has been added, and then continues from
+	/// the start of the next second. Going backwards out of a leap second
+	/// continues from the end of the second before it.
+	const fn add_total_nanoseconds_overflowing(self, nanoseconds: i128) -> (Self, bool) {
+		let total = if self.is_leap_second() {
+			let into_leap_second = self.nanoseconds as i128 - NANOS_PER_SECOND + nanoseconds;
+			if into_leap_second >= 0 && into_leap_second < NANOS_PER_SECOND {
+				return (
+					Self::new(self.seconds, (NANOS_PER_SECOND + into_leap_second) as u32),
+					false,
+				);
+			} else if into_leap_second < 0 {
+				// the leap second started at the end of this second
+				self.seconds as i128 * NANOS_PER_SECOND + NANOS_PER_SECOND + into_leap_second
+			} else {
+				// and the next second starts when it ends
+				self.seconds as i128 * NANOS_PER_SECOND + into_leap_second
+			}
+		} else {
+			self.as_nanos() + nanoseconds
+		};
+
+		let seconds = total.div_euclid(NANOS_PER_SECOND);
+		let overflow = seconds < i64::MIN as i128 || seconds > i64::MAX as i128;
+		let nanoseconds = total.rem_euclid(NANOS_PER_SECOND) as u32;
+		(Self::new(seconds as i64, nanoseconds), overflow)
 	}
 }
 
 impl From<NaiveDateTime> for Timestamp {
 	fn from(ndt: NaiveDateTime) -> Self {
-		const UNIX_EPOCH_DAYS: i64 = Date::UNIX_EPOCH.days_after_common_era();
-		// TODO don't require the .date()
-		let days = (ndt.date().days_after_common_era() - UNIX_EPOCH_DAYS) as i64;
-		let seconds = days * 86_400 + i64::from(ndt.time().seconds_from_midnight());
-		let nanoseconds = ndt.nanosecond();
-
-		Self::new(seconds, nanoseconds)
+		ndt.timestamp()
 	}
 }
 
 impl PartialOrd for Timestamp {
 	fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
-		match self.seconds.partial_cmp(&other.seconds) {
-			Some(core::cmp::Ordering::Equal) => self.nanoseconds.partial_cmp(&other.nanoseconds),
-			ord => ord,
-		}
+		Some(self.cmp(other))
 	}
 }
 
@@ -101,3 +391,273 @@ impl Ord for Timestamp {
 		}
 	}
 }
+
+/// The number of nanoseconds since the Unix epoch, not counting leap seconds.
+///
+/// This is how databases like ClickHouse and kdb+ exchange times. Since it's a
+/// single number, arithmetic doesn't need to carry from the nanoseconds into
+/// the seconds, like it does for a [`Timestamp`]. Many formats store this as
+/// an `i64`, which covers the years 1677 through 2262.
+///
+/// A leap second can't be represented, so it's counted as part of the second
+/// after it. For example, 23:59:60.5 becomes 00:00:00.5 on the next day.
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct UnixNanos(i128);
+
+impl UnixNanos {
+	#[must_use]
+	pub const fn new(nanoseconds: i128) -> Self {
+		Self(nanoseconds)
+	}
+
+	#[must_use]
+	pub const fn from_i64(nanoseconds: i64) -> Self {
+		Self(nanoseconds as i128)
+	}
+
+	#[must_use]
+	pub const fn as_i128(self) -> i128 {
+		self.0
+	}
+
+	/// Gets the number of nanoseconds as an `i64`, or `None` if it's outside
+	/// of the years 1677 through 2262
+	#[must_use]
+	pub const fn to_i64(self) -> Option<i64> {
+		if self.0 < i64::MIN as i128 || self.0 > i64::MAX as i128 {
+			return None;
+		}
+
+		Some(self.0 as i64)
+	}
+
+	/// Converts a timestamp into nanoseconds. A leap second is counted as part
+	/// of the second after it.
+	#[must_use]
+	pub const fn from_timestamp(timestamp: Timestamp) -> Self {
+		Self(timestamp.as_nanos())
+	}
+
+	/// Converts into a timestamp, or returns `None` if the number of seconds
+	/// doesn't fit in an `i64`
+	#[must_use]
+	pub const fn to_timestamp(self) -> Option<Timestamp> {
+		Timestamp::from_nanos(self.0)
+	}
+
+	#[must_use]
+	pub const fn add_nanoseconds_overflowing(self, nanoseconds: i128) -> (Self, bool) {
+		let (nanoseconds, overflow) = self.0.overflowing_add(nanoseconds);
+		(Self(nanoseconds), overflow)
+	}
+}
+
+impl From<i64> for UnixNanos {
+	fn from(nanoseconds: i64) -> Self {
+		Self::from_i64(nanoseconds)
+	}
+}
+
+impl From<Timestamp> for UnixNanos {
+	fn from(timestamp: Timestamp) -> Self {
+		Self::from_timestamp(timestamp)
+	}
+}
+
+impl From<NaiveDateTime> for UnixNanos {
+	fn from(ndt: NaiveDateTime) -> Self {
+		ndt.unix_nanos()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn integer_units() {
+		assert_eq!(
+			Timestamp::from_millis(1_500),
+			Timestamp::new(1, 500_000_000)
+		);
+		assert_eq!(Timestamp::from_millis(-1), Timestamp::new(-1, 999_000_000));
+		assert_eq!(
+			Timestamp::from_micros(-1_000_001),
+			Timestamp::new(-2, 999_999_000)
+		);
+		assert_eq!(
+			Timestamp::from_nanos(-1),
+			Some(Timestamp::new(-1, 999_999_999))
+		);
+		assert_eq!(Timestamp::from_nanos(i128::MAX), None);
+
+		let timestamp = Timestamp::new(-2, 999_999_999);
+		assert_eq!(timestamp.as_millis(), -1_001);
+		assert_eq!(timestamp.as_micros(), -1_000_001);
+		assert_eq!(timestamp.as_nanos(), -1_000_000_001);
+		assert_eq!(
+			Timestamp::from_millis(i64::MIN).as_millis(),
+			i64::MIN.into()
+		);
+		assert_eq!(
+			Timestamp::MAX.as_millis(),
+			i128::from(i64::MAX) * 1_000 + 1_999
+		);
+	}
+
+	#[test]
+	fn float_seconds() {
+		assert_eq!(
+			Timestamp::from_secs_f64(1.5),
+			Some(Timestamp::new(1, 500_000_000))
+		);
+		assert_eq!(Timestamp::from_secs_f64(-0.0), Some(Timestamp::EPOCH));
+		assert_eq!(
+			Timestamp::from_secs_f64(-0.000_000_000_1),
+			Some(Timestamp::EPOCH)
+		);
+		assert_eq!(Timestamp::from_secs_f64(f64::INFINITY), None);
+		assert_eq!(Timestamp::from_secs_f64(1e19), None);
+		assert_eq!(
+			Timestamp::from_secs_f64(i64::MIN as f64),
+			Some(Timestamp::MIN)
+		);
+
+		let timestamp = Timestamp::from_secs_f64(1_700_000_000.25).unwrap();
+		assert_eq!(timestamp, Timestamp::new(1_700_000_000, 250_000_000));
+		assert_eq!(timestamp.as_secs_f64(), 1_700_000_000.25);
+		assert_eq!(Timestamp::new(-2, 750_000_000).as_secs_f64(), -1.25);
+	}
+
+	#[test]
+	fn const_clamp() {
+		const CLAMPED: Timestamp = Timestamp::MAX.clamp(Timestamp::EPOCH, Timestamp::new(0, 1));
+		assert_eq!(CLAMPED, Timestamp::new(0, 1));
+		assert_eq!(
+			Timestamp::MIN.clamp(Timestamp::EPOCH, Timestamp::MAX),
+			Timestamp::EPOCH
+		);
+		assert_eq!(
+			Timestamp::new(5, 0).max(Timestamp::new(4, 1_500_000_000)),
+			Timestamp::new(5, 0)
+		);
+	}
+
+	#[test]
+	fn time_steps() {
+		// the test vectors from RFC 6238
+		let period = Duration::from_secs(30);
+		for (seconds, step) in [
+			(59, 1),
+			(1_111_111_109, 37_037_036),
+			(1_111_111_111, 37_037_037),
+			(1_234_567_890, 41_152_263),
+			(2_000_000_000, 66_666_666),
+			(20_000_000_000, 666_666_666),
+		] {
+			let timestamp = Timestamp::new(seconds, 0);
+			assert_eq!(timestamp.time_step(period, Timestamp::EPOCH), step);
+		}
+
+		let t0 = Timestamp::new(100, 0);
+		let early = Timestamp::new(50, 0);
+		assert_eq!(early.time_step(period, t0), 0);
+		assert_eq!(early.time_step_start(period, t0), t0);
+
+		let timestamp = Timestamp::new(-31, 500);
+		assert_eq!(
+			timestamp.time_step_start(Duration::from_secs(10), Timestamp::new(-100, 0)),
+			Timestamp::new(-40, 0)
+		);
+		assert_eq!(Timestamp::MAX.time_step_end(period, Timestamp::MIN), None);
+		assert_eq!(
+			Timestamp::MAX.time_step(Duration::from_nanos(1), Timestamp::MIN),
+			u64::MAX
+		);
+	}
+
+	#[test]
+	fn add_carries() {
+		let timestamp = Timestamp::new(1_483_228_799, 500_000_000);
+		assert_eq!(
+			timestamp.add_nanoseconds_overflowing(600_000_000),
+			(Timestamp::new(1_483_228_800, 100_000_000), false)
+		);
+		assert_eq!(
+			timestamp.add_nanoseconds_overflowing(-1_600_000_000),
+			(Timestamp::new(1_483_228_797, 900_000_000), false)
+		);
+		assert_eq!(
+			timestamp.add_seconds_overflowing(61),
+			(Timestamp::new(1_483_228_860, 500_000_000), false)
+		);
+		assert_eq!(
+			timestamp.add_days_overflowing(-1),
+			(Timestamp::new(1_483_142_399, 500_000_000), false)
+		);
+		assert_eq!(
+			Timestamp::new(i64::MAX, 0).add_seconds_overflowing(1),
+			(Timestamp::new(i64::MIN, 0), true)
+		);
+	}
+
+	#[test]
+	fn add_across_leap_second() {
+		// 2016-12-31 23:59:60.5
+		let leap_second = Timestamp::new(1_483_228_799, 1_500_000_000);
+		assert_eq!(
+			leap_second.add_nanoseconds_overflowing(400_000_000),
+			(Timestamp::new(1_483_228_799, 1_900_000_000), false)
+		);
+		assert_eq!(
+			leap_second.add_seconds_overflowing(1),
+			(Timestamp::new(1_483_228_800, 500_000_000), false)
+		);
+		assert_eq!(
+			leap_second.add_seconds_overflowing(-1),
+			(Timestamp::new(1_483_228_799, 500_000_000), false)
+		);
+		assert_eq!(
+			leap_second.add_minutes_overflowing(1),
+			(Timestamp::new(1_483_228_859, 500_000_000), false)
+		);
+	}
+
+	#[test]
+	fn constants() {
+		assert!(Timestamp::MIN < Timestamp::EPOCH);
+		assert!(Timestamp::MAX > Timestamp::new(i64::MAX, 999_999_999));
+		assert!(Timestamp::MAX.is_leap_second());
+	}
+
+	#[test]
+	fn unix_nanos_round_trip() {
+		for (seconds, nanoseconds) in [(0, 0), (-1, 999_999_999), (1_700_000_000, 5)] {
+			let timestamp = Timestamp::new(seconds, nanoseconds);
+			let nanos = UnixNanos::from(timestamp);
+			assert_eq!(nanos.to_timestamp(), Some(timestamp));
+		}
+
+		assert_eq!(
+			UnixNanos::from_i64(-1).to_timestamp(),
+			Some(Timestamp::new(-1, 999_999_999))
+		);
+		assert_eq!(UnixNanos::new(i128::MAX).to_timestamp(), None);
+	}
+
+	#[test]
+	fn unix_nanos_leap_second() {
+		let leap_second = Timestamp::new(1_483_228_799, 1_500_000_000);
+		assert_eq!(
+			UnixNanos::from(leap_second).to_timestamp(),
+			Some(Timestamp::new(1_483_228_800, 500_000_000))
+		);
+	}
+
+	#[test]
+	fn unix_nanos_to_i64() {
+		assert_eq!(UnixNanos::from_i64(i64::MIN).to_i64(), Some(i64::MIN));
+		assert_eq!(UnixNanos::new(i128::from(i64::MAX) + 1).to_i64(), None);
+	}
+}
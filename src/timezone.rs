@@ -1,6 +1,28 @@
+use crate::error::{check_range, ComponentRange};
+#[cfg(feature = "std")]
+use crate::sys;
+#[cfg(feature = "alloc")]
+use crate::{Date, DateRange, Time};
 use crate::{DateTime, NaiveDateTime};
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, boxed::Box, string::ToString, sync::Arc};
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+#[cfg(feature = "alloc")]
+use core::any::Any;
 use core::convert::Infallible;
+#[cfg(feature = "alloc")]
+use core::error::Error;
 use core::fmt::Display;
+#[cfg(feature = "alloc")]
+use core::ops::Range;
+use core::str::FromStr;
+#[cfg(feature = "std")]
+use std::time::SystemTime;
+
+#[cfg(feature = "std")]
+use parking_lot::Mutex;
+use thiserror::Error;
 
 /// A type that can be used to represent a `TimeZone`
 pub trait TimeZone: Sized + Eq + Display {
@@ -17,6 +39,186 @@ pub trait TimeZone: Sized + Eq + Display {
 	/// This returns an Err if the given `NaiveDateTime` cannot exist in this timezone.
 	/// For example, the time may have been skipped because of daylight savings time.
 	fn offset_from_local_naive(&self, date_time: NaiveDateTime) -> Result<UtcOffset, Self::Err>;
+
+	/// Gets the name of the time zone, such as `UTC` or `UTC+5:30`, so that
+	/// generic code can name the time zone. This is the `Display` output by
+	/// default, but time zones with a fixed name can avoid allocating.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use botic::tai::Tai;
+	/// use botic::timezone::{ArcTimeZone, Utc, UtcOffset};
+	/// use botic::TimeZone;
+	///
+	/// fn describe<Tz: TimeZone>(timezone: &Tz) -> String {
+	///     format!("all times are in {}", timezone.name())
+	/// }
+	///
+	/// assert_eq!(describe(&Utc), "all times are in UTC");
+	/// assert_eq!(describe(&UtcOffset::from_hours(-5).unwrap()), "all times are in UTC-5");
+	/// assert_eq!(describe(&ArcTimeZone::new(Tai::new())), "all times are in TAI");
+	/// ```
+	#[cfg(feature = "alloc")]
+	fn name(&self) -> Cow<'_, str> {
+		Cow::Owned(self.to_string())
+	}
+}
+
+/// An object-safe version of [`TimeZone`], which is implemented for every
+/// thread-safe `TimeZone` whose error type implements [`Error`].
+///
+/// This allows time zones to be chosen at runtime. See [`ArcTimeZone`] for a
+/// `TimeZone` which wraps this trait.
+#[cfg(feature = "alloc")]
+pub trait TimeZoneDyn: Display + Send + Sync {
+	/// Given the time in the UTC timezone, determine the `UtcOffset`
+	fn dyn_utc_offset(&self, date_time: DateTime<Utc>) -> UtcOffset;
+
+	/// Given the local date and time, figure out the offset from UTC
+	///
+	/// # Errors
+	///
+	/// This returns an Err if the given `NaiveDateTime` cannot exist in this timezone.
+	fn dyn_offset_from_local_naive(
+		&self,
+		date_time: NaiveDateTime,
+	) -> Result<UtcOffset, Box<dyn Error + Send + Sync>>;
+
+	/// Gets the name of the time zone
+	fn dyn_name(&self) -> Cow<'_, str>;
+
+	/// Checks if this time zone is equal to another time zone
+	fn dyn_eq(&self, other: &dyn TimeZoneDyn) -> bool;
+
+	/// Gets the time zone as `Any`, so that it can be downcast
+	fn as_any(&self) -> &dyn Any;
+}
+
+#[cfg(feature = "alloc")]
+impl<Tz> TimeZoneDyn for Tz
+where
+	Tz: TimeZone + Send + Sync + 'static,
+	Tz::Err: Error + Send + Sync + 'static,
+{
+	fn dyn_utc_offset(&self, date_time: DateTime<Utc>) -> UtcOffset {
+		TimeZone::utc_offset(self, date_time)
+	}
+
+	fn dyn_offset_from_local_naive(
+		&self,
+		date_time: NaiveDateTime,
+	) -> Result<UtcOffset, Box<dyn Error + Send + Sync>> {
+		TimeZone::offset_from_local_naive(self, date_time).map_err(|e| Box::new(e) as _)
+	}
+
+	fn dyn_name(&self) -> Cow<'_, str> {
+		TimeZone::name(self)
+	}
+
+	fn dyn_eq(&self, other: &dyn TimeZoneDyn) -> bool {
+		other
+			.as_any()
+			.downcast_ref::<Self>()
+			.is_some_and(|other| self == other)
+	}
+
+	fn as_any(&self) -> &dyn Any {
+		self
+	}
+}
+
+/// A reference-counted time zone whose type is chosen at runtime.
+///
+/// This can be used to store different kinds of time zones in one collection.
+///
+/// # Example
+///
+/// ```
+/// use botic::tai::Tai;
+/// use botic::timezone::{ArcTimeZone, Utc, UtcOffset};
+///
+/// let zones = [
+///     ArcTimeZone::new(Utc),
+///     ArcTimeZone::new(UtcOffset::from_hours(-5).unwrap()),
+///     ArcTimeZone::new(Tai::new()),
+/// ];
+///
+/// assert_eq!("UTC-5", zones[1].to_string());
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Clone)]
+pub struct ArcTimeZone(Arc<dyn TimeZoneDyn>);
+
+#[cfg(feature = "alloc")]
+impl ArcTimeZone {
+	/// Wraps the given time zone
+	#[must_use]
+	pub fn new(timezone: impl TimeZoneDyn + 'static) -> Self {
+		Self(Arc::new(timezone))
+	}
+
+	/// Gets the wrapped time zone
+	#[must_use]
+	pub fn inner(&self) -> &dyn TimeZoneDyn {
+		&*self.0
+	}
+
+	/// Gets the wrapped time zone if it's of the given type
+	#[must_use]
+	pub fn downcast_ref<Tz: TimeZoneDyn + 'static>(&self) -> Option<&Tz> {
+		self.0.as_any().downcast_ref()
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl From<Arc<dyn TimeZoneDyn>> for ArcTimeZone {
+	fn from(timezone: Arc<dyn TimeZoneDyn>) -> Self {
+		Self(timezone)
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl core::fmt::Debug for ArcTimeZone {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		f.debug_tuple("ArcTimeZone")
+			.field(&format_args!("{}", self.0))
+			.finish()
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl PartialEq for ArcTimeZone {
+	fn eq(&self, other: &Self) -> bool {
+		self.0.dyn_eq(&*other.0)
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl Eq for ArcTimeZone {}
+
+#[cfg(feature = "alloc")]
+impl Display for ArcTimeZone {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		self.0.fmt(f)
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl TimeZone for ArcTimeZone {
+	type Err = Box<dyn Error + Send + Sync>;
+
+	fn utc_offset(&self, date_time: DateTime<Utc>) -> UtcOffset {
+		self.0.dyn_utc_offset(date_time)
+	}
+
+	fn offset_from_local_naive(&self, date_time: NaiveDateTime) -> Result<UtcOffset, Self::Err> {
+		self.0.dyn_offset_from_local_naive(date_time)
+	}
+
+	fn name(&self) -> Cow<'_, str> {
+		self.0.dyn_name()
+	}
 }
 
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
@@ -33,6 +235,11 @@ impl TimeZone for Utc {
 	fn offset_from_local_naive(&self, _: NaiveDateTime) -> Result<UtcOffset, Self::Err> {
 		Ok(UtcOffset::UTC)
 	}
+
+	#[cfg(feature = "alloc")]
+	fn name(&self) -> Cow<'_, str> {
+		Cow::Borrowed("UTC")
+	}
 }
 
 impl Display for Utc {
@@ -41,6 +248,21 @@ impl Display for Utc {
 	}
 }
 
+impl FromStr for Utc {
+	type Err = ParseTimeZoneError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s {
+			"UTC" | "Z" => Ok(Utc),
+			_ => Err(ParseTimeZoneError),
+		}
+	}
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Error)]
+#[error("Failed to parse the time zone")]
+pub struct ParseTimeZoneError;
+
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
 /// A timezone with a fixed offset from UTC
 pub struct UtcOffset {
@@ -51,11 +273,49 @@ impl UtcOffset {
 	/// The UTC Timezone, represented as an offset
 	pub const UTC: Self = Self { offset_seconds: 0 };
 
+	/// The furthest offset behind UTC which can be constructed, UTC-26
+	pub const MIN: Self = Self {
+		offset_seconds: -26 * 3600,
+	};
+
+	/// The furthest offset ahead of UTC which can be constructed, UTC+26
+	pub const MAX: Self = Self {
+		offset_seconds: 26 * 3600,
+	};
+
 	/// Makes a new `UtcOffset` timezone with the given timezone difference.
 	/// A positive number is the Eastern hemisphere. A negative number behind
 	/// UTC, such as UTC-5.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use botic::timezone::UtcOffset;
+	///
+	/// assert_eq!(-18_000, UtcOffset::from_seconds(-18_000).unwrap().seconds_ahead());
+	/// assert!(UtcOffset::from_seconds(300 * 3600).is_err());
+	/// ```
+	///
+	/// # Errors
+	///
+	/// Returns an error if the offset is outside of the range `MIN..=MAX`
+	pub const fn from_seconds(seconds: i32) -> Result<Self, ComponentRange> {
+		check_range!(
+			"offset seconds",
+			seconds,
+			Self::MIN.offset_seconds,
+			Self::MAX.offset_seconds
+		);
+
+		Ok(Self::from_seconds_unchecked(seconds))
+	}
+
+	/// Makes a new `UtcOffset` timezone with the given timezone difference,
+	/// without checking that it's within the range `MIN..=MAX`.
+	/// A positive number is the Eastern hemisphere. A negative number behind
+	/// UTC, such as UTC-5.
 	#[must_use]
-	pub const fn from_seconds(seconds: i32) -> Self {
+	pub const fn from_seconds_unchecked(seconds: i32) -> Self {
 		Self {
 			offset_seconds: seconds,
 		}
@@ -64,9 +324,23 @@ impl UtcOffset {
 	/// Makes a new `UtcOffset` timezone with the given timezone difference.
 	/// A positive number is the Eastern hemisphere. A negative number is
 	/// behind UTC, such as UTC-5.
+	///
+	/// # Errors
+	///
+	/// Returns an error if the offset is outside of the range `MIN..=MAX`
+	pub const fn from_hours(hours: i32) -> Result<Self, ComponentRange> {
+		check_range!("offset hours", hours, -26, 26);
+
+		Ok(Self::from_hours_unchecked(hours))
+	}
+
+	/// Makes a new `UtcOffset` timezone with the given timezone difference,
+	/// without checking that it's within the range `MIN..=MAX`.
+	/// A positive number is the Eastern hemisphere. A negative number is
+	/// behind UTC, such as UTC-5.
 	#[must_use]
-	pub const fn from_hours(hours: i32) -> Self {
-		Self::from_seconds(hours * 3600)
+	pub const fn from_hours_unchecked(hours: i32) -> Self {
+		Self::from_seconds_unchecked(hours * 3600)
 	}
 
 	/// The number of hours this timezone is ahead of UTC. This number is
@@ -82,6 +356,586 @@ impl UtcOffset {
 	pub const fn seconds_ahead(self) -> i32 {
 		self.offset_seconds
 	}
+
+	/// Gets the system's current offset from UTC.
+	///
+	/// If the `TZ` environment variable describes a fixed offset, such as
+	/// `UTC`, `:Etc/UTC`, or the POSIX rule `EST5`, then that offset is used.
+	///
+	/// Otherwise, on Unix systems, the offset is found using `localtime_r`,
+	/// which handles daylight saving time rules and zone references such as
+	/// `:America/Chicago`, and falls back to `/etc/localtime` if `TZ` isn't
+	/// set. But `localtime_r` reads the environment, and modifying the
+	/// environment while it is being read is undefined behavior on most
+	/// platforms. There's no way to prevent another thread from doing that.
+	/// So this function will only call `localtime_r` if it can verify that
+	/// the process is single-threaded, which is currently only possible on
+	/// Linux. Otherwise, an error is returned. See
+	/// [`UtcOffset::current_local_unchecked`] for a version without this
+	/// check.
+	///
+	/// # Errors
+	///
+	/// Returns an error if the process might have multiple threads, if the
+	/// platform is unsupported, or if the offset is outside of `MIN..=MAX`.
+	#[cfg(feature = "std")]
+	pub fn current_local() -> Result<Self, IndeterminateOffsetError> {
+		let unix_seconds = match SystemTime::now().duration_since(SystemTime::UNIX_EPOCH) {
+			Ok(duration) => duration.as_secs() as i64,
+			Err(ste) => -(ste.duration().as_secs() as i64),
+		};
+
+		Self::local_at(unix_seconds)
+	}
+
+	/// Gets the system's offset from UTC at the given Unix timestamp.
+	/// See [`UtcOffset::current_local`] for when this can fail.
+	#[cfg(feature = "std")]
+	pub(crate) fn local_at(unix_seconds: i64) -> Result<Self, IndeterminateOffsetError> {
+		// std synchronizes its own access to the environment, so this is safe
+		if let Some(offset) = std::env::var("TZ")
+			.ok()
+			.and_then(|tz| fixed_offset_from_tz(&tz))
+		{
+			return Ok(offset);
+		}
+
+		if !sys::is_single_threaded() {
+			return Err(IndeterminateOffsetError);
+		}
+
+		unsafe { Self::local_at_unchecked(unix_seconds) }
+	}
+
+	/// Gets the system's current offset from UTC, without making sure that
+	/// it's safe to read the environment.
+	///
+	/// # Errors
+	///
+	/// Returns an error if the platform is unsupported, or if the offset is
+	/// outside of `MIN..=MAX`.
+	///
+	/// # Safety
+	///
+	/// No other thread may modify the environment while this is running.
+	/// For example, calling `std::env::set_var` from another thread at the
+	/// same time results in undefined behavior.
+	///
+	/// Unlike [`UtcOffset::current_local`], this always uses `localtime_r`
+	/// on Unix systems, which also reads the `TZ` environment variable.
+	#[cfg(feature = "std")]
+	pub unsafe fn current_local_unchecked() -> Result<Self, IndeterminateOffsetError> {
+		let unix_seconds = match SystemTime::now().duration_since(SystemTime::UNIX_EPOCH) {
+			Ok(duration) => duration.as_secs() as i64,
+			Err(ste) => -(ste.duration().as_secs() as i64),
+		};
+
+		Self::local_at_unchecked(unix_seconds)
+	}
+
+	/// Gets the system's offset from UTC at the given Unix timestamp
+	///
+	/// # Safety
+	///
+	/// No other thread may modify the environment while this is running.
+	#[cfg(feature = "std")]
+	pub(crate) unsafe fn local_at_unchecked(
+		unix_seconds: i64,
+	) -> Result<Self, IndeterminateOffsetError> {
+		let offset_seconds = sys::local_offset_at(unix_seconds).ok_or(IndeterminateOffsetError)?;
+		Self::from_seconds(offset_seconds).map_err(|_| IndeterminateOffsetError)
+	}
+
+	/// Gets the offset represented by a military time zone letter.
+	///
+	/// `Z` is UTC, `A` through `M` (skipping `J`) are UTC+1 through UTC+12,
+	/// and `N` through `Y` are UTC-1 through UTC-12. Lowercase letters are
+	/// also accepted. `J` refers to the observer's local time, so it doesn't
+	/// have a fixed offset.
+	///
+	/// Note that RFC 822 got the signs of these zones backwards, so RFC 2822
+	/// recommends treating any letter other than `Z` in an email as `-0000`.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use botic::timezone::UtcOffset;
+	///
+	/// assert_eq!(Some(UtcOffset::UTC), UtcOffset::from_military_letter('Z'));
+	/// assert_eq!(UtcOffset::from_hours(10).ok(), UtcOffset::from_military_letter('K'));
+	/// assert_eq!(UtcOffset::from_hours(-12).ok(), UtcOffset::from_military_letter('y'));
+	/// assert_eq!(None, UtcOffset::from_military_letter('J'));
+	/// ```
+	#[must_use]
+	pub const fn from_military_letter(letter: char) -> Option<Self> {
+		let hours = match letter.to_ascii_uppercase() {
+			'Z' => 0,
+			letter @ 'A'..='I' => letter as i32 - 'A' as i32 + 1,
+			letter @ 'K'..='M' => letter as i32 - 'K' as i32 + 10,
+			letter @ 'N'..='Y' => -(letter as i32 - 'N' as i32 + 1),
+			_ => return None,
+		};
+
+		Some(Self::from_hours_unchecked(hours))
+	}
+
+	/// Gets the military time zone letter for this offset.
+	/// Returns `None` if the offset isn't a whole number of hours between
+	/// UTC-12 and UTC+12.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use botic::timezone::UtcOffset;
+	///
+	/// assert_eq!(Some('Z'), UtcOffset::UTC.military_letter());
+	/// assert_eq!(Some('M'), UtcOffset::from_hours(12).unwrap().military_letter());
+	/// assert_eq!(Some('N'), UtcOffset::from_hours(-1).unwrap().military_letter());
+	/// assert_eq!(None, UtcOffset::from_seconds(1800).unwrap().military_letter());
+	/// ```
+	#[must_use]
+	pub const fn military_letter(self) -> Option<char> {
+		if self.offset_seconds % 3600 != 0 {
+			return None;
+		}
+
+		let letter = match self.offset_seconds / 3600 {
+			0 => b'Z',
+			hours @ 1..=9 => b'A' + hours as u8 - 1,
+			hours @ 10..=12 => b'K' + hours as u8 - 10,
+			hours @ -12..=-1 => b'N' + (-hours) as u8 - 1,
+			_ => return None,
+		};
+
+		Some(letter as char)
+	}
+
+	/// Gets the offset of a common time zone abbreviation, such as `EST` or
+	/// `CEST`, ignoring letter case. This is meant for logs and other text
+	/// which only records the abbreviation. Returns `None` if the abbreviation
+	/// isn't known.
+	///
+	/// Some abbreviations are used by more than one time zone. Each of these
+	/// always resolves to the zone which uses it most widely, which matches
+	/// how most software and RFC 2822 interpret it:
+	///
+	/// | Abbreviation | Resolves to              | Also used for                       |
+	/// |--------------|--------------------------|-------------------------------------|
+	/// | `AST`        | Atlantic, UTC-4          | Arabia, UTC+3                       |
+	/// | `BST`        | British Summer, UTC+1    | Bangladesh, UTC+6                   |
+	/// | `CST`        | Central (US), UTC-6      | China, UTC+8, and Cuba, UTC-5       |
+	/// | `GST`        | Gulf, UTC+4              | South Georgia, UTC-2                |
+	/// | `IST`        | India, UTC+05:30         | Ireland, UTC+1, and Israel, UTC+2   |
+	/// | `MST`        | Mountain (US), UTC-7     | Malaysia, UTC+8                     |
+	///
+	/// An abbreviation only gives the offset which was in effect, so this
+	/// can't be used to find the time zone itself.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use botic::timezone::UtcOffset;
+	///
+	/// assert_eq!(UtcOffset::from_hours(9).ok(), UtcOffset::from_abbreviation("JST"));
+	/// assert_eq!(UtcOffset::from_hours(2).ok(), UtcOffset::from_abbreviation("cest"));
+	/// assert_eq!(UtcOffset::from_hours(-6).ok(), UtcOffset::from_abbreviation("CST"));
+	/// assert_eq!(None, UtcOffset::from_abbreviation("XYZ"));
+	/// ```
+	#[cfg(feature = "abbreviations")]
+	#[must_use]
+	pub fn from_abbreviation(abbreviation: &str) -> Option<Self> {
+		ABBREVIATIONS
+			.iter()
+			.find(|(name, _)| name.eq_ignore_ascii_case(abbreviation))
+			.map(|(_, seconds)| Self::from_seconds_unchecked(*seconds))
+	}
+
+	/// Parses an offset from one of its common textual forms.
+	///
+	/// The accepted forms are a military time zone letter such as `Z` (see
+	/// [`UtcOffset::from_military_letter`]), or a signed offset such as
+	/// `+05:30`, `-0800`, or `+01`, optionally prefixed with `UTC` or `GMT`.
+	/// When prefixed, the hour may be a single digit, such as `UTC+2`.
+	/// Seconds may be included, as in `+05:30:15`. The output of this type's
+	/// `Display` implementation is always accepted.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use botic::timezone::UtcOffset;
+	///
+	/// assert_eq!(Ok(UtcOffset::UTC), UtcOffset::parse("Z"));
+	/// assert_eq!(UtcOffset::from_seconds(19_800).unwrap(), UtcOffset::parse("+05:30").unwrap());
+	/// assert_eq!(UtcOffset::from_hours(-8).unwrap(), UtcOffset::parse("-0800").unwrap());
+	/// assert_eq!(UtcOffset::from_hours(2).unwrap(), UtcOffset::parse("UTC+2").unwrap());
+	/// ```
+	///
+	/// # Errors
+	///
+	/// Returns an error if the string is not in one of the above forms, if
+	/// the minute or second of the offset is greater than 59, or if the
+	/// offset is outside of the range `MIN..=MAX`.
+	pub fn parse(s: &str) -> Result<Self, ParseUtcOffsetError> {
+		if s.is_empty() {
+			return Err(ParseUtcOffsetError::Empty);
+		}
+
+		if let [letter] = s.as_bytes() {
+			return Self::from_military_letter(char::from(*letter))
+				.ok_or(ParseUtcOffsetError::InvalidFormat);
+		}
+
+		let (body, prefixed) = match s.strip_prefix("UTC").or_else(|| s.strip_prefix("GMT")) {
+			Some("") => return Ok(Self::UTC),
+			Some(body) => (body, true),
+			None => (s, false),
+		};
+
+		let body = body.as_bytes();
+		let negative = match body.first() {
+			Some(b'+') => false,
+			Some(b'-') => true,
+			_ => return Err(ParseUtcOffsetError::MissingSign),
+		};
+		let body = &body[1..];
+
+		let hour_digits = body.iter().take_while(|b| b.is_ascii_digit()).count();
+		let hour_digits = if prefixed && hour_digits == 1 { 1 } else { 2 };
+		let hours = parse_digits(body, hour_digits)?;
+		let rest = &body[hour_digits..];
+
+		let (minutes, seconds) = match rest {
+			[] => (0, 0),
+			[b':', m1, m2] => (parse_digits(&[*m1, *m2], 2)?, 0),
+			[b':', m1, m2, b':', s1, s2] => {
+				(parse_digits(&[*m1, *m2], 2)?, parse_digits(&[*s1, *s2], 2)?)
+			}
+			[m1, m2] if hour_digits == 2 => (parse_digits(&[*m1, *m2], 2)?, 0),
+			[m1, m2, s1, s2] if hour_digits == 2 => {
+				(parse_digits(&[*m1, *m2], 2)?, parse_digits(&[*s1, *s2], 2)?)
+			}
+			_ => return Err(ParseUtcOffsetError::InvalidFormat),
+		};
+
+		if minutes >= 60 {
+			return Err(ParseUtcOffsetError::ComponentOutOfRange("minute"));
+		}
+
+		if seconds >= 60 {
+			return Err(ParseUtcOffsetError::ComponentOutOfRange("second"));
+		}
+
+		let offset_seconds = hours * 3600 + minutes * 60 + seconds;
+		let offset_seconds = if negative {
+			-offset_seconds
+		} else {
+			offset_seconds
+		};
+
+		Self::from_seconds(offset_seconds)
+			.map_err(|_| ParseUtcOffsetError::ComponentOutOfRange("hour"))
+	}
+}
+
+/// Common time zone abbreviations, and their offsets in seconds. See
+/// [`UtcOffset::from_abbreviation`] for how ambiguous abbreviations are
+/// chosen.
+#[cfg(feature = "abbreviations")]
+const ABBREVIATIONS: [(&str, i32); 56] = [
+	("ACDT", 37_800),
+	("ACST", 34_200),
+	("ADT", -10_800),
+	("AEDT", 39_600),
+	("AEST", 36_000),
+	("AKDT", -28_800),
+	("AKST", -32_400),
+	("ART", -10_800),
+	("AST", -14_400),
+	("AWST", 28_800),
+	("BRT", -10_800),
+	("BST", 3_600),
+	("CAT", 7_200),
+	("CDT", -18_000),
+	("CEST", 7_200),
+	("CET", 3_600),
+	("CST", -21_600),
+	("EAT", 10_800),
+	("EDT", -14_400),
+	("EEST", 10_800),
+	("EET", 7_200),
+	("EST", -18_000),
+	("GMT", 0),
+	("GST", 14_400),
+	("HDT", -32_400),
+	("HKT", 28_800),
+	("HST", -36_000),
+	("ICT", 25_200),
+	("IDT", 10_800),
+	("IST", 19_800),
+	("JST", 32_400),
+	("KST", 32_400),
+	("MDT", -21_600),
+	("MEST", 7_200),
+	("MET", 3_600),
+	("MSK", 10_800),
+	("MST", -25_200),
+	("NDT", -9_000),
+	("NPT", 20_700),
+	("NST", -12_600),
+	("NZDT", 46_800),
+	("NZST", 43_200),
+	("PDT", -25_200),
+	("PHT", 28_800),
+	("PKT", 18_000),
+	("PST", -28_800),
+	("SAST", 7_200),
+	("SGT", 28_800),
+	("TRT", 10_800),
+	("UT", 0),
+	("UTC", 0),
+	("WAT", 3_600),
+	("WEST", 3_600),
+	("WET", 0),
+	("WIB", 25_200),
+	("WIT", 32_400),
+];
+
+/// Gets the offset described by a value of the `TZ` environment variable, if
+/// it has a fixed offset. Returns `None` if the offset can change, or if the
+/// value refers to a time zone file which would need to be loaded.
+#[cfg(feature = "std")]
+fn fixed_offset_from_tz(tz: &str) -> Option<UtcOffset> {
+	// a leading colon means that the rest is the name of a time zone
+	let (name, is_reference) = match tz.strip_prefix(':') {
+		Some(name) => (name, true),
+		None => (tz, false),
+	};
+
+	match name {
+		"" | "UTC" | "UCT" | "GMT" | "Zulu" | "Etc/UTC" | "Etc/UCT" | "Etc/GMT" | "Etc/Zulu" => {
+			return Some(UtcOffset::UTC)
+		}
+		_ => (),
+	}
+
+	// Etc/GMT+5 is five hours behind UTC
+	if let Some(hours) = name.strip_prefix("Etc/GMT") {
+		let hours: i32 = hours.strip_prefix('+').unwrap_or(hours).parse().ok()?;
+		return UtcOffset::from_hours(-hours).ok();
+	}
+
+	if is_reference {
+		return None;
+	}
+
+	// a POSIX rule such as EST5 or <+0530>-5:30, which is only fixed if no
+	// daylight saving time zone comes after the offset
+	let rest = match name.strip_prefix('<') {
+		Some(quoted) => &quoted[quoted.find('>')? + 1..],
+		None => {
+			let abbreviation_len = name.bytes().take_while(u8::is_ascii_alphabetic).count();
+			if abbreviation_len < 3 {
+				return None;
+			}
+
+			&name[abbreviation_len..]
+		}
+	};
+
+	let (negative, rest) = match rest.as_bytes().first() {
+		Some(b'-') => (true, &rest[1..]),
+		Some(b'+') => (false, &rest[1..]),
+		_ => (false, rest),
+	};
+
+	let mut seconds = 0;
+	for (i, part) in rest.split(':').enumerate() {
+		if i > 2 || part.is_empty() || part.len() > 2 || !part.bytes().all(|b| b.is_ascii_digit()) {
+			return None;
+		}
+
+		let value: i32 = part.parse().ok()?;
+		seconds += value * [3600, 60, 1][i];
+	}
+
+	// POSIX offsets are the time added to local time to get UTC
+	let seconds = if negative { seconds } else { -seconds };
+	UtcOffset::from_seconds(seconds).ok()
+}
+
+/// Parses the first `count` bytes of the input as an ASCII decimal number
+fn parse_digits(bytes: &[u8], count: usize) -> Result<i32, ParseUtcOffsetError> {
+	let Some(digits) = bytes.get(..count) else {
+		return Err(ParseUtcOffsetError::InvalidFormat);
+	};
+
+	digits.iter().try_fold(0, |total, digit| {
+		if digit.is_ascii_digit() {
+			Ok(total * 10 + i32::from(digit - b'0'))
+		} else {
+			Err(ParseUtcOffsetError::InvalidFormat)
+		}
+	})
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Error)]
+#[error("Failed to determine the local UTC offset")]
+pub struct IndeterminateOffsetError;
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Error)]
+pub enum ParseUtcOffsetError {
+	#[error("Tried to parse an empty string as a UTC offset")]
+	Empty,
+	#[error("Expected a UTC offset to start with '+' or '-'")]
+	MissingSign,
+	#[error("The UTC offset was not in a recognized format")]
+	InvalidFormat,
+	#[error("The {0} of the UTC offset is out of range")]
+	ComponentOutOfRange(&'static str),
+}
+
+/// How to choose an instant for a local time which is skipped or repeated
+/// because the offset from UTC changed, such as when daylight saving time
+/// starts or ends
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub enum LocalTimePolicy {
+	/// Use the later instant for a skipped time, and the earlier one for a
+	/// repeated time. This is what most operating systems do, so an alarm for
+	/// a skipped time goes off right after the clocks change.
+	#[default]
+	Compatible,
+	/// Use the earlier of the two instants. A skipped time is moved back by
+	/// the length of the gap, so 02:30 becomes 01:30.
+	Earlier,
+	/// Use the later of the two instants. A skipped time is moved forward by
+	/// the length of the gap, so 02:30 becomes 03:30.
+	Later,
+	/// Return a [`LocalTimeError`]
+	Reject,
+}
+
+/// A local date and time couldn't be converted into an instant
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Error)]
+pub enum LocalTimeError {
+	/// The local time was skipped by a change in the offset from UTC
+	#[error("The local time doesn't exist, because it was skipped by a change in the UTC offset")]
+	Skipped,
+	/// The local time happened twice, because of a change in the offset from
+	/// UTC
+	#[error("The local time is ambiguous, because it was repeated by a change in the UTC offset")]
+	Repeated,
+	/// The date and time is outside of the representable range
+	#[error("The date and time is outside of the representable range")]
+	OutOfRange,
+}
+
+/// Finds when the working hours in several time zones overlap.
+///
+/// Each window is a time zone, and the local times when work starts and ends
+/// in it. If the end is before the start, then the window continues past
+/// midnight, and if they're equal, then the window lasts a whole day. A
+/// window which would end after [`Date::MAX`] is treated as empty. The date
+/// is in the time zone of the first window, and the other windows are checked
+/// on whichever of their days overlap with it. Local times which are skipped
+/// or repeated are resolved with [`LocalTimePolicy::Compatible`].
+///
+/// The overlapping ranges are returned in order. There's usually at most one,
+/// but a long window can overlap with two days of another window. An empty
+/// list means that the windows don't overlap.
+///
+/// Use [`ArcTimeZone`] to compare different kinds of time zones.
+///
+/// # Example
+///
+/// ```
+/// use botic::{date, datetime, time};
+/// use botic::timezone::{self, UtcOffset};
+///
+/// let new_york = UtcOffset::from_hours(-4).unwrap();
+/// let london = UtcOffset::from_hours(1).unwrap();
+/// let overlap = timezone::overlapping_hours(
+///     date!(2024-06-10),
+///     &[(new_york, time!(09:00)..time!(17:00)), (london, time!(09:00)..time!(17:00))],
+/// );
+///
+/// assert_eq!(overlap, [datetime!(2024-06-10 13:00 UTC)..datetime!(2024-06-10 16:00 UTC)]);
+/// ```
+#[cfg(feature = "alloc")]
+#[must_use]
+pub fn overlapping_hours<Tz: TimeZone + Clone>(
+	date: Date,
+	windows: &[(Tz, Range<Time>)],
+) -> Vec<Range<DateTime<Utc>>> {
+	let Some(((first_zone, first_hours), rest)) = windows.split_first() else {
+		return Vec::new();
+	};
+
+	let Some(first_window) = window_in_utc(date, first_zone, first_hours) else {
+		return Vec::new();
+	};
+
+	let mut overlap = vec![first_window.clone()];
+	for (timezone, hours) in rest {
+		let local_date = |date_time: DateTime<Utc>| {
+			date_time
+				.into_timezone(timezone.clone())
+				.to_naive_local_saturating()
+				.date()
+		};
+		// a window which started the day before can still be going
+		let first_day = local_date(first_window.start).add_days_overflowing(-1).0;
+		let last_day = local_date(first_window.end);
+		let ranges: Vec<_> = DateRange::from(first_day..=last_day)
+			.filter_map(|date| window_in_utc(date, timezone, hours))
+			.collect();
+
+		overlap = overlap
+			.iter()
+			.flat_map(|a| {
+				ranges
+					.iter()
+					.map(|b| a.start.max(b.start)..a.end.min(b.end))
+			})
+			.filter(|range| !range.is_empty())
+			.collect();
+	}
+
+	overlap.sort_by_key(|range| range.start);
+	overlap
+}
+
+/// Gets the range of UTC times in a local window on the given date
+#[cfg(feature = "alloc")]
+fn window_in_utc<Tz: TimeZone + Clone>(
+	date: Date,
+	timezone: &Tz,
+	hours: &Range<Time>,
+) -> Option<Range<DateTime<Utc>>> {
+	let end_date = if hours.end <= hours.start {
+		let (next_day, overflow) = date.add_days_overflowing(1);
+		if overflow {
+			return None;
+		}
+		next_day
+	} else {
+		date
+	};
+
+	let in_utc = |local: NaiveDateTime| {
+		DateTime::from_local_with_policy(local, timezone.clone(), LocalTimePolicy::Compatible)
+			.ok()
+			.map(|date_time| date_time.as_utc())
+	};
+
+	Some(in_utc(date.at(hours.start))?..in_utc(end_date.at(hours.end))?)
+}
+
+impl FromStr for UtcOffset {
+	type Err = ParseUtcOffsetError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Self::parse(s)
+	}
 }
 
 impl Display for UtcOffset {
@@ -126,10 +980,201 @@ impl TimeZone for UtcOffset {
 	}
 }
 
+/// The number of offsets remembered by a [`CachedTimeZone`]
+#[cfg(feature = "std")]
+const CACHE_CAPACITY: usize = 16;
+
+/// A small least-recently-used cache of offsets, keyed by the minute they were looked up for
+#[cfg(feature = "std")]
+#[derive(Debug, Default)]
+struct OffsetCache {
+	/// The cached offsets, and when each one was last used
+	entries: Vec<(NaiveDateTime, UtcOffset, u64)>,
+	/// Counts up each time the cache is used
+	clock: u64,
+}
+
+#[cfg(feature = "std")]
+impl OffsetCache {
+	const fn new() -> Self {
+		Self {
+			entries: Vec::new(),
+			clock: 0,
+		}
+	}
+
+	fn tick(&mut self) -> u64 {
+		self.clock += 1;
+		self.clock
+	}
+
+	fn get(&mut self, key: NaiveDateTime) -> Option<UtcOffset> {
+		let now = self.tick();
+		let entry = self.entries.iter_mut().find(|(k, _, _)| *k == key)?;
+		entry.2 = now;
+		Some(entry.1)
+	}
+
+	fn insert(&mut self, key: NaiveDateTime, offset: UtcOffset) {
+		let now = self.tick();
+		if let Some(entry) = self.entries.iter_mut().find(|(k, _, _)| *k == key) {
+			*entry = (key, offset, now);
+		} else if self.entries.len() < CACHE_CAPACITY {
+			self.entries.push((key, offset, now));
+		} else if let Some(oldest) = self.entries.iter_mut().min_by_key(|(_, _, used)| *used) {
+			*oldest = (key, offset, now);
+		}
+	}
+
+	fn clear(&mut self) {
+		self.entries.clear();
+	}
+}
+
+/// Truncates the given time to the start of its minute
+#[cfg(feature = "std")]
+fn minute_key(date_time: NaiveDateTime) -> NaiveDateTime {
+	let time = Time::from_hms_unchecked(date_time.hour(), date_time.minute(), 0);
+	NaiveDateTime::new(date_time.date(), time)
+}
+
+/// Gets an offset from the cache, or looks it up and caches it. The cache
+/// isn't locked during the lookup.
+///
+/// The offset is only cached if it's the same at the start and end of the
+/// minute. Some time zones change their offset in the middle of a minute,
+/// such as TAI, whose local time changes offset 37 seconds into the minute
+/// after a leap second.
+#[cfg(feature = "std")]
+fn get_or_lookup<E>(
+	cache: &Mutex<OffsetCache>,
+	date_time: NaiveDateTime,
+	lookup: impl Fn(NaiveDateTime) -> Result<UtcOffset, E>,
+) -> Result<UtcOffset, E> {
+	let key = minute_key(date_time);
+	if let Some(offset) = cache.lock().get(key) {
+		return Ok(offset);
+	}
+
+	let start = lookup(key);
+	let (end, _) = key.add_nanoseconds_overflowing(59_999_999_999);
+	match start {
+		Ok(offset) if lookup(end).is_ok_and(|other| other == offset) => {
+			cache.lock().insert(key, offset);
+			Ok(offset)
+		}
+		start if date_time == key => start,
+		_ => lookup(date_time),
+	}
+}
+
+/// A wrapper around a `TimeZone` which remembers recently calculated offsets.
+///
+/// This is useful for time zones whose offsets are expensive to compute, and
+/// which are queried repeatedly for similar times, such as when logging.
+/// Offsets are cached by the minute. If the offset changes during a minute,
+/// that minute isn't cached, so each lookup goes to the wrapped time zone.
+#[cfg(feature = "std")]
+pub struct CachedTimeZone<Tz: TimeZone> {
+	timezone: Tz,
+	utc_cache: Mutex<OffsetCache>,
+	local_cache: Mutex<OffsetCache>,
+}
+
+#[cfg(feature = "std")]
+impl<Tz: TimeZone> CachedTimeZone<Tz> {
+	/// Wraps the given time zone with an empty cache
+	#[must_use]
+	pub const fn new(timezone: Tz) -> Self {
+		Self {
+			timezone,
+			utc_cache: Mutex::new(OffsetCache::new()),
+			local_cache: Mutex::new(OffsetCache::new()),
+		}
+	}
+
+	/// Get the time zone being cached
+	#[must_use]
+	pub const fn inner(&self) -> &Tz {
+		&self.timezone
+	}
+
+	/// Unwraps the cached time zone, discarding the cache
+	#[must_use]
+	pub fn into_inner(self) -> Tz {
+		self.timezone
+	}
+
+	/// Forget all of the cached offsets.
+	/// This should be called if the underlying time zone data changes.
+	pub fn clear(&self) {
+		self.utc_cache.lock().clear();
+		self.local_cache.lock().clear();
+	}
+}
+
+#[cfg(feature = "std")]
+impl<Tz: TimeZone + Clone> Clone for CachedTimeZone<Tz> {
+	fn clone(&self) -> Self {
+		Self::new(self.timezone.clone())
+	}
+}
+
+#[cfg(feature = "std")]
+impl<Tz: TimeZone + core::fmt::Debug> core::fmt::Debug for CachedTimeZone<Tz> {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		f.debug_struct("CachedTimeZone")
+			.field("timezone", &self.timezone)
+			.finish_non_exhaustive()
+	}
+}
+
+#[cfg(feature = "std")]
+impl<Tz: TimeZone> PartialEq for CachedTimeZone<Tz> {
+	fn eq(&self, other: &Self) -> bool {
+		self.timezone == other.timezone
+	}
+}
+
+#[cfg(feature = "std")]
+impl<Tz: TimeZone> Eq for CachedTimeZone<Tz> {}
+
+#[cfg(feature = "std")]
+impl<Tz: TimeZone> Display for CachedTimeZone<Tz> {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		self.timezone.fmt(f)
+	}
+}
+
+#[cfg(feature = "std")]
+impl<Tz: TimeZone> TimeZone for CachedTimeZone<Tz> {
+	type Err = Tz::Err;
+
+	fn utc_offset(&self, date_time: DateTime<Utc>) -> UtcOffset {
+		let lookup =
+			|utc| Ok::<_, Infallible>(self.timezone.utc_offset(DateTime::from_utc(utc, Utc)));
+		match get_or_lookup(&self.utc_cache, date_time.naive_utc(), lookup) {
+			Ok(offset) => offset,
+			Err(infallible) => match infallible {},
+		}
+	}
+
+	fn offset_from_local_naive(&self, date_time: NaiveDateTime) -> Result<UtcOffset, Self::Err> {
+		get_or_lookup(&self.local_cache, date_time, |local| {
+			self.timezone.offset_from_local_naive(local)
+		})
+	}
+
+	fn name(&self) -> Cow<'_, str> {
+		self.timezone.name()
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
 
+	#[cfg(feature = "alloc")]
 	#[test]
 	fn utc_offset_display_no_offset() {
 		let offset = UtcOffset::UTC;
@@ -137,24 +1182,283 @@ mod tests {
 		assert_eq!(offset_str, "UTC");
 	}
 
+	#[cfg(feature = "alloc")]
 	#[test]
 	fn utc_offset_display_positive_offset() {
-		let offset = UtcOffset::from_hours(1);
+		let offset = UtcOffset::from_hours_unchecked(1);
 		let offset_str = offset.to_string();
 		assert_eq!(offset_str, "UTC+1");
 	}
 
+	#[cfg(feature = "alloc")]
 	#[test]
 	fn utc_offset_display_minute_offset() {
-		let offset = UtcOffset::from_seconds(60);
+		let offset = UtcOffset::from_seconds_unchecked(60);
 		let offset_str = offset.to_string();
 		assert_eq!(offset_str, "UTC+00:01");
 	}
 
+	#[cfg(feature = "alloc")]
 	#[test]
 	fn utc_offset_display_second_offset() {
-		let offset = UtcOffset::from_seconds(-32);
+		let offset = UtcOffset::from_seconds_unchecked(-32);
 		let offset_str = offset.to_string();
 		assert_eq!(offset_str, "UTC-00:00:32");
 	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn cached_timezone_matches_inner() {
+		let timezone = CachedTimeZone::new(UtcOffset::from_hours_unchecked(-5));
+		let date_time = DateTime::from_utc(
+			NaiveDateTime::new(crate::Date::UNIX_EPOCH, Time::MIDNIGHT),
+			Utc,
+		);
+
+		assert_eq!(
+			timezone.utc_offset(date_time),
+			UtcOffset::from_hours_unchecked(-5)
+		);
+		assert_eq!(
+			timezone.utc_offset(date_time),
+			UtcOffset::from_hours_unchecked(-5)
+		);
+		assert_eq!(timezone.to_string(), "UTC-5");
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn cached_timezone_offset_changes_within_a_minute() {
+		use crate::tai::Tai;
+
+		let timezone = CachedTimeZone::new(Tai::new());
+		let local = |second| {
+			let time = Time::from_hms(0, 0, second).unwrap();
+			NaiveDateTime::new(crate::date!(2017 - 01 - 01), time)
+		};
+
+		// TAI's offset went from 36 to 37 seconds at 00:00:37 TAI
+		for second in [40, 10, 40, 10] {
+			assert_eq!(
+				timezone.offset_from_local_naive(local(second)),
+				Tai::new().offset_from_local_naive(local(second))
+			);
+		}
+		assert!(timezone.local_cache.lock().entries.is_empty());
+
+		let utc = DateTime::from_utc(local(30), Utc);
+		assert_eq!(timezone.utc_offset(utc).seconds_ahead(), 37);
+		assert_eq!(timezone.utc_cache.lock().entries.len(), 1);
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn lookups_happen_without_the_cache_locked() {
+		let cache = Mutex::new(OffsetCache::new());
+		let lookups = core::cell::Cell::new(0);
+		let lookup = |_| {
+			assert!(cache.try_lock().is_some());
+			lookups.set(lookups.get() + 1);
+			Ok::<_, Infallible>(UtcOffset::UTC)
+		};
+
+		let date_time =
+			NaiveDateTime::new(crate::Date::UNIX_EPOCH, Time::from_hms_unchecked(1, 2, 3));
+		assert_eq!(get_or_lookup(&cache, date_time, lookup), Ok(UtcOffset::UTC));
+		assert_eq!(lookups.get(), 2);
+		assert_eq!(get_or_lookup(&cache, date_time, lookup), Ok(UtcOffset::UTC));
+		assert_eq!(lookups.get(), 2);
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn offset_cache_evicts_least_recently_used() {
+		let key = |minute| {
+			let time = Time::from_hms(1, minute, 0).unwrap();
+			NaiveDateTime::new(crate::Date::UNIX_EPOCH, time)
+		};
+
+		let mut cache = OffsetCache::new();
+		for minute in 0..CACHE_CAPACITY as u8 {
+			cache.insert(
+				key(minute),
+				UtcOffset::from_seconds_unchecked(minute.into()),
+			);
+		}
+
+		assert_eq!(cache.get(key(0)), Some(UtcOffset::UTC));
+		cache.insert(key(59), UtcOffset::UTC);
+		assert_eq!(cache.get(key(0)), Some(UtcOffset::UTC));
+		assert_eq!(cache.get(key(1)), None);
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn utc_offset_parse_round_trips_display() {
+		for seconds in [0, 3600, -18_000, 19_800, 60, -32, 45_296] {
+			let offset = UtcOffset::from_seconds_unchecked(seconds);
+			assert_eq!(UtcOffset::parse(&offset.to_string()), Ok(offset));
+		}
+	}
+
+	#[test]
+	fn utc_offset_parse_forms() {
+		let expected = UtcOffset::from_seconds_unchecked(-(8 * 3600 + 30 * 60));
+		assert_eq!(UtcOffset::parse("-08:30"), Ok(expected));
+		assert_eq!(UtcOffset::parse("-0830"), Ok(expected));
+		assert_eq!(UtcOffset::parse("GMT-08:30"), Ok(expected));
+		assert_eq!(UtcOffset::parse("+00"), Ok(UtcOffset::UTC));
+		assert_eq!(UtcOffset::parse("UTC"), Ok(UtcOffset::UTC));
+	}
+
+	#[test]
+	fn utc_offset_parse_errors() {
+		assert_eq!(UtcOffset::parse(""), Err(ParseUtcOffsetError::Empty));
+		assert_eq!(
+			UtcOffset::parse("05:30"),
+			Err(ParseUtcOffsetError::MissingSign)
+		);
+		assert_eq!(
+			UtcOffset::parse("+5:30"),
+			Err(ParseUtcOffsetError::InvalidFormat)
+		);
+		assert_eq!(
+			UtcOffset::parse("+05:3"),
+			Err(ParseUtcOffsetError::InvalidFormat)
+		);
+		assert_eq!(
+			UtcOffset::parse("+0530:00"),
+			Err(ParseUtcOffsetError::InvalidFormat)
+		);
+		assert_eq!(
+			UtcOffset::parse("+05:60"),
+			Err(ParseUtcOffsetError::ComponentOutOfRange("minute"))
+		);
+		assert_eq!(
+			UtcOffset::parse("+26:01"),
+			Err(ParseUtcOffsetError::ComponentOutOfRange("hour"))
+		);
+	}
+
+	#[test]
+	fn utc_offset_range() {
+		assert_eq!(UtcOffset::from_hours(26), Ok(UtcOffset::MAX));
+		assert_eq!(UtcOffset::from_hours(-26), Ok(UtcOffset::MIN));
+		assert!(UtcOffset::from_hours(27).is_err());
+		assert!(UtcOffset::from_hours(i32::MIN).is_err());
+		assert!(UtcOffset::from_seconds(UtcOffset::MAX.seconds_ahead() + 1).is_err());
+		assert!(UtcOffset::from_seconds(UtcOffset::MIN.seconds_ahead() - 1).is_err());
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn arc_timezone_equality() {
+		let utc = ArcTimeZone::new(Utc);
+		let offset = ArcTimeZone::new(UtcOffset::UTC);
+
+		assert_eq!(utc, ArcTimeZone::new(Utc));
+		assert_ne!(utc, offset);
+		assert_eq!(offset.downcast_ref::<UtcOffset>(), Some(&UtcOffset::UTC));
+		assert_eq!(offset.downcast_ref::<Utc>(), None);
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn military_letters_round_trip() {
+		for letter in ('A'..='Z').filter(|letter| *letter != 'J') {
+			let offset = UtcOffset::from_military_letter(letter).unwrap();
+			assert_eq!(offset.military_letter(), Some(letter));
+			assert_eq!(UtcOffset::parse(&letter.to_string()), Ok(offset));
+		}
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn fixed_offsets_from_tz() {
+		let offset = |seconds| Some(UtcOffset::from_seconds_unchecked(seconds));
+		assert_eq!(fixed_offset_from_tz(""), Some(UtcOffset::UTC));
+		assert_eq!(fixed_offset_from_tz(":Etc/UTC"), Some(UtcOffset::UTC));
+		assert_eq!(fixed_offset_from_tz("UTC0"), Some(UtcOffset::UTC));
+		assert_eq!(fixed_offset_from_tz("Etc/GMT+5"), offset(-18_000));
+		assert_eq!(fixed_offset_from_tz("EST5"), offset(-18_000));
+		assert_eq!(fixed_offset_from_tz("<+0530>-5:30"), offset(19_800));
+		assert_eq!(fixed_offset_from_tz("JST-9"), offset(32_400));
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn variable_offsets_from_tz() {
+		assert_eq!(fixed_offset_from_tz(":America/Chicago"), None);
+		assert_eq!(fixed_offset_from_tz("America/Chicago"), None);
+		assert_eq!(fixed_offset_from_tz("EST5EDT,M3.2.0,M11.1.0"), None);
+		assert_eq!(fixed_offset_from_tz("EST"), None);
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn overlapping_working_hours() {
+		use crate::{date, datetime, time};
+
+		#[rustfmt::skip]
+		let date = date!(2024-06-10);
+		let zone = |hours| UtcOffset::from_hours(hours).unwrap();
+
+		// a night shift in New York overlaps with the next morning in Tokyo
+		let overlap = overlapping_hours(
+			date,
+			&[
+				(zone(-4), time!(20:00)..time!(02:00)),
+				(zone(9), time!(09:00)..time!(18:00)),
+			],
+		);
+		assert_eq!(
+			overlap,
+			[datetime!(2024-06-11 00:00 UTC)..datetime!(2024-06-11 06:00 UTC)]
+		);
+
+		// a whole day overlaps with two of the windows in another time zone
+		let overlap = overlapping_hours(
+			date,
+			&[
+				(zone(0), time!(00:00)..time!(00:00)),
+				(zone(12), time!(06:00)..time!(18:00)),
+			],
+		);
+		assert_eq!(
+			overlap,
+			[
+				datetime!(2024-06-10 00:00 UTC)..datetime!(2024-06-10 06:00 UTC),
+				datetime!(2024-06-10 18:00 UTC)..datetime!(2024-06-11 00:00 UTC),
+			]
+		);
+
+		// equal start and end times are a whole day
+		let whole_day = [(zone(3), time!(09:00)..time!(09:00))];
+		assert_eq!(
+			overlapping_hours(date, &whole_day),
+			[datetime!(2024-06-10 06:00 UTC)..datetime!(2024-06-11 06:00 UTC)]
+		);
+		let night_shift = [(zone(0), time!(20:00)..time!(02:00))];
+		assert!(overlapping_hours(crate::Date::MAX, &night_shift).is_empty());
+
+		// the time zones are 26 hours apart, so the overlap is two days later
+		let overlap = overlapping_hours(
+			date,
+			&[
+				(zone(-12), time!(20:00)..time!(04:00)),
+				(zone(14), time!(00:00)..time!(06:00)),
+			],
+		);
+		assert_eq!(
+			overlap,
+			[datetime!(2024-06-11 10:00 UTC)..datetime!(2024-06-11 16:00 UTC)]
+		);
+
+		let apart = [
+			(zone(-8), time!(09:00)..time!(17:00)),
+			(zone(8), time!(09:00)..time!(17:00)),
+		];
+		assert!(overlapping_hours(date, &apart).is_empty());
+		assert!(overlapping_hours::<UtcOffset>(date, &[]).is_empty());
+	}
 }
@@ -0,0 +1,104 @@
+//! The parts of the wall-clock timers which don't depend on the runtime
+
+use std::time::Duration;
+
+use crate::{timezone::Utc, DateTime, NaiveDateTime, TimeZone, Timestamp};
+
+/// The longest time to sleep before checking the wall clock again
+pub(crate) const MAX_SLEEP: Duration = Duration::from_secs(1);
+
+const NANOS_PER_SECOND: i128 = 1_000_000_000;
+
+/// Gets the number of nanoseconds since the Unix epoch, not counting leap
+/// seconds
+fn unix_nanos<Tz: TimeZone>(date_time: &DateTime<Tz>) -> i128 {
+	let timestamp = date_time.unix_timestamp();
+	i128::from(timestamp.total_seconds()) * NANOS_PER_SECOND + i128::from(timestamp.nanosecond())
+}
+
+/// Gets the amount of time until the given time, or `None` if it has passed
+pub(crate) fn duration_until<Tz: TimeZone>(deadline: &DateTime<Tz>) -> Option<Duration> {
+	let remaining = deadline.duration_until_now();
+	(!remaining.is_zero()).then_some(remaining)
+}
+
+/// Gets the first tick that isn't before the given time, or `minimum_tick` if
+/// that is later
+fn first_tick_at_or_after(
+	anchor_nanos: i128,
+	period_nanos: i128,
+	now_nanos: i128,
+	minimum_tick: i128,
+) -> i128 {
+	let since_anchor = now_nanos - anchor_nanos;
+	let tick = since_anchor.div_euclid(period_nanos)
+		+ i128::from(since_anchor.rem_euclid(period_nanos) != 0);
+	tick.max(minimum_tick)
+}
+
+/// The ticks of a wall-clock interval
+#[derive(Clone, Debug)]
+pub(crate) struct WallSchedule<Tz: TimeZone> {
+	anchor_nanos: i128,
+	period_nanos: i128,
+	next_tick: i128,
+	timezone: Tz,
+}
+
+impl<Tz: TimeZone + Clone> WallSchedule<Tz> {
+	/// Creates a schedule which ticks at `anchor`, and then every `period`
+	/// after it
+	///
+	/// # Panics
+	///
+	/// Panics if the period is zero
+	pub(crate) fn new(period: Duration, anchor: &DateTime<Tz>) -> Self {
+		assert!(
+			!period.is_zero(),
+			"the period of an interval must be positive"
+		);
+
+		Self {
+			anchor_nanos: unix_nanos(anchor),
+			period_nanos: period.as_nanos() as i128,
+			next_tick: 0,
+			timezone: anchor.timezone().clone(),
+		}
+	}
+
+	/// Gets the time of the next tick. Ticks which have already passed are
+	/// skipped, and a tick is never returned twice.
+	pub(crate) fn next_deadline(&mut self) -> DateTime<Tz> {
+		let now = unix_nanos(&DateTime::<Utc>::now());
+		let tick =
+			first_tick_at_or_after(self.anchor_nanos, self.period_nanos, now, self.next_tick);
+		self.next_tick = tick + 1;
+
+		let deadline = self.anchor_nanos + tick * self.period_nanos;
+		let timestamp = Timestamp::new(
+			deadline.div_euclid(NANOS_PER_SECOND) as i64,
+			deadline.rem_euclid(NANOS_PER_SECOND) as u32,
+		);
+		let deadline = DateTime::from_utc(NaiveDateTime::from_timestamp(timestamp), Utc);
+		deadline.into_timezone(self.timezone.clone())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn ticks_are_aligned_to_the_anchor() {
+		assert_eq!(first_tick_at_or_after(0, 10, 0, 0), 0);
+		assert_eq!(first_tick_at_or_after(0, 10, 1, 0), 1);
+		assert_eq!(first_tick_at_or_after(0, 10, 10, 0), 1);
+		assert_eq!(first_tick_at_or_after(0, 10, 35, 0), 4);
+		assert_eq!(first_tick_at_or_after(100, 10, 35, 0), 0);
+	}
+
+	#[test]
+	fn ticks_are_not_repeated() {
+		assert_eq!(first_tick_at_or_after(0, 10, 15, 5), 5);
+	}
+}
@@ -1,13 +1,17 @@
+use core::array::IntoIter;
 use core::str::FromStr;
 
 use derive_more::Display;
 
 use thiserror::Error;
 
+use crate::error::{closest_match, DidYouMean};
+
 use self::Weekday::*;
 
 /// Day of the week
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Display)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[repr(u8)]
 pub enum Weekday {
 	Monday = 0,
@@ -20,6 +24,37 @@ pub enum Weekday {
 }
 
 impl Weekday {
+	/// Gets the weekday which is the given number of days after a Monday
+	pub(crate) const fn from_days_after_monday(days: i64) -> Self {
+		match days.rem_euclid(7) {
+			0 => Monday,
+			1 => Tuesday,
+			2 => Wednesday,
+			3 => Thursday,
+			4 => Friday,
+			5 => Saturday,
+			_ => Sunday,
+		}
+	}
+
+	/// Iterates over the days of the week, starting with Monday
+	///
+	/// # Example
+	///
+	/// ```
+	/// use botic::Weekday;
+	///
+	/// let header: Vec<_> = Weekday::iter().map(Weekday::abbreviation).collect();
+	/// assert_eq!(header, ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"]);
+	/// ```
+	#[must_use]
+	pub fn iter() -> IntoIter<Self, 7> {
+		[
+			Monday, Tuesday, Wednesday, Thursday, Friday, Saturday, Sunday,
+		]
+		.into_iter()
+	}
+
 	/// Get the weekday from its name. Returns `None` if an invalid name was given.
 	///
 	/// # Example
@@ -44,6 +79,76 @@ impl Weekday {
 		}
 	}
 
+	/// Get the weekday from its three-letter abbreviation. Returns `None` if
+	/// an invalid abbreviation was given.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use botic::Weekday;
+	///
+	/// assert_eq!(Some(Weekday::Monday), Weekday::from_abbreviation("Mon"));
+	/// assert_eq!(None, Weekday::from_abbreviation("Mond"));
+	/// assert_eq!(Ok(Weekday::Friday), "Fri".parse());
+	/// ```
+	#[must_use]
+	pub fn from_abbreviation(abbreviation: &str) -> Option<Self> {
+		match abbreviation {
+			"Mon" => Some(Monday),
+			"Tue" => Some(Tuesday),
+			"Wed" => Some(Wednesday),
+			"Thu" => Some(Thursday),
+			"Fri" => Some(Friday),
+			"Sat" => Some(Saturday),
+			"Sun" => Some(Sunday),
+			_ => None,
+		}
+	}
+
+	/// Get the name of the weekday
+	///
+	/// # Example
+	///
+	/// ```
+	/// use botic::Weekday;
+	///
+	/// assert_eq!("Monday", Weekday::Monday.name());
+	/// ```
+	#[must_use]
+	pub const fn name(self) -> &'static str {
+		match self {
+			Monday => "Monday",
+			Tuesday => "Tuesday",
+			Wednesday => "Wednesday",
+			Thursday => "Thursday",
+			Friday => "Friday",
+			Saturday => "Saturday",
+			Sunday => "Sunday",
+		}
+	}
+
+	/// Get the three-letter abbreviation of the weekday
+	///
+	/// # Example
+	///
+	/// ```
+	/// use botic::Weekday;
+	///
+	/// assert_eq!("Mon", Weekday::Monday.abbreviation());
+	/// ```
+	#[must_use]
+	pub const fn abbreviation(self) -> &'static str {
+		match self {
+			Monday => "Mon",
+			Tuesday => "Tue",
+			Wednesday => "Wed",
+			Thursday => "Thu",
+			Friday => "Fri",
+			Saturday => "Sat",
+			Sunday => "Sun",
+		}
+	}
+
 	/// Get the next weekday
 	///
 	/// # Example
@@ -88,6 +193,53 @@ impl Weekday {
 		}
 	}
 
+	/// Get the weekday which is `n` days after this one
+	///
+	/// # Example
+	///
+	/// ```
+	/// use botic::Weekday;
+	///
+	/// assert_eq!(Weekday::Wednesday, Weekday::Monday.nth_next(2));
+	/// assert_eq!(Weekday::Monday, Weekday::Monday.nth_next(700));
+	/// ```
+	#[must_use]
+	pub const fn nth_next(self, n: u32) -> Self {
+		Self::from_days_after_monday(self as i64 + (n % 7) as i64)
+	}
+
+	/// Get the weekday which is `n` days before this one
+	///
+	/// # Example
+	///
+	/// ```
+	/// use botic::Weekday;
+	///
+	/// assert_eq!(Weekday::Saturday, Weekday::Monday.nth_previous(2));
+	/// assert_eq!(Weekday::Sunday, Weekday::Monday.nth_previous(8));
+	/// ```
+	#[must_use]
+	pub const fn nth_previous(self, n: u32) -> Self {
+		Self::from_days_after_monday(self as i64 - (n % 7) as i64)
+	}
+
+	/// Get the number of days from this weekday until the next time it's the
+	/// other weekday. This is zero if they're the same weekday.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use botic::Weekday;
+	///
+	/// assert_eq!(4, Weekday::Monday.days_until(Weekday::Friday));
+	/// assert_eq!(3, Weekday::Friday.days_until(Weekday::Monday));
+	/// assert_eq!(0, Weekday::Friday.days_until(Weekday::Friday));
+	/// ```
+	#[must_use]
+	pub const fn days_until(self, other: Self) -> u8 {
+		(other as u8 + 7 - self as u8) % 7
+	}
+
 	/// Get the zero-indexed number of days from Monday.
 	/// In other words, the number representing the day of the week,
 	/// starting with Monday = 0
@@ -161,20 +313,52 @@ impl Weekday {
 	}
 }
 
+/// The string wasn't the name or abbreviation of a weekday
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Error)]
-#[error("Failed to parse the month")]
-// TODO Consider trying to figure out what month the user meant to use
-pub struct ParseWeekdayError;
+#[error("Failed to parse the weekday{}", DidYouMean(.suggestion))]
+pub struct ParseWeekdayError {
+	suggestion: Option<Weekday>,
+}
+
+impl ParseWeekdayError {
+	/// Creates an error for the given string, guessing which weekday was meant
+	fn new(s: &str) -> Self {
+		let names = Weekday::iter()
+			.flat_map(|weekday| [(weekday.name(), weekday), (weekday.abbreviation(), weekday)]);
+		Self {
+			suggestion: closest_match(s, names),
+		}
+	}
+
+	/// The weekday which was most likely meant, if the string was close to
+	/// the name or abbreviation of one. This can be used to show a helpful error message.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use botic::Weekday;
+	///
+	/// let error = "monday".parse::<Weekday>().unwrap_err();
+	/// assert_eq!(error.suggestion(), Some(Weekday::Monday));
+	/// assert_eq!(error.to_string(), "Failed to parse the weekday. Did you mean Monday?");
+	/// ```
+	#[must_use]
+	pub const fn suggestion(&self) -> Option<Weekday> {
+		self.suggestion
+	}
+}
 
 // TODO make case-insensitive
-// TODO support short names
 impl FromStr for Weekday {
 	type Err = ParseWeekdayError;
 
 	fn from_str(s: &str) -> Result<Self, Self::Err> {
-		match Self::from_name(s) {
-			Some(weekday) => Ok(weekday),
-			None => Err(ParseWeekdayError),
+		if let Some(weekday) = Self::from_abbreviation(s) {
+			Ok(weekday)
+		} else if let Some(weekday) = Self::from_name(s) {
+			Ok(weekday)
+		} else {
+			Err(ParseWeekdayError::new(s))
 		}
 	}
 }
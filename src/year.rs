@@ -1,7 +1,66 @@
+use core::fmt::Display;
 use core::ops::{Add, AddAssign, Sub, SubAssign};
 
 use derive_more::{Display, FromStr};
 
+/// An era of the proleptic Gregorian calendar
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum Era {
+	/// Before the Common Era, which is also known as BC
+	Bce,
+	/// The Common Era, which is also known as AD
+	Ce,
+}
+
+impl Era {
+	/// Gets the abbreviation of the era, which is either `"BCE"` or `"CE"`
+	///
+	/// # Example
+	///
+	/// ```
+	/// use botic::Era;
+	///
+	/// assert_eq!("BCE", Era::Bce.abbreviation());
+	/// ```
+	#[must_use]
+	pub const fn abbreviation(self) -> &'static str {
+		match self {
+			Self::Bce => "BCE",
+			Self::Ce => "CE",
+		}
+	}
+
+	/// Gets the traditional abbreviation of the era, which is either `"BC"` or
+	/// `"AD"`
+	///
+	/// # Example
+	///
+	/// ```
+	/// use botic::Era;
+	///
+	/// assert_eq!("AD", Era::Ce.traditional_abbreviation());
+	/// ```
+	#[must_use]
+	pub const fn traditional_abbreviation(self) -> &'static str {
+		match self {
+			Self::Bce => "BC",
+			Self::Ce => "AD",
+		}
+	}
+}
+
+/// Formats the abbreviation of the era. The alternate flag (`{:#}`) uses the
+/// traditional abbreviation instead.
+impl Display for Era {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		if f.alternate() {
+			f.write_str(self.traditional_abbreviation())
+		} else {
+			f.write_str(self.abbreviation())
+		}
+	}
+}
+
 /// A year value type, stored as an i16
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, FromStr, Display)]
 pub struct Year(i16);
@@ -184,6 +243,65 @@ impl Year {
 		Year(self.0.wrapping_sub(rhs))
 	}
 
+	/// Gets the era of the year.
+	///
+	/// Years are numbered astronomically, so there is a year zero, which is
+	/// 1 BCE. Year `-1` is 2 BCE, and so on.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use botic::{Era, Year};
+	///
+	/// assert_eq!(Era::Ce, Year::from(1).era());
+	/// assert_eq!(Era::Bce, Year::from(0).era());
+	/// ```
+	#[must_use]
+	pub const fn era(self) -> Era {
+		if self.0 > 0 {
+			Era::Ce
+		} else {
+			Era::Bce
+		}
+	}
+
+	/// Gets the number of the year within its era, which is always positive.
+	/// See [`Year::era`] for how years before the Common Era are numbered.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use botic::Year;
+	///
+	/// assert_eq!(2024, Year::from(2024).year_of_era());
+	/// assert_eq!(1, Year::from(0).year_of_era());
+	/// assert_eq!(44, Year::from(-43).year_of_era());
+	/// ```
+	#[must_use]
+	pub const fn year_of_era(self) -> u16 {
+		match self.era() {
+			Era::Ce => self.0 as u16,
+			Era::Bce => (1 - self.0 as i32) as u16,
+		}
+	}
+
+	/// Formats the year with its era, such as `44 BCE` or `2024 CE`, instead
+	/// of as a signed number. The alternate flag (`{:#}`) uses `BC` and `AD`
+	/// instead.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use botic::Year;
+	///
+	/// assert_eq!("44 BCE", Year::from(-43).with_era().to_string());
+	/// assert_eq!("2024 AD", format!("{:#}", Year::from(2024).with_era()));
+	/// ```
+	#[must_use]
+	pub const fn with_era(self) -> YearWithEra {
+		YearWithEra(self)
+	}
+
 	/// Checks if the year is a leap year
 	///
 	/// # Example
@@ -200,6 +318,59 @@ impl Year {
 	pub const fn is_leap_year(self) -> bool {
 		(self.0 % 4 == 0) && ((self.0 % 100 != 0) || (self.0 % 400 == 0))
 	}
+
+	/// Gets the number of leap days from 0001-01-01 to the start of the year.
+	/// For years before 1 CE, this is negative, and counts the leap days from
+	/// the start of the year to 0001-01-01.
+	///
+	/// The first day of a year is `365 * (year - 1) + year.leap_days_since_ce()`
+	/// days after 0001-01-01.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use botic::Year;
+	///
+	/// assert_eq!(0, Year::from(1).leap_days_since_ce());
+	/// assert_eq!(1, Year::from(5).leap_days_since_ce());
+	/// assert_eq!(485, Year::from(2001).leap_days_since_ce());
+	/// assert_eq!(-1, Year::from(0).leap_days_since_ce());
+	/// ```
+	#[must_use]
+	pub const fn leap_days_since_ce(self) -> i32 {
+		let years = self.0 as i32 - 1;
+		years.div_euclid(4) - years.div_euclid(100) + years.div_euclid(400)
+	}
+
+	/// Counts the leap years from `start` up to, but not including, `end`. If
+	/// `end` is before `start`, then this is the negative of the number of
+	/// leap years from `end` to `start`.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use botic::Year;
+	///
+	/// assert_eq!(25, Year::leap_years_between(Year::from(1900), Year::from(2001)));
+	/// assert_eq!(1, Year::leap_years_between(Year::from(2024), Year::from(2025)));
+	/// assert_eq!(0, Year::leap_years_between(Year::from(2025), Year::from(2028)));
+	/// assert_eq!(-1, Year::leap_years_between(Year::from(2025), Year::from(2024)));
+	/// ```
+	#[must_use]
+	pub const fn leap_years_between(start: Self, end: Self) -> i32 {
+		end.leap_days_since_ce() - start.leap_days_since_ce()
+	}
+}
+
+/// A year which is formatted with its era. See [`Year::with_era`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct YearWithEra(Year);
+
+impl Display for YearWithEra {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(f, "{} ", self.0.year_of_era())?;
+		Display::fmt(&self.0.era(), f)
+	}
 }
 
 impl From<i16> for Year {